@@ -0,0 +1,333 @@
+//! C FFI layer of [slip-10](slip_10) crate
+//!
+//! Exposes master and child key derivation, and key serialization as `extern "C"` functions, so
+//! native code (iOS, Android, other languages) can call into this implementation. Function
+//! signatures are compatible with [cbindgen], which generates a C header:
+//! ```text
+//! cbindgen --config cbindgen.toml --output slip10.h
+//! ```
+//!
+//! The crate is built as a static and a dynamic C library:
+//! ```text
+//! cargo build --release -p slip-10-ffi
+//! ```
+//!
+//! ### Conventions
+//! * Every function returns a status code: [`SLIP10_OK`] on success, or one of `SLIP10_ERR_*`
+//!   codes on failure.
+//! * Every input and output buffer is passed as a pointer along with its length in bytes.
+//!   Pointers must not be null and must be valid for reads (inputs) or writes (outputs) of the
+//!   given length. Output buffers must be at least as long as documented for each function,
+//!   otherwise [`SLIP10_ERR_BUFFER_TOO_SMALL`] is returned and nothing is written.
+//! * Output buffer must not overlap with any input buffer, e.g. a child key can't be derived in
+//!   place of its parent key.
+//! * Curve is selected with [`SLIP10_CURVE_SECP256K1`] or [`SLIP10_CURVE_SECP256R1`].
+//! * Extended secret keys are exchanged as [64 bytes](slip_10::ExtendedSecretKey::to_bytes), and
+//!   extended public keys as [65 bytes](slip_10::ExtendedPublicKey::to_bytes). Outputs containing
+//!   secret keys must be handled (and erased) accordingly by the caller.
+//! * Derivation paths are UTF-8 strings like `m/44'/0'/0'/0/0`, passed without NUL terminator.
+//!
+//! ### Example
+//! ```c
+//! uint8_t master_key[SLIP10_SECRET_KEY_LEN];
+//! int status = slip10_derive_master_key(SLIP10_CURVE_SECP256K1, seed, seed_len,
+//!                                       master_key, sizeof(master_key));
+//! if (status != SLIP10_OK) { /* handle error */ }
+//!
+//! const char *path = "m/44'/0'/0'/0/0";
+//! uint8_t child_key[SLIP10_SECRET_KEY_LEN];
+//! status = slip10_derive_secret_key(SLIP10_CURVE_SECP256K1, master_key, sizeof(master_key),
+//!                                   (const uint8_t *)path, strlen(path),
+//!                                   child_key, sizeof(child_key));
+//! ```
+//!
+//! [cbindgen]: https://github.com/mozilla/cbindgen
+
+#![forbid(missing_docs)]
+
+use generic_ec::Curve;
+
+use slip_10::{
+    bip32, errors,
+    supported_curves::{Secp256k1, Secp256r1},
+    DerivationPath, ExtendedKeyPair, ExtendedPublicKey, ExtendedSecretKey, Seed, SupportedCurve,
+};
+
+/// Dispatches the block over the curve, `E` is a type of the curve inside the block
+macro_rules! with_curve {
+    ($curve:expr, |$E:ident| $body:block) => {
+        match $curve {
+            SLIP10_CURVE_SECP256K1 => {
+                type $E = Secp256k1;
+                $body
+            }
+            SLIP10_CURVE_SECP256R1 => {
+                type $E = Secp256r1;
+                $body
+            }
+            _ => return Err(SLIP10_ERR_UNSUPPORTED_CURVE),
+        }
+    };
+}
+
+/// Operation succeeded
+pub const SLIP10_OK: i32 = 0;
+/// Required pointer is null
+pub const SLIP10_ERR_NULL_POINTER: i32 = -1;
+/// Output buffer is too small
+pub const SLIP10_ERR_BUFFER_TOO_SMALL: i32 = -2;
+/// Curve is not supported
+pub const SLIP10_ERR_UNSUPPORTED_CURVE: i32 = -3;
+/// Seed is not 16-64 bytes long
+pub const SLIP10_ERR_INVALID_SEED: i32 = -4;
+/// Derivation path is malformed
+pub const SLIP10_ERR_INVALID_PATH: i32 = -5;
+/// Key has invalid length or encoding
+pub const SLIP10_ERR_INVALID_KEY: i32 = -6;
+/// Path contains a hardened index, which is not defined for public derivation
+pub const SLIP10_ERR_HARDENED_PUBLIC_DERIVATION: i32 = -7;
+/// Derived key exceeds maximum depth or can't be derived
+pub const SLIP10_ERR_DERIVATION_FAILED: i32 = -8;
+
+/// secp256k1 curve
+pub const SLIP10_CURVE_SECP256K1: u32 = 0;
+/// secp256r1 (NIST P-256) curve
+pub const SLIP10_CURVE_SECP256R1: u32 = 1;
+
+/// Length of serialized extended secret key
+pub const SLIP10_SECRET_KEY_LEN: usize = 64;
+/// Length of serialized extended public key
+pub const SLIP10_PUBLIC_KEY_LEN: usize = 65;
+/// Length of output buffer sufficient for NUL-terminated `xpub...` string
+pub const SLIP10_XPUB_MAX_LEN: usize = 112;
+
+/// Derives a master key from the seed
+///
+/// Writes [`SLIP10_SECRET_KEY_LEN`] bytes of extended secret key into `out`.
+///
+/// # Safety
+/// `seed` must be valid for reads of `seed_len` bytes, and `out` must be valid for writes
+/// of `out_len` bytes. Buffers must not overlap.
+#[no_mangle]
+pub unsafe extern "C" fn slip10_derive_master_key(
+    curve: u32,
+    seed: *const u8,
+    seed_len: usize,
+    out: *mut u8,
+    out_len: usize,
+) -> i32 {
+    status(|| {
+        let seed = Seed::new(input(seed, seed_len)?).map_err(|_| SLIP10_ERR_INVALID_SEED)?;
+        check_output(out, out_len, SLIP10_SECRET_KEY_LEN)?;
+        let master_key = with_curve!(curve, |E| {
+            slip_10::derive_master_key::<E>(&seed).to_bytes()
+        });
+        write_output(out, &master_key);
+        Ok(())
+    })
+}
+
+/// Derives a child secret key with specified derivation path
+///
+/// `key` is a [`SLIP10_SECRET_KEY_LEN`] bytes parent extended secret key. Writes
+/// [`SLIP10_SECRET_KEY_LEN`] bytes of child extended secret key into `out`.
+///
+/// # Safety
+/// `key` must be valid for reads of `key_len` bytes, `path` must be valid for reads of
+/// `path_len` bytes, and `out` must be valid for writes of `out_len` bytes. Buffers must not
+/// overlap.
+#[no_mangle]
+pub unsafe extern "C" fn slip10_derive_secret_key(
+    curve: u32,
+    key: *const u8,
+    key_len: usize,
+    path: *const u8,
+    path_len: usize,
+    out: *mut u8,
+    out_len: usize,
+) -> i32 {
+    status(|| {
+        let key = input(key, key_len)?;
+        let path = parse_path(input(path, path_len)?)?;
+        check_output(out, out_len, SLIP10_SECRET_KEY_LEN)?;
+        let child_key = with_curve!(curve, |E| {
+            let parent_key = ExtendedKeyPair::from(parse_secret_key::<E>(key)?);
+            parent_key.derive_path(&path).secret_key().to_bytes()
+        });
+        write_output(out, &child_key);
+        Ok(())
+    })
+}
+
+/// Computes extended public key of the extended secret key
+///
+/// `key` is a [`SLIP10_SECRET_KEY_LEN`] bytes extended secret key. Writes
+/// [`SLIP10_PUBLIC_KEY_LEN`] bytes of extended public key into `out`.
+///
+/// # Safety
+/// `key` must be valid for reads of `key_len` bytes, and `out` must be valid for writes
+/// of `out_len` bytes. Buffers must not overlap.
+#[no_mangle]
+pub unsafe extern "C" fn slip10_public_key(
+    curve: u32,
+    key: *const u8,
+    key_len: usize,
+    out: *mut u8,
+    out_len: usize,
+) -> i32 {
+    status(|| {
+        let key = input(key, key_len)?;
+        check_output(out, out_len, SLIP10_PUBLIC_KEY_LEN)?;
+        let public_key = with_curve!(curve, |E| {
+            ExtendedPublicKey::from(&parse_secret_key::<E>(key)?).to_bytes()
+        });
+        write_output(out, &public_key);
+        Ok(())
+    })
+}
+
+/// Derives a child public key with specified non-hardened derivation path
+///
+/// `key` is a [`SLIP10_PUBLIC_KEY_LEN`] bytes parent extended public key. Writes
+/// [`SLIP10_PUBLIC_KEY_LEN`] bytes of child extended public key into `out`. Returns
+/// [`SLIP10_ERR_HARDENED_PUBLIC_DERIVATION`] if path contains a hardened index.
+///
+/// # Safety
+/// `key` must be valid for reads of `key_len` bytes, `path` must be valid for reads of
+/// `path_len` bytes, and `out` must be valid for writes of `out_len` bytes. Buffers must not
+/// overlap.
+#[no_mangle]
+pub unsafe extern "C" fn slip10_derive_public_key(
+    curve: u32,
+    key: *const u8,
+    key_len: usize,
+    path: *const u8,
+    path_len: usize,
+    out: *mut u8,
+    out_len: usize,
+) -> i32 {
+    status(|| {
+        let key = input(key, key_len)?;
+        let path = parse_path(input(path, path_len)?)?;
+        check_output(out, out_len, SLIP10_PUBLIC_KEY_LEN)?;
+        let child_key = with_curve!(curve, |E| {
+            let parent_key =
+                ExtendedPublicKey::<E>::try_from(key).map_err(|_| SLIP10_ERR_INVALID_KEY)?;
+            parent_key
+                .derive_path(&path)
+                .map_err(derive_error)?
+                .to_bytes()
+        });
+        write_output(out, &child_key);
+        Ok(())
+    })
+}
+
+/// Derives a key from the seed with specified derivation path, and serializes its extended
+/// public key into `xpub...` string
+///
+/// Writes NUL-terminated string into `out`, which must be at least [`SLIP10_XPUB_MAX_LEN`]
+/// bytes long.
+///
+/// # Safety
+/// `seed` must be valid for reads of `seed_len` bytes, `path` must be valid for reads of
+/// `path_len` bytes, and `out` must be valid for writes of `out_len` bytes. Buffers must not
+/// overlap.
+#[no_mangle]
+pub unsafe extern "C" fn slip10_xpub_from_seed(
+    curve: u32,
+    seed: *const u8,
+    seed_len: usize,
+    path: *const u8,
+    path_len: usize,
+    out: *mut u8,
+    out_len: usize,
+) -> i32 {
+    status(|| {
+        let seed = Seed::new(input(seed, seed_len)?).map_err(|_| SLIP10_ERR_INVALID_SEED)?;
+        let path = parse_path(input(path, path_len)?)?;
+        check_output(out, out_len, SLIP10_XPUB_MAX_LEN)?;
+        let xpub = with_curve!(curve, |E| {
+            let master_key =
+                bip32::Bip32ExtendedKey::master(slip_10::derive_master_key::<E>(&seed));
+            let key = master_key
+                .derive_child_with_path(&path)
+                .map_err(derive_error)?;
+            key.to_xpub().to_string()
+        });
+        write_str(out, out_len, &xpub)?;
+        Ok(())
+    })
+}
+
+fn status(f: impl FnOnce() -> Result<(), i32>) -> i32 {
+    match f() {
+        Ok(()) => SLIP10_OK,
+        Err(code) => code,
+    }
+}
+
+/// # Safety
+/// `ptr` must be valid for reads of `len` bytes
+unsafe fn input<'a>(ptr: *const u8, len: usize) -> Result<&'a [u8], i32> {
+    if ptr.is_null() {
+        return Err(SLIP10_ERR_NULL_POINTER);
+    }
+    Ok(core::slice::from_raw_parts(ptr, len))
+}
+
+/// Checks that the output buffer is not null and can fit `required_len` bytes
+///
+/// Doesn't access the buffer, so it can be called while inputs are still borrowed.
+fn check_output(ptr: *mut u8, len: usize, required_len: usize) -> Result<(), i32> {
+    if ptr.is_null() {
+        return Err(SLIP10_ERR_NULL_POINTER);
+    }
+    if len < required_len {
+        return Err(SLIP10_ERR_BUFFER_TOO_SMALL);
+    }
+    Ok(())
+}
+
+/// Writes bytes into the output buffer
+///
+/// # Safety
+/// `ptr` must be valid for writes of `bytes.len()` bytes, and must not overlap with any
+/// borrowed input
+unsafe fn write_output(ptr: *mut u8, bytes: &[u8]) {
+    core::slice::from_raw_parts_mut(ptr, bytes.len()).copy_from_slice(bytes)
+}
+
+fn parse_path(path: &[u8]) -> Result<DerivationPath, i32> {
+    core::str::from_utf8(path)
+        .ok()
+        .and_then(|path| path.parse().ok())
+        .ok_or(SLIP10_ERR_INVALID_PATH)
+}
+
+fn parse_secret_key<E: Curve + SupportedCurve>(key: &[u8]) -> Result<ExtendedSecretKey<E>, i32> {
+    let key = key.try_into().map_err(|_| SLIP10_ERR_INVALID_KEY)?;
+    ExtendedSecretKey::from_bytes(key).map_err(|_| SLIP10_ERR_INVALID_KEY)
+}
+
+fn derive_error(err: errors::DeriveError) -> i32 {
+    match err {
+        errors::DeriveError::HardenedInPublicPath { .. } => SLIP10_ERR_HARDENED_PUBLIC_DERIVATION,
+        _ => SLIP10_ERR_DERIVATION_FAILED,
+    }
+}
+
+/// Writes NUL-terminated string into the output buffer
+///
+/// # Safety
+/// `ptr` must be valid for writes of `len` bytes, and must not overlap with any borrowed input
+unsafe fn write_str(ptr: *mut u8, len: usize, s: &str) -> Result<(), i32> {
+    // Reserve one byte for NUL terminator
+    if s.len() >= len {
+        return Err(SLIP10_ERR_BUFFER_TOO_SMALL);
+    }
+    let out = core::slice::from_raw_parts_mut(ptr, s.len() + 1);
+    out[..s.len()].copy_from_slice(s.as_bytes());
+    out[s.len()] = 0;
+    Ok(())
+}
@@ -0,0 +1,207 @@
+use slip_10::{
+    supported_curves::{Secp256k1, Secp256r1},
+    ExtendedKeyPair, ExtendedPublicKey, H,
+};
+use slip_10_ffi::*;
+
+const SEED: &[u8] = b"16-64 bytes of high entropy";
+
+fn master_key(curve: u32) -> [u8; SLIP10_SECRET_KEY_LEN] {
+    let mut out = [0u8; SLIP10_SECRET_KEY_LEN];
+    let status = unsafe {
+        slip10_derive_master_key(
+            curve,
+            SEED.as_ptr(),
+            SEED.len(),
+            out.as_mut_ptr(),
+            out.len(),
+        )
+    };
+    assert_eq!(status, SLIP10_OK);
+    out
+}
+
+fn derive_secret_key(
+    curve: u32,
+    key: &[u8],
+    path: &str,
+) -> Result<[u8; SLIP10_SECRET_KEY_LEN], i32> {
+    let mut out = [0u8; SLIP10_SECRET_KEY_LEN];
+    let status = unsafe {
+        slip10_derive_secret_key(
+            curve,
+            key.as_ptr(),
+            key.len(),
+            path.as_ptr(),
+            path.len(),
+            out.as_mut_ptr(),
+            out.len(),
+        )
+    };
+    if status == SLIP10_OK {
+        Ok(out)
+    } else {
+        Err(status)
+    }
+}
+
+fn public_key(curve: u32, key: &[u8]) -> [u8; SLIP10_PUBLIC_KEY_LEN] {
+    let mut out = [0u8; SLIP10_PUBLIC_KEY_LEN];
+    let status =
+        unsafe { slip10_public_key(curve, key.as_ptr(), key.len(), out.as_mut_ptr(), out.len()) };
+    assert_eq!(status, SLIP10_OK);
+    out
+}
+
+fn derive_public_key(
+    curve: u32,
+    key: &[u8],
+    path: &str,
+) -> Result<[u8; SLIP10_PUBLIC_KEY_LEN], i32> {
+    let mut out = [0u8; SLIP10_PUBLIC_KEY_LEN];
+    let status = unsafe {
+        slip10_derive_public_key(
+            curve,
+            key.as_ptr(),
+            key.len(),
+            path.as_ptr(),
+            path.len(),
+            out.as_mut_ptr(),
+            out.len(),
+        )
+    };
+    if status == SLIP10_OK {
+        Ok(out)
+    } else {
+        Err(status)
+    }
+}
+
+#[test]
+fn matches_rust_api() {
+    let seed = slip_10::Seed::new(SEED).unwrap();
+
+    let master_key = master_key(SLIP10_CURVE_SECP256K1);
+    let expected = ExtendedKeyPair::from(slip_10::derive_master_key::<Secp256k1>(&seed));
+    assert_eq!(master_key, expected.secret_key().to_bytes());
+
+    let child_key = derive_secret_key(SLIP10_CURVE_SECP256K1, &master_key, "m/44'/0'/0'").unwrap();
+    let expected = expected.derive_path([44 + H, H, H]);
+    assert_eq!(child_key, expected.secret_key().to_bytes());
+
+    let child_public_key = public_key(SLIP10_CURVE_SECP256K1, &child_key);
+    assert_eq!(child_public_key, expected.public_key().to_bytes());
+
+    let leaf = derive_public_key(SLIP10_CURVE_SECP256K1, &child_public_key, "0/1").unwrap();
+    let expected_leaf = expected.derive_path([0, 1]);
+    assert_eq!(leaf, expected_leaf.public_key().to_bytes());
+    assert_eq!(
+        derive_secret_key(SLIP10_CURVE_SECP256K1, &child_key, "0/1").unwrap(),
+        expected_leaf.secret_key().to_bytes()
+    );
+
+    // secp256r1 keys are different
+    let master_key_r1 = self::master_key(SLIP10_CURVE_SECP256R1);
+    assert_ne!(master_key_r1, master_key);
+    assert_eq!(
+        public_key(SLIP10_CURVE_SECP256R1, &master_key_r1),
+        ExtendedPublicKey::from(&slip_10::derive_master_key::<Secp256r1>(&seed)).to_bytes()
+    );
+}
+
+#[test]
+fn xpub() {
+    let seed = slip_10::Seed::new(SEED).unwrap();
+    let path = "m/84'/0'/0'";
+    let mut out = [0xffu8; SLIP10_XPUB_MAX_LEN];
+    let status = unsafe {
+        slip10_xpub_from_seed(
+            SLIP10_CURVE_SECP256K1,
+            SEED.as_ptr(),
+            SEED.len(),
+            path.as_ptr(),
+            path.len(),
+            out.as_mut_ptr(),
+            out.len(),
+        )
+    };
+    assert_eq!(status, SLIP10_OK);
+
+    let expected =
+        slip_10::bip32::Bip32ExtendedKey::master(slip_10::derive_master_key::<Secp256k1>(&seed))
+            .derive_child_with_path(&path.parse::<slip_10::DerivationPath>().unwrap())
+            .unwrap()
+            .to_xpub()
+            .to_string();
+    let xpub = std::ffi::CStr::from_bytes_until_nul(&out).unwrap();
+    assert_eq!(xpub.to_str().unwrap(), expected);
+}
+
+#[test]
+fn errors() {
+    let master_key = master_key(SLIP10_CURVE_SECP256K1);
+
+    let mut out = [0u8; SLIP10_SECRET_KEY_LEN];
+    let short_seed = b"too short";
+    let status = unsafe {
+        slip10_derive_master_key(
+            SLIP10_CURVE_SECP256K1,
+            short_seed.as_ptr(),
+            short_seed.len(),
+            out.as_mut_ptr(),
+            out.len(),
+        )
+    };
+    assert_eq!(status, SLIP10_ERR_INVALID_SEED);
+
+    let status = unsafe {
+        slip10_derive_master_key(2, SEED.as_ptr(), SEED.len(), out.as_mut_ptr(), out.len())
+    };
+    assert_eq!(status, SLIP10_ERR_UNSUPPORTED_CURVE);
+
+    let status = unsafe {
+        slip10_derive_master_key(
+            SLIP10_CURVE_SECP256K1,
+            core::ptr::null(),
+            0,
+            out.as_mut_ptr(),
+            out.len(),
+        )
+    };
+    assert_eq!(status, SLIP10_ERR_NULL_POINTER);
+
+    let status = unsafe {
+        slip10_derive_master_key(
+            SLIP10_CURVE_SECP256K1,
+            SEED.as_ptr(),
+            SEED.len(),
+            out.as_mut_ptr(),
+            out.len() - 1,
+        )
+    };
+    assert_eq!(status, SLIP10_ERR_BUFFER_TOO_SMALL);
+    assert_eq!(out, [0u8; SLIP10_SECRET_KEY_LEN]);
+
+    assert_eq!(
+        derive_secret_key(SLIP10_CURVE_SECP256K1, &master_key, "m/x"),
+        Err(SLIP10_ERR_INVALID_PATH)
+    );
+    assert_eq!(
+        derive_secret_key(SLIP10_CURVE_SECP256K1, &master_key[1..], "m/0"),
+        Err(SLIP10_ERR_INVALID_KEY)
+    );
+    assert_eq!(
+        derive_secret_key(SLIP10_CURVE_SECP256K1, &[0u8; SLIP10_SECRET_KEY_LEN], "m/0"),
+        Err(SLIP10_ERR_INVALID_KEY)
+    );
+
+    let public_key = public_key(SLIP10_CURVE_SECP256K1, &master_key);
+    assert_eq!(
+        derive_public_key(SLIP10_CURVE_SECP256K1, &public_key, "m/0/1'"),
+        Err(SLIP10_ERR_HARDENED_PUBLIC_DERIVATION)
+    );
+    assert_eq!(
+        derive_public_key(SLIP10_CURVE_SECP256K1, &[0u8; SLIP10_PUBLIC_KEY_LEN], "m/0"),
+        Err(SLIP10_ERR_INVALID_KEY)
+    );
+}
@@ -0,0 +1,176 @@
+//! Python bindings for [`slip_10`] crate
+//!
+//! Exposes `slip10` Python module, so Python scripts derive exactly the same keys as Rust
+//! services. Build and install it with [maturin]:
+//! ```text
+//! cd python && maturin develop --release
+//! ```
+//!
+//! ### Example
+//! ```python
+//! import slip10
+//!
+//! master = slip10.HdKey.from_seed(seed)
+//! account = master.derive_path("m/84'/0'/0'")
+//! print(account.xpub())
+//! ```
+//!
+//! [maturin]: https://www.maturin.rs
+
+use generic_ec::Curve;
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyBytes};
+use slip_10::{
+    bip32::{Bip32ExtendedKey, Xprv},
+    supported_curves::{Secp256k1, Secp256r1},
+    DerivationPath, Seed, SupportedCurve,
+};
+
+/// Extended key of one of supported curves
+enum Key {
+    Secp256k1(Bip32ExtendedKey<Secp256k1>),
+    Secp256r1(Bip32ExtendedKey<Secp256r1>),
+}
+
+/// Evaluates `$body` with `$key` bound to the curve-specific extended key
+macro_rules! with_key {
+    ($self:expr, |$key:ident| $body:expr) => {
+        match &$self {
+            Key::Secp256k1($key) => $body,
+            Key::Secp256r1($key) => $body,
+        }
+    };
+}
+
+/// Extended key pair along with its BIP32 metadata
+///
+/// Supported curves are `secp256k1` (default) and `secp256r1`.
+#[pyclass(module = "slip10", frozen)]
+struct HdKey(Key);
+
+#[pymethods]
+impl HdKey {
+    /// Derives a master key from the seed
+    ///
+    /// Raises `ValueError` if the seed is not 16-64 bytes long, or the curve is not supported.
+    #[staticmethod]
+    #[pyo3(signature = (seed, curve = "secp256k1"))]
+    fn from_seed(seed: &[u8], curve: &str) -> PyResult<Self> {
+        let seed = Seed::new(seed).map_err(value_error)?;
+        let key = match curve {
+            "secp256k1" => Key::Secp256k1(master_key(&seed)),
+            "secp256r1" => Key::Secp256r1(master_key(&seed)),
+            _ => return Err(value_error(format_args!("unsupported curve: {curve}"))),
+        };
+        Ok(Self(key))
+    }
+
+    /// Parses secp256k1 `xprv...` string
+    ///
+    /// Raises `ValueError` if the string is not a valid extended secret key.
+    #[staticmethod]
+    fn from_xprv(xprv: &str) -> PyResult<Self> {
+        let xprv: Xprv<Secp256k1> = xprv.parse().map_err(value_error)?;
+        Ok(Self(Key::Secp256k1(xprv.into())))
+    }
+
+    /// Derives a child key with specified derivation path, e.g. `m/44'/0'/0'/0/0`
+    ///
+    /// Path is relative to this key. Raises `ValueError` if the path is malformed or the
+    /// resulting key exceeds maximum depth.
+    fn derive_path(&self, path: &str) -> PyResult<Self> {
+        let path: DerivationPath = path.parse().map_err(value_error)?;
+        let key = match &self.0 {
+            Key::Secp256k1(key) => {
+                Key::Secp256k1(key.derive_child_with_path(&path).map_err(value_error)?)
+            }
+            Key::Secp256r1(key) => {
+                Key::Secp256r1(key.derive_child_with_path(&path).map_err(value_error)?)
+            }
+        };
+        Ok(Self(key))
+    }
+
+    /// Curve of the key
+    #[getter]
+    fn curve(&self) -> &'static str {
+        match self.0 {
+            Key::Secp256k1(_) => "secp256k1",
+            Key::Secp256r1(_) => "secp256r1",
+        }
+    }
+
+    /// Returns `xpub...` string
+    fn xpub(&self) -> String {
+        with_key!(self.0, |key| key.to_xpub().to_string())
+    }
+
+    /// Returns `xprv...` string
+    ///
+    /// Output contains secret key, make sure it's handled accordingly.
+    fn xprv(&self) -> String {
+        with_key!(self.0, |key| key.to_xprv().to_string())
+    }
+
+    /// 33 bytes compressed public key
+    #[getter]
+    fn public_key<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        with_key!(self.0, |key| PyBytes::new(
+            py,
+            &key.key().public_key().public_key.to_bytes(true)
+        ))
+    }
+
+    /// 32 bytes secret key
+    ///
+    /// Output contains secret key, make sure it's handled accordingly.
+    #[getter]
+    fn secret_key<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        with_key!(self.0, |key| PyBytes::new(
+            py,
+            &key.key().secret_key().secret_key.as_ref().to_be_bytes()
+        ))
+    }
+
+    /// 32 bytes chain code
+    #[getter]
+    fn chain_code<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        with_key!(self.0, |key| PyBytes::new(
+            py,
+            key.key().chain_code().as_bytes()
+        ))
+    }
+
+    /// 4 bytes fingerprint of the key
+    #[getter]
+    fn fingerprint<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        with_key!(self.0, |key| PyBytes::new(
+            py,
+            &key.key().public_key().fingerprint()
+        ))
+    }
+
+    /// Depth of the key, 0 for master key
+    #[getter]
+    fn depth(&self) -> u8 {
+        with_key!(self.0, |key| *key.metadata().depth)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("HdKey({}, {})", self.curve(), self.xpub())
+    }
+}
+
+fn master_key<E: Curve + SupportedCurve>(seed: &Seed) -> Bip32ExtendedKey<E> {
+    Bip32ExtendedKey::master(slip_10::derive_master_key::<E>(seed))
+}
+
+fn value_error(err: impl core::fmt::Display) -> PyErr {
+    PyValueError::new_err(err.to_string())
+}
+
+/// SLIP-10 and BIP32 key derivation
+#[pymodule]
+fn slip10(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<HdKey>()?;
+    Ok(())
+}
@@ -0,0 +1,60 @@
+import unittest
+
+import slip10
+
+# Test vector 1 defined in BIP32 and SLIP-10
+SEED = bytes.fromhex("000102030405060708090a0b0c0d0e0f")
+
+
+class HdKeyTest(unittest.TestCase):
+    def test_bip32_test_vector(self):
+        master = slip10.HdKey.from_seed(SEED)
+        self.assertEqual(master.depth, 0)
+        self.assertEqual(
+            master.xpub(),
+            "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8",
+        )
+        self.assertEqual(
+            master.xprv(),
+            "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi",
+        )
+
+        child = master.derive_path("m/0'/1")
+        self.assertEqual(child.depth, 2)
+        self.assertEqual(
+            child.xpub(),
+            "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ",
+        )
+        self.assertEqual(master.derive_path("0'").derive_path("1").xpub(), child.xpub())
+        self.assertEqual(slip10.HdKey.from_xprv(child.xprv()).xpub(), child.xpub())
+
+    def test_secp256r1(self):
+        master = slip10.HdKey.from_seed(SEED, curve="secp256r1")
+        self.assertEqual(master.curve, "secp256r1")
+        self.assertEqual(
+            master.secret_key.hex(),
+            "612091aaa12e22dd2abef664f8a01a82cae99ad7441b7ef8110424915c268bc2",
+        )
+        self.assertEqual(
+            master.public_key.hex(),
+            "0266874dc6ade47b3ecd096745ca09bcd29638dd52c2c12117b11ed3e458cfa9e8",
+        )
+        self.assertEqual(
+            master.chain_code.hex(),
+            "beeb672fe4621673f722f38529c07392fecaa61015c80c34f29ce8b41b3cb6ea",
+        )
+        self.assertEqual(master.derive_path("m/0'").curve, "secp256r1")
+
+    def test_errors(self):
+        with self.assertRaises(ValueError):
+            slip10.HdKey.from_seed(b"too short")
+        with self.assertRaises(ValueError):
+            slip10.HdKey.from_seed(SEED, curve="ed25519")
+        with self.assertRaises(ValueError):
+            slip10.HdKey.from_seed(SEED).derive_path("m/x")
+        with self.assertRaises(ValueError):
+            slip10.HdKey.from_xprv("xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8")
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,123 @@
+//! Bitcoin addresses of secp256k1 keys
+//!
+//! Converts derived public keys into the most common single-key addresses:
+//! * Legacy [P2PKH](p2pkh): `1...`
+//! * Native SegWit [P2WPKH](p2wpkh) ([BIP173][bip173], bech32): `bc1q...`
+//! * Taproot [P2TR](p2tr) ([BIP341][bip341], bech32m): `bc1p...`
+//!
+//! ### Example
+//! ```rust
+//! use slip_10::{address, supported_curves::Secp256k1};
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//! let master_key = slip_10::ExtendedKeyPair::from(master_key);
+//! let key = slip_10::derive_child_key_pair_with_path(&master_key, slip_10::path!("m/84'/0'/0'/0/0"));
+//!
+//! let address = address::p2wpkh(key.public_key(), address::Network::Bitcoin).to_string();
+//! assert!(address.starts_with("bc1q"));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [bip173]: https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki
+//! [bip341]: https://github.com/bitcoin/bips/blob/master/bip-0341.mediawiki
+
+use core::fmt;
+
+use sha2::Digest as _;
+
+use crate::{supported_curves::Secp256k1, ExtendedPublicKey};
+
+/// Bitcoin network which the address is used on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Network {
+    /// Mainnet: `1...`, `bc1...`
+    Bitcoin,
+    /// Testnet: `m...`/`n...`, `tb1...`
+    Testnet,
+    /// Signet: `m...`/`n...`, `tb1...`
+    Signet,
+    /// Regtest: `m...`/`n...`, `bcrt1...`
+    Regtest,
+}
+
+impl Network {
+    /// Version byte of P2PKH addresses
+    fn p2pkh_version(&self) -> u8 {
+        match self {
+            Self::Bitcoin => 0x00,
+            Self::Testnet | Self::Signet | Self::Regtest => 0x6f,
+        }
+    }
+
+    /// Human-readable part of SegWit addresses
+    fn hrp(&self) -> bech32::Hrp {
+        match self {
+            Self::Bitcoin => bech32::hrp::BC,
+            Self::Testnet | Self::Signet => bech32::hrp::TB,
+            Self::Regtest => bech32::hrp::BCRT,
+        }
+    }
+}
+
+/// Returns legacy P2PKH address of the public key
+pub fn p2pkh(public_key: &ExtendedPublicKey<Secp256k1>, network: Network) -> impl fmt::Display {
+    let mut payload = [0u8; 21];
+    payload[0] = network.p2pkh_version();
+    payload[1..].copy_from_slice(&public_key.key_identifier());
+    Base58Check(payload)
+}
+
+/// Returns native SegWit P2WPKH address of the public key
+pub fn p2wpkh(public_key: &ExtendedPublicKey<Secp256k1>, network: Network) -> impl fmt::Display {
+    SegwitAddress {
+        hrp: network.hrp(),
+        version: bech32::segwit::VERSION_0,
+        program: public_key.key_identifier(),
+    }
+}
+
+/// Returns single-key Taproot P2TR address of the public key
+///
+/// Public key is used as internal key without script path, i.e. output key is tweaked with
+/// `H_TapTweak(internal_key)` as recommended by [BIP86](https://github.com/bitcoin/bips/blob/master/bip-0086.mediawiki).
+/// See [`taproot_output_key`](crate::taproot::taproot_output_key).
+pub fn p2tr(public_key: &ExtendedPublicKey<Secp256k1>, network: Network) -> impl fmt::Display {
+    SegwitAddress {
+        hrp: network.hrp(),
+        version: bech32::segwit::VERSION_1,
+        program: crate::taproot::taproot_output_key(public_key).0,
+    }
+}
+
+/// Payload displayed as Base58Check string
+struct Base58Check([u8; 21]);
+
+impl fmt::Display for Base58Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let checksum = sha2::Sha256::digest(sha2::Sha256::digest(self.0));
+        let mut checked = [0u8; 25];
+        checked[..21].copy_from_slice(&self.0);
+        checked[21..].copy_from_slice(&checksum[..4]);
+
+        let mut encoded = [0u8; 35];
+        let len = bs58::encode(checked)
+            .onto(&mut encoded[..])
+            .map_err(|_| fmt::Error)?;
+        let encoded = core::str::from_utf8(&encoded[..len]).map_err(|_| fmt::Error)?;
+        f.write_str(encoded)
+    }
+}
+
+/// SegWit address displayed as bech32 (version 0) or bech32m (version 1) string
+struct SegwitAddress<const N: usize> {
+    hrp: bech32::Hrp,
+    version: bech32::Fe32,
+    program: [u8; N],
+}
+
+impl<const N: usize> fmt::Display for SegwitAddress<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        bech32::segwit::encode_to_fmt_unchecked(f, self.hrp, self.version, &self.program)
+    }
+}
@@ -0,0 +1,672 @@
+//! BIP32 serialization of extended keys
+//!
+//! Extended keys can be serialized to the standard 78 bytes [BIP32][bip32-ser] payload, and
+//! encoded with Base58Check into well-known `xpub...`/`xprv...` strings. Apart from the key
+//! itself, serialization format carries [`Metadata`]: depth, parent fingerprint, and child
+//! number. It can be provided by the caller, or tracked automatically by [`Bip32ExtendedKey`]
+//! which updates the metadata on each derivation.
+//!
+//! Only curves supported by SLIP10 (secp256k1 and secp256r1) can be serialized.
+//!
+//! ### Example
+//! ```rust
+//! use slip_10::{bip32, supported_curves::Secp256k1};
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//! let xprv = bip32::Xprv {
+//!     metadata: bip32::Metadata::MASTER,
+//!     key: master_key,
+//! };
+//! let encoded = xprv.to_string();
+//! assert!(encoded.starts_with("xprv"));
+//!
+//! let decoded: bip32::Xprv<Secp256k1> = encoded.parse()?;
+//! assert_eq!(decoded.to_bytes(), xprv.to_bytes());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Derive a child key and serialize it without tracking metadata manually:
+//! ```rust
+//! use slip_10::{bip32::Bip32ExtendedKey, supported_curves::Secp256k1, DerivationPath};
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//! let master_key = Bip32ExtendedKey::master(master_key);
+//!
+//! let path: DerivationPath = "m/44'/0'/0'".parse()?;
+//! let child_key = master_key.derive_child_with_path(&path)?;
+//! assert_eq!(child_key.metadata().depth, 3);
+//! let xpub = child_key.to_xpub().to_string();
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [bip32-ser]: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#serialization-format
+
+use core::fmt;
+
+use generic_ec::{Curve, Point, Scalar, SecretScalar};
+use sha2::Digest as _;
+
+use crate::{
+    errors, ChainCode, ChildIndex, ExtendedKeyPair, ExtendedPublicKey, ExtendedSecretKey,
+    NonHardenedIndex, SupportedCurve,
+};
+
+/// Size of BIP32 serialized extended key in bytes
+pub const SERIALIZED_LEN: usize = 78;
+
+/// Size of Base58Check payload: serialized key followed by 4 bytes checksum
+const CHECKED_LEN: usize = SERIALIZED_LEN + 4;
+/// Upper bound of Base58 encoding length of [`CHECKED_LEN`] bytes
+const ENCODED_MAX_LEN: usize = 112;
+
+/// BIP32 metadata of extended key
+#[derive(Clone, Copy, Debug)]
+pub struct Metadata {
+    /// Depth of the key: 0 for master key, 1 for its direct children, and so on
+    pub depth: Depth,
+    /// First 4 bytes of the parent key identifier, zeroes for master key
+    pub parent_fingerprint: [u8; 4],
+    /// Child index that was used to derive the key, 0 for master key
+    pub child_number: ChildIndex,
+}
+
+impl Metadata {
+    /// Metadata of master key
+    pub const MASTER: Self = Self {
+        depth: Depth::MASTER,
+        parent_fingerprint: [0; 4],
+        child_number: ChildIndex::NonHardened(NonHardenedIndex::MIN),
+    };
+}
+
+/// Depth of the key in the derivation tree
+///
+/// BIP32 serializes depth as a single byte, so a key can be at most [`Depth::MAX`] levels deep.
+/// Derivation via [`Bip32ExtendedKey`] returns an error instead of exceeding it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Depth(u8);
+
+impl Depth {
+    /// Depth of master key
+    pub const MASTER: Self = Self(0);
+    /// Maximal depth
+    pub const MAX: Self = Self(u8::MAX);
+
+    /// Constructs a depth
+    pub const fn new(depth: u8) -> Self {
+        Self(depth)
+    }
+
+    /// Returns depth of a child key, or an error if it exceeds [`Depth::MAX`]
+    pub fn child(self) -> Result<Self, errors::DepthOverflow> {
+        self.0.checked_add(1).map(Self).ok_or(errors::DepthOverflow)
+    }
+}
+
+impl From<u8> for Depth {
+    fn from(depth: u8) -> Self {
+        Self(depth)
+    }
+}
+
+impl From<Depth> for u8 {
+    fn from(depth: Depth) -> Self {
+        depth.0
+    }
+}
+
+impl TryFrom<usize> for Depth {
+    type Error = errors::DepthOverflow;
+    fn try_from(depth: usize) -> Result<Self, Self::Error> {
+        u8::try_from(depth)
+            .map(Self)
+            .map_err(|_| errors::DepthOverflow)
+    }
+}
+
+impl PartialEq<u8> for Depth {
+    fn eq(&self, other: &u8) -> bool {
+        self.0 == *other
+    }
+}
+
+impl core::ops::Deref for Depth {
+    type Target = u8;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Display for Depth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Version bytes of serialized extended keys
+///
+/// Version bytes determine the prefix of encoded key (e.g. `xpub`, `tpub`, `zpub`). Implement
+/// this trait to support version bytes that are not in [`slip132`](crate::slip132) registry.
+pub trait KeyVersion: Sized {
+    /// Version bytes of extended public key
+    fn public_version(&self) -> [u8; 4];
+    /// Version bytes of extended secret key
+    fn secret_version(&self) -> [u8; 4];
+    /// Recognizes version bytes of extended public key
+    fn from_public_version(version: [u8; 4]) -> Option<Self>;
+    /// Recognizes version bytes of extended secret key
+    fn from_secret_version(version: [u8; 4]) -> Option<Self>;
+}
+
+/// Network which extended keys are serialized for
+///
+/// Determines version bytes: `xpub`/`xprv` on mainnet, `tpub`/`tprv` on testnet (and other test
+/// networks like signet and regtest). Can be passed to [`Xpub::encode_with_version`] and
+/// [`Xpub::decode_with_version`] (and the same methods of [`Xprv`]).
+///
+/// ### Example
+/// ```rust
+/// use slip_10::{bip32::{Network, Xprv}, supported_curves::Secp256k1};
+///
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+/// let xprv = Xprv {
+///     metadata: slip_10::bip32::Metadata::MASTER,
+///     key: master_key,
+/// };
+/// let tprv = xprv.encode_with_version(&Network::Testnet).to_string();
+/// assert!(tprv.starts_with("tprv"));
+///
+/// let (_, network) = Xprv::<Secp256k1>::decode_with_version::<Network>(&tprv)?;
+/// assert_eq!(network, Network::Testnet);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Network {
+    /// Mainnet: `xpub`/`xprv`
+    Mainnet,
+    /// Testnet: `tpub`/`tprv`
+    Testnet,
+    /// Custom version bytes
+    ///
+    /// They are never recognized when decoding a key: implement [`KeyVersion`] to decode keys
+    /// with custom version bytes.
+    Custom {
+        /// Version bytes of extended public key
+        public: [u8; 4],
+        /// Version bytes of extended secret key
+        secret: [u8; 4],
+    },
+}
+
+impl KeyVersion for Network {
+    fn public_version(&self) -> [u8; 4] {
+        match self {
+            Self::Mainnet => [0x04, 0x88, 0xB2, 0x1E],
+            Self::Testnet => [0x04, 0x35, 0x87, 0xCF],
+            Self::Custom { public, .. } => *public,
+        }
+    }
+
+    fn secret_version(&self) -> [u8; 4] {
+        match self {
+            Self::Mainnet => [0x04, 0x88, 0xAD, 0xE4],
+            Self::Testnet => [0x04, 0x35, 0x83, 0x94],
+            Self::Custom { secret, .. } => *secret,
+        }
+    }
+
+    fn from_public_version(version: [u8; 4]) -> Option<Self> {
+        [Self::Mainnet, Self::Testnet]
+            .into_iter()
+            .find(|n| n.public_version() == version)
+    }
+
+    fn from_secret_version(version: [u8; 4]) -> Option<Self> {
+        [Self::Mainnet, Self::Testnet]
+            .into_iter()
+            .find(|n| n.secret_version() == version)
+    }
+}
+
+/// Extended public key with BIP32 metadata, serializable to `xpub...` string
+#[derive(Clone, Copy, Debug)]
+pub struct Xpub<E: Curve> {
+    /// BIP32 metadata
+    pub metadata: Metadata,
+    /// Extended public key
+    pub key: ExtendedPublicKey<E>,
+}
+
+/// Extended secret key with BIP32 metadata, serializable to `xprv...` string
+#[derive(Clone, Debug)]
+pub struct Xprv<E: Curve> {
+    /// BIP32 metadata
+    pub metadata: Metadata,
+    /// Extended secret key
+    pub key: ExtendedSecretKey<E>,
+}
+
+/// Extended key pair along with its BIP32 metadata
+///
+/// Metadata is updated automatically on each derivation: depth is incremented, parent
+/// fingerprint and child number are set accordingly. The key can be converted into
+/// [`Xpub`] and [`Xprv`] at any point.
+#[derive(Clone, Debug)]
+pub struct Bip32ExtendedKey<E: Curve> {
+    metadata: Metadata,
+    key: ExtendedKeyPair<E>,
+}
+
+impl<E: Curve> Bip32ExtendedKey<E> {
+    /// Wraps a master key
+    pub fn master(key: impl Into<ExtendedKeyPair<E>>) -> Self {
+        Self {
+            metadata: Metadata::MASTER,
+            key: key.into(),
+        }
+    }
+
+    /// Wraps a key with given metadata
+    pub fn new(metadata: Metadata, key: impl Into<ExtendedKeyPair<E>>) -> Self {
+        Self {
+            metadata,
+            key: key.into(),
+        }
+    }
+
+    /// Returns BIP32 metadata of the key
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    /// Returns the extended key pair
+    pub fn key(&self) -> &ExtendedKeyPair<E> {
+        &self.key
+    }
+
+    /// Derives a child key, updating the metadata
+    ///
+    /// Returns an error if the key is already at [maximum depth](Depth::MAX)
+    pub fn derive_child(
+        &self,
+        child_index: impl Into<ChildIndex>,
+    ) -> Result<Self, errors::DeriveError> {
+        let child_number = child_index.into();
+        let Ok(depth) = self.metadata.depth.child() else {
+            return Err(errors::DeriveError::DepthOverflow {
+                level: 0,
+                child_index: child_number,
+            });
+        };
+        Ok(Self {
+            metadata: Metadata {
+                depth,
+                parent_fingerprint: self.key.public_key().fingerprint(),
+                child_number,
+            },
+            key: crate::derive_child_key_pair(&self.key, child_number),
+        })
+    }
+
+    /// Derives a child key with specified derivation path, updating the metadata
+    ///
+    /// If derivation path is empty, a copy of `self` is returned. Returns an error
+    /// if the resulting key would exceed [maximum depth](Depth::MAX).
+    pub fn derive_child_with_path(
+        &self,
+        path: impl IntoIterator<Item = impl Into<ChildIndex>>,
+    ) -> Result<Self, errors::DeriveError> {
+        let mut derived_key = self.clone();
+        for (level, child_index) in path.into_iter().enumerate() {
+            let child_index = child_index.into();
+            derived_key = derived_key
+                .derive_child(child_index)
+                .map_err(|err| err.at_level(level))?;
+        }
+        Ok(derived_key)
+    }
+
+    /// Promotes the key into a standalone master key, resetting the metadata to
+    /// [`Metadata::MASTER`]
+    ///
+    /// Useful for handing off a subtree to another service (e.g. a sub-wallet), which then
+    /// treats the key as its own master key. Keys derived from the re-rooted key are the same as
+    /// before, only the metadata differs.
+    ///
+    /// Re-rooting only changes how the key is presented, it doesn't detach it from the original
+    /// tree:
+    /// * If the key is a non-hardened child, its secret key along with the parent extended public
+    ///   key reveals the parent secret key. Only hand off subtrees rooted at a hardened child.
+    /// * Whoever holds an ancestor key can still derive the re-rooted key and all its children.
+    /// * Depth and parent fingerprint are lost, so wallets can no longer tell where the key
+    ///   originates from. Keep the original path if it's needed later, e.g. for key origin in
+    ///   descriptors.
+    pub fn into_root(self) -> Self {
+        Self {
+            metadata: Metadata::MASTER,
+            ..self
+        }
+    }
+
+    /// Returns the extended public key along with the metadata
+    pub fn to_xpub(&self) -> Xpub<E> {
+        Xpub {
+            metadata: self.metadata,
+            key: *self.key.public_key(),
+        }
+    }
+
+    /// Returns the extended secret key along with the metadata
+    pub fn to_xprv(&self) -> Xprv<E> {
+        Xprv {
+            metadata: self.metadata,
+            key: self.key.secret_key().clone(),
+        }
+    }
+}
+
+impl<E: Curve> Xpub<E> {
+    /// Promotes the key into a standalone master key, resetting the metadata to
+    /// [`Metadata::MASTER`]
+    ///
+    /// Same as [`Bip32ExtendedKey::into_root`], see its docs for security implications.
+    pub fn into_root(self) -> Self {
+        Self {
+            metadata: Metadata::MASTER,
+            ..self
+        }
+    }
+}
+
+impl<E: Curve> Xprv<E> {
+    /// Promotes the key into a standalone master key, resetting the metadata to
+    /// [`Metadata::MASTER`]
+    ///
+    /// Same as [`Bip32ExtendedKey::into_root`], see its docs for security implications.
+    pub fn into_root(self) -> Self {
+        Self {
+            metadata: Metadata::MASTER,
+            ..self
+        }
+    }
+}
+
+impl<E: Curve> From<Xprv<E>> for Bip32ExtendedKey<E> {
+    fn from(xprv: Xprv<E>) -> Self {
+        Self::new(xprv.metadata, xprv.key)
+    }
+}
+
+impl<E: Curve + SupportedCurve> Xpub<E> {
+    /// Version bytes of mainnet extended public key
+    ///
+    /// Use [`Network`] to serialize the key for other networks
+    pub const VERSION: [u8; 4] = [0x04, 0x88, 0xB2, 0x1E];
+
+    /// Serializes the key into 78 bytes as defined in BIP32
+    pub fn to_bytes(&self) -> [u8; SERIALIZED_LEN] {
+        self.to_bytes_with_version(Self::VERSION)
+    }
+
+    /// Deserializes the key from 78 bytes as defined in BIP32
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, errors::ParseExtendedKeyError> {
+        Self::from_bytes_with_version(Self::VERSION, bytes)
+    }
+
+    /// Serializes the key into 78 bytes with given version bytes
+    pub fn to_bytes_with_version(&self, version: [u8; 4]) -> [u8; SERIALIZED_LEN] {
+        let () = crate::AssertEncodingSizes::<E>::OK;
+        let mut key = [0u8; 33];
+        key.copy_from_slice(&self.key.public_key.to_bytes(true));
+        serialize(version, &self.metadata, &self.key.chain_code, &key)
+    }
+
+    /// Encodes the key into Base58Check string with version bytes of `version`
+    ///
+    /// ### Example
+    /// ```rust
+    /// use slip_10::{bip32::Xpub, slip132::Slip132, supported_curves::Secp256k1};
+    ///
+    /// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+    /// # let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+    /// # let xpub = Xpub {
+    /// #     metadata: slip_10::bip32::Metadata::MASTER,
+    /// #     key: slip_10::ExtendedPublicKey::from(&master_key),
+    /// # };
+    /// let zpub = xpub.encode_with_version(&Slip132::Zpub).to_string();
+    /// assert!(zpub.starts_with("zpub"));
+    ///
+    /// let (decoded, version) = Xpub::<Secp256k1>::decode_with_version::<Slip132>(&zpub)?;
+    /// assert_eq!(version, Slip132::Zpub);
+    /// assert_eq!(decoded.to_bytes(), xpub.to_bytes());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn encode_with_version<V: KeyVersion>(&self, version: &V) -> impl fmt::Display {
+        Base58Check(self.to_bytes_with_version(version.public_version()))
+    }
+
+    /// Decodes the key from Base58Check string, recognizing its version bytes
+    ///
+    /// Returns an error if version bytes are not recognized by `V`
+    pub fn decode_with_version<V: KeyVersion>(
+        s: &str,
+    ) -> Result<(Self, V), errors::ParseExtendedKeyError> {
+        let bytes = decode_base58check(s)?;
+        let version = V::from_public_version(version_of(&bytes))
+            .ok_or(errors::ParseExtendedKeyError::UnknownVersion)?;
+        let key = Self::from_bytes_with_version(version.public_version(), &bytes)?;
+        Ok((key, version))
+    }
+
+    fn from_bytes_with_version(
+        version: [u8; 4],
+        bytes: &[u8],
+    ) -> Result<Self, errors::ParseExtendedKeyError> {
+        let (metadata, chain_code, key) = deserialize(version, bytes)?;
+        let public_key = Point::<E>::from_bytes(key)
+            .ok()
+            .filter(|p| !p.is_zero())
+            .ok_or(errors::ParseExtendedKeyError::InvalidKey)?;
+        Ok(Self {
+            metadata,
+            key: ExtendedPublicKey {
+                public_key,
+                chain_code,
+            },
+        })
+    }
+}
+
+impl<E: Curve + SupportedCurve> Xprv<E> {
+    /// Version bytes of mainnet extended secret key
+    ///
+    /// Use [`Network`] to serialize the key for other networks
+    pub const VERSION: [u8; 4] = [0x04, 0x88, 0xAD, 0xE4];
+
+    /// Serializes the key into 78 bytes as defined in BIP32
+    pub fn to_bytes(&self) -> [u8; SERIALIZED_LEN] {
+        self.to_bytes_with_version(Self::VERSION)
+    }
+
+    /// Deserializes the key from 78 bytes as defined in BIP32
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, errors::ParseExtendedKeyError> {
+        Self::from_bytes_with_version(Self::VERSION, bytes)
+    }
+
+    /// Serializes the key into 78 bytes with given version bytes
+    pub fn to_bytes_with_version(&self, version: [u8; 4]) -> [u8; SERIALIZED_LEN] {
+        let () = crate::AssertEncodingSizes::<E>::OK;
+        let mut key = [0u8; 33];
+        let mut secret_key = self.key.secret_key.as_ref().to_be_bytes();
+        key[1..].copy_from_slice(&secret_key);
+        crate::erase(secret_key.as_mut());
+        let serialized = serialize(version, &self.metadata, &self.key.chain_code, &key);
+        crate::erase(&mut key);
+        serialized
+    }
+
+    /// Encodes the key into Base58Check string with version bytes of `version`
+    pub fn encode_with_version<V: KeyVersion>(&self, version: &V) -> impl fmt::Display {
+        Base58Check(self.to_bytes_with_version(version.secret_version()))
+    }
+
+    /// Decodes the key from Base58Check string, recognizing its version bytes
+    ///
+    /// Returns an error if version bytes are not recognized by `V`
+    pub fn decode_with_version<V: KeyVersion>(
+        s: &str,
+    ) -> Result<(Self, V), errors::ParseExtendedKeyError> {
+        let bytes = decode_base58check(s)?;
+        let version = V::from_secret_version(version_of(&bytes))
+            .ok_or(errors::ParseExtendedKeyError::UnknownVersion)?;
+        let key = Self::from_bytes_with_version(version.secret_version(), &bytes)?;
+        Ok((key, version))
+    }
+
+    fn from_bytes_with_version(
+        version: [u8; 4],
+        bytes: &[u8],
+    ) -> Result<Self, errors::ParseExtendedKeyError> {
+        let (metadata, chain_code, key) = deserialize(version, bytes)?;
+        if key[0] != 0 {
+            return Err(errors::ParseExtendedKeyError::InvalidKey);
+        }
+        let mut secret_key = Scalar::<E>::from_be_bytes(&key[1..])
+            .ok()
+            .filter(|sk| !bool::from(subtle::ConstantTimeEq::ct_eq(sk, &Scalar::zero())))
+            .ok_or(errors::ParseExtendedKeyError::InvalidKey)?;
+        Ok(Self {
+            metadata,
+            key: ExtendedSecretKey {
+                secret_key: SecretScalar::new(&mut secret_key),
+                chain_code,
+            },
+        })
+    }
+}
+
+impl<E: Curve + SupportedCurve> fmt::Display for Xpub<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_base58check(f, &self.to_bytes())
+    }
+}
+
+impl<E: Curve + SupportedCurve> fmt::Display for Xprv<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_base58check(f, &self.to_bytes())
+    }
+}
+
+/// Serialized key displayed as Base58Check string
+struct Base58Check([u8; SERIALIZED_LEN]);
+
+impl fmt::Display for Base58Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_base58check(f, &self.0)
+    }
+}
+
+impl<E: Curve + SupportedCurve> core::str::FromStr for Xpub<E> {
+    type Err = errors::ParseExtendedKeyError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(&decode_base58check(s)?)
+    }
+}
+
+impl<E: Curve + SupportedCurve> core::str::FromStr for Xprv<E> {
+    type Err = errors::ParseExtendedKeyError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(&decode_base58check(s)?)
+    }
+}
+
+fn serialize(
+    version: [u8; 4],
+    metadata: &Metadata,
+    chain_code: &ChainCode,
+    key: &[u8; 33],
+) -> [u8; SERIALIZED_LEN] {
+    let mut out = [0u8; SERIALIZED_LEN];
+    out[0..4].copy_from_slice(&version);
+    out[4] = *metadata.depth;
+    out[5..9].copy_from_slice(&metadata.parent_fingerprint);
+    out[9..13].copy_from_slice(&u32::from(metadata.child_number).to_be_bytes());
+    out[13..45].copy_from_slice(chain_code.as_slice());
+    out[45..].copy_from_slice(key);
+    out
+}
+
+fn deserialize(
+    expected_version: [u8; 4],
+    bytes: &[u8],
+) -> Result<(Metadata, ChainCode, [u8; 33]), errors::ParseExtendedKeyError> {
+    let bytes: &[u8; SERIALIZED_LEN] = bytes
+        .try_into()
+        .map_err(|_| errors::ParseExtendedKeyError::InvalidLength(errors::InvalidLength))?;
+    if bytes[0..4] != expected_version {
+        return Err(errors::ParseExtendedKeyError::UnknownVersion);
+    }
+
+    let metadata = Metadata {
+        depth: Depth::new(bytes[4]),
+        parent_fingerprint: bytes[5..9].try_into().expect("exactly 4 bytes"),
+        child_number: u32::from_be_bytes(bytes[9..13].try_into().expect("exactly 4 bytes")).into(),
+    };
+    if metadata.depth == Depth::MASTER
+        && (metadata.parent_fingerprint != [0; 4] || u32::from(metadata.child_number) != 0)
+    {
+        return Err(errors::ParseExtendedKeyError::InvalidMetadata);
+    }
+
+    let chain_code = ChainCode::new(bytes[13..45].try_into().expect("exactly 32 bytes"));
+    let key = bytes[45..].try_into().expect("exactly 33 bytes");
+    Ok((metadata, chain_code, key))
+}
+
+fn version_of(bytes: &[u8; SERIALIZED_LEN]) -> [u8; 4] {
+    bytes[0..4].try_into().expect("exactly 4 bytes")
+}
+
+fn checksum(payload: &[u8]) -> [u8; 4] {
+    let hash = sha2::Sha256::digest(sha2::Sha256::digest(payload));
+    hash[..4].try_into().expect("exactly 4 bytes")
+}
+
+fn write_base58check(f: &mut fmt::Formatter<'_>, payload: &[u8; SERIALIZED_LEN]) -> fmt::Result {
+    let mut checked = [0u8; CHECKED_LEN];
+    checked[..SERIALIZED_LEN].copy_from_slice(payload);
+    checked[SERIALIZED_LEN..].copy_from_slice(&checksum(payload));
+
+    let mut encoded = [0u8; ENCODED_MAX_LEN];
+    let len = bs58::encode(checked)
+        .onto(&mut encoded[..])
+        .map_err(|_| fmt::Error)?;
+    let encoded = core::str::from_utf8(&encoded[..len]).map_err(|_| fmt::Error)?;
+    f.write_str(encoded)
+}
+
+fn decode_base58check(s: &str) -> Result<[u8; SERIALIZED_LEN], errors::ParseExtendedKeyError> {
+    let mut checked = [0u8; CHECKED_LEN];
+    let len = bs58::decode(s)
+        .onto(&mut checked)
+        .map_err(|err| match err {
+            bs58::decode::Error::BufferTooSmall => {
+                errors::ParseExtendedKeyError::InvalidLength(errors::InvalidLength)
+            }
+            _ => errors::ParseExtendedKeyError::Base58,
+        })?;
+    if len != CHECKED_LEN {
+        return Err(errors::ParseExtendedKeyError::InvalidLength(
+            errors::InvalidLength,
+        ));
+    }
+
+    let (payload, expected_checksum) = checked.split_at(SERIALIZED_LEN);
+    if checksum(payload) != expected_checksum {
+        return Err(errors::ParseExtendedKeyError::InvalidChecksum);
+    }
+    Ok(payload.try_into().expect("exactly 78 bytes"))
+}
@@ -0,0 +1,382 @@
+//! BIP32-Ed25519 derivation scheme
+//!
+//! **This is not SLIP10.** [BIP32-Ed25519][paper] (by Khovratovich and Law) is a different
+//! derivation scheme for ed25519 that, unlike SLIP10, supports non-hardened derivation, and
+//! therefore deriving child public keys from parent public key. It's used by Cardano wallets
+//! and Ledger apps. Keys derived by this module are not compatible with keys derived by
+//! [ed25519](crate::ed25519) module, even if the same seed and path are used.
+//!
+//! Extended secret key consists of two halves $k_L || k_R$ and a chain code. $k_L$ is a
+//! clamped scalar which public key is computed from (note that it's used as is, not hashed
+//! as in RFC8032), and $k_R$ is used as a nonce source when signing.
+//!
+//! Child indexes are serialized in little-endian as defined by V2 derivation scheme.
+//!
+//! ### Example
+//! Derive a child key m/1852<sub>H</sub>/1815<sub>H</sub>/0<sub>H</sub>/0/0:
+//! ```rust
+//! use slip_10::{bip32_ed25519, H};
+//!
+//! let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = bip32_ed25519::derive_master_key(&seed);
+//! let master_key_pair = bip32_ed25519::ExtendedKeyPair::from(master_key);
+//!
+//! let account_key_pair = bip32_ed25519::derive_child_key_pair_with_path(
+//!     &master_key_pair,
+//!     [1852 + H, 1815 + H, 0 + H],
+//! );
+//! // Child public keys can be derived from parent public key
+//! let address_public_key = bip32_ed25519::derive_child_public_key_with_path(
+//!     account_key_pair.public_key(),
+//!     [0.try_into()?, 0.try_into()?],
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [paper]: https://input-output-hk.github.io/adrestia/static/Ed25519_BIP.pdf
+
+use generic_ec::{curves::Ed25519, Point, Scalar, SecretScalar};
+use hmac::Mac as _;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use crate::{ChainCode, ChildIndex, HmacSha512, NonHardenedIndex, Seed};
+
+/// Extended BIP32-Ed25519 public key
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExtendedPublicKey {
+    /// The public key that can be used for signature verification
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<generic_ec::serde::PreferCompact>")
+    )]
+    pub public_key: Point<Ed25519>,
+    /// A chain code that is used to derive child keys
+    pub chain_code: ChainCode,
+}
+
+/// Extended BIP32-Ed25519 secret key
+///
+/// `Debug` output doesn't include the secret key unless `insecure-debug` feature is enabled.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExtendedSecretKey {
+    /// The secret key $k_L || k_R$ that can be used for signing
+    ///
+    /// $k_L$ (first 32 bytes) is a scalar in little-endian that defines the public key,
+    /// $k_R$ (last 32 bytes) is used to derive nonces when signing.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<crate::serde_utils::Bytes>")
+    )]
+    pub secret_key: [u8; 64],
+    /// A chain code that is used to derive child keys
+    pub chain_code: ChainCode,
+}
+
+/// Pair of extended BIP32-Ed25519 secret and public keys
+#[derive(Clone, Debug)]
+pub struct ExtendedKeyPair {
+    public_key: ExtendedPublicKey,
+    secret_key: ExtendedSecretKey,
+}
+
+impl core::fmt::Debug for ExtendedSecretKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ExtendedSecretKey")
+            .field(
+                "secret_key",
+                &crate::DebugSecret("SecretKey", &self.secret_key[..]),
+            )
+            .field("chain_code", &self.chain_code)
+            .finish()
+    }
+}
+
+impl From<&ExtendedSecretKey> for ExtendedPublicKey {
+    fn from(sk: &ExtendedSecretKey) -> Self {
+        let (k_l, _) = split(&sk.secret_key);
+        let mut k_l = Scalar::<Ed25519>::from_le_bytes_mod_order(k_l);
+        let k_l = SecretScalar::new(&mut k_l);
+        ExtendedPublicKey {
+            public_key: Point::generator() * &k_l,
+            chain_code: sk.chain_code,
+        }
+    }
+}
+
+impl From<ExtendedSecretKey> for ExtendedKeyPair {
+    fn from(secret_key: ExtendedSecretKey) -> Self {
+        Self {
+            public_key: (&secret_key).into(),
+            secret_key,
+        }
+    }
+}
+
+impl ExtendedKeyPair {
+    /// Returns chain code of the key
+    pub fn chain_code(&self) -> &ChainCode {
+        debug_assert_eq!(self.public_key.chain_code, self.secret_key.chain_code);
+        &self.public_key.chain_code
+    }
+
+    /// Returns extended public key
+    pub fn public_key(&self) -> &ExtendedPublicKey {
+        &self.public_key
+    }
+
+    /// Returns extended secret key
+    pub fn secret_key(&self) -> &ExtendedSecretKey {
+        &self.secret_key
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for ExtendedSecretKey {
+    fn zeroize(&mut self) {
+        self.secret_key.zeroize();
+        self.chain_code.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for ExtendedKeyPair {
+    fn zeroize(&mut self) {
+        self.public_key.public_key.zeroize();
+        self.public_key.chain_code.zeroize();
+        self.secret_key.zeroize();
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ExtendedKeyPair {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.secret_key.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ExtendedKeyPair {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let secret_key = ExtendedSecretKey::deserialize(deserializer)?;
+        Ok(secret_key.into())
+    }
+}
+
+/// Derives a master key from the seed
+///
+/// Master key is derived as implemented by Ledger: $k_L || k_R$ is computed as
+/// `HMAC-SHA512("ed25519 seed", seed)` (rehashed until third highest bit of $k_L$ is cleared), and
+/// then $k_L$ is clamped. Chain code is `HMAC-SHA256("ed25519 seed", 0x01 || seed)`. Note that
+/// Cardano wallets derive master key from the mnemonic differently.
+pub fn derive_master_key(seed: impl AsRef<Seed>) -> ExtendedSecretKey {
+    let seed = seed.as_ref().as_bytes();
+
+    const CURVE_TAG: &[u8] = b"ed25519 seed";
+    let hmac = HmacSha512::new_from_slice(CURVE_TAG)
+        .expect("this never fails: hmac can handle keys of any size");
+    let mut i = hmac.clone().chain_update(seed).finalize().into_bytes();
+    while i[31] & 0b0010_0000 != 0 {
+        let next_i = hmac.clone().chain_update(&i[..]).finalize().into_bytes();
+        crate::erase(&mut i);
+        i = next_i;
+    }
+
+    let mut secret_key = [0u8; 64];
+    secret_key.copy_from_slice(&i);
+    crate::erase(&mut i);
+    secret_key[0] &= 0b1111_1000;
+    secret_key[31] &= 0b0111_1111;
+    secret_key[31] |= 0b0100_0000;
+
+    let chain_code = hmac::Hmac::<sha2::Sha256>::new_from_slice(CURVE_TAG)
+        .expect("this never fails: hmac can handle keys of any size")
+        .chain_update([0x01])
+        .chain_update(seed)
+        .finalize()
+        .into_bytes();
+
+    ExtendedSecretKey {
+        secret_key,
+        chain_code: ChainCode::new(chain_code.into()),
+    }
+}
+
+/// Derives child key pair (extended secret key + public key) from parent key pair
+///
+/// Unlike SLIP10 ed25519, both hardened and non-hardened derivations are supported.
+pub fn derive_child_key_pair(
+    parent_key: &ExtendedKeyPair,
+    child_index: impl Into<ChildIndex>,
+) -> ExtendedKeyPair {
+    let child_index = child_index.into();
+    let hmac = HmacSha512::new_from_slice(parent_key.chain_code().as_slice())
+        .expect("this never fails: hmac can handle keys of any size");
+    let index = u32::from(child_index).to_le_bytes();
+    let (mut z, mut i) = match child_index {
+        ChildIndex::Hardened(_) => {
+            let z = hmac
+                .clone()
+                .chain_update([0x00])
+                .chain_update(parent_key.secret_key.secret_key)
+                .chain_update(index)
+                .finalize()
+                .into_bytes();
+            let i = hmac
+                .chain_update([0x01])
+                .chain_update(parent_key.secret_key.secret_key)
+                .chain_update(index)
+                .finalize()
+                .into_bytes();
+            (z, i)
+        }
+        ChildIndex::NonHardened(_) => {
+            let public_key = parent_key.public_key.public_key.to_bytes(true);
+            let z = hmac
+                .clone()
+                .chain_update([0x02])
+                .chain_update(&public_key)
+                .chain_update(index)
+                .finalize()
+                .into_bytes();
+            let i = hmac
+                .chain_update([0x03])
+                .chain_update(&public_key)
+                .chain_update(index)
+                .finalize()
+                .into_bytes();
+            (z, i)
+        }
+    };
+    let (z_l, z_r) = crate::split_into_two_halfes(&z);
+    let (_, chain_code) = crate::split_into_two_halfes(&i);
+
+    let (mut k_l, mut k_r) = split(&parent_key.secret_key.secret_key);
+    let mut child_k_l = add_28_mul8(&k_l, &(*z_l).into());
+    let mut child_k_r = add_256bits(&k_r, &(*z_r).into());
+    let mut secret_key = [0u8; 64];
+    secret_key[..32].copy_from_slice(&child_k_l);
+    secret_key[32..].copy_from_slice(&child_k_r);
+    let chain_code = ChainCode::new((*chain_code).into());
+
+    for secret in [&mut k_l, &mut k_r, &mut child_k_l, &mut child_k_r] {
+        crate::erase(secret);
+    }
+    crate::erase(&mut z);
+    crate::erase(&mut i);
+
+    ExtendedSecretKey {
+        secret_key,
+        chain_code,
+    }
+    .into()
+}
+
+/// Derives a child key pair with specified derivation path from parent key pair
+///
+/// Derivation path is an iterator that yields child indexes.
+///
+/// If derivation path is empty, `parent_key` is returned
+pub fn derive_child_key_pair_with_path(
+    parent_key: &ExtendedKeyPair,
+    path: impl IntoIterator<Item = impl Into<ChildIndex>>,
+) -> ExtendedKeyPair {
+    let mut derived_key = parent_key.clone();
+    for child_index in path {
+        let child_key = derive_child_key_pair(&derived_key, child_index);
+        crate::erase_key(&mut derived_key);
+        derived_key = child_key;
+    }
+    derived_key
+}
+
+/// Derives child extended public key from parent extended public key
+///
+/// Only non-hardened child keys can be derived from the public key.
+pub fn derive_child_public_key(
+    parent_public_key: &ExtendedPublicKey,
+    child_index: NonHardenedIndex,
+) -> ExtendedPublicKey {
+    let hmac = HmacSha512::new_from_slice(parent_public_key.chain_code.as_slice())
+        .expect("this never fails: hmac can handle keys of any size");
+    let public_key = parent_public_key.public_key.to_bytes(true);
+    let index = child_index.to_le_bytes();
+    let z = hmac
+        .clone()
+        .chain_update([0x02])
+        .chain_update(&public_key)
+        .chain_update(index)
+        .finalize()
+        .into_bytes();
+    let i = hmac
+        .chain_update([0x03])
+        .chain_update(&public_key)
+        .chain_update(index)
+        .finalize()
+        .into_bytes();
+    let (z_l, _) = crate::split_into_two_halfes(&z);
+    let (_, chain_code) = crate::split_into_two_halfes(&i);
+
+    let shift = Scalar::<Ed25519>::from_le_bytes_mod_order(add_28_mul8(&[0; 32], &(*z_l).into()));
+    ExtendedPublicKey {
+        public_key: parent_public_key.public_key + Point::generator() * shift,
+        chain_code: ChainCode::new((*chain_code).into()),
+    }
+}
+
+/// Derives a child public key with specified derivation path
+///
+/// Derivation path is an iterator that yields non-hardened child indexes.
+///
+/// If derivation path is empty, `parent_public_key` is returned
+pub fn derive_child_public_key_with_path(
+    parent_public_key: &ExtendedPublicKey,
+    path: impl IntoIterator<Item = NonHardenedIndex>,
+) -> ExtendedPublicKey {
+    path.into_iter()
+        .fold(*parent_public_key, |key, child_index| {
+            derive_child_public_key(&key, child_index)
+        })
+}
+
+/// Splits $k_L || k_R$ into two halves
+fn split(secret_key: &[u8; 64]) -> ([u8; 32], [u8; 32]) {
+    let mut k_l = [0u8; 32];
+    let mut k_r = [0u8; 32];
+    k_l.copy_from_slice(&secret_key[..32]);
+    k_r.copy_from_slice(&secret_key[32..]);
+    (k_l, k_r)
+}
+
+/// Computes $x + 8 \cdot y_{[0..28]}$ where numbers are 256 bits little-endian integers
+fn add_28_mul8(x: &[u8; 32], y: &[u8; 32]) -> [u8; 32] {
+    let mut carry = 0u16;
+    let mut out = [0u8; 32];
+    for i in 0..32 {
+        let y = if i < 28 { u16::from(y[i]) << 3 } else { 0 };
+        let r = u16::from(x[i]) + y + carry;
+        out[i] = r as u8;
+        carry = r >> 8;
+    }
+    out
+}
+
+/// Computes $x + y \mod 2^{256}$ where numbers are 256 bits little-endian integers
+fn add_256bits(x: &[u8; 32], y: &[u8; 32]) -> [u8; 32] {
+    let mut carry = 0u16;
+    let mut out = [0u8; 32];
+    for i in 0..32 {
+        let r = u16::from(x[i]) + u16::from(y[i]) + carry;
+        out[i] = r as u8;
+        carry = r >> 8;
+    }
+    out
+}
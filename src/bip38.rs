@@ -0,0 +1,300 @@
+//! [BIP38][bip38] passphrase-protected secp256k1 secret keys
+//!
+//! BIP38 is a legacy format of encrypted keys (`6P...`) supported by paper wallets and older
+//! tooling. Only non-EC-multiplied mode is supported: [`encrypt`] encrypts a derived secret key
+//! under the passphrase, and [`decrypt`] decrypts it back.
+//!
+//! Passphrase must be normalized to Unicode NFC by the caller if it contains non-ASCII
+//! characters. Encryption and decryption are deliberately slow (scrypt with $N = 2^{14}, r = 8, p = 8$).
+//!
+//! ### Extended keys
+//! BIP38 doesn't define encryption of extended keys. [`encrypt_extended_key`] is an analogue
+//! which follows BIP38 as closely as possible, but isn't understood by other software:
+//! 1. `addresshash`, `derivedhalf1`, and `derivedhalf2` are computed as in BIP38, using the
+//!    compressed P2PKH address
+//! 2. Secret key is encrypted exactly as in BIP38
+//! 3. Chain code is XORed with encrypted secret key (chaining as in CBC mode), and encrypted with
+//!    AES-256 in two 16-bytes blocks using `derivedhalf2` as a key
+//! 4. Payload is `0x01 0x44 0xe0 || addresshash || encrypted`, encoded as Base58Check
+//!
+//! ### Example
+//! ```rust
+//! use slip_10::{bip38, supported_curves::Secp256k1};
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//! let master_key = slip_10::ExtendedKeyPair::from(master_key);
+//! let key = slip_10::derive_child_key_pair_with_path(&master_key, slip_10::path!("m/44'/0'/0'/0/0"));
+//!
+//! let encrypted = bip38::encrypt(&key.secret_key().secret_key, "passphrase", true).to_string();
+//! assert!(encrypted.starts_with("6P"));
+//!
+//! let decrypted = bip38::decrypt(&encrypted, "passphrase")?;
+//! assert_eq!(decrypted.secret_key.as_ref(), key.secret_key().secret_key.as_ref());
+//! assert!(decrypted.compressed);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [bip38]: https://github.com/bitcoin/bips/blob/master/bip-0038.mediawiki
+
+use core::fmt;
+
+use aes::cipher::{generic_array::GenericArray, BlockDecrypt, BlockEncrypt, KeyInit};
+use generic_ec::{Point, Scalar, SecretScalar};
+use sha2::Digest as _;
+
+use crate::{errors, supported_curves::Secp256k1, ChainCode, ExtendedSecretKey};
+
+/// Prefix of non-EC-multiplied keys defined in BIP38
+const PREFIX: [u8; 2] = [0x01, 0x42];
+/// Prefix of encrypted extended keys (not defined in BIP38)
+const EXTENDED_KEY_PREFIX: [u8; 2] = [0x01, 0x44];
+/// Flag byte of keys corresponding to compressed public key
+const FLAG_COMPRESSED: u8 = 0xe0;
+/// Flag byte of keys corresponding to uncompressed public key
+const FLAG_UNCOMPRESSED: u8 = 0xc0;
+
+/// Size of payload of encrypted key: prefix, flag, address hash, and encrypted key
+const PAYLOAD_LEN: usize = 2 + 1 + 4 + 32;
+/// Size of payload of encrypted extended key
+const EXTENDED_KEY_PAYLOAD_LEN: usize = 2 + 1 + 4 + 64;
+/// Upper bound of Base58 encoding length of the encrypted extended key with checksum
+const ENCODED_MAX_LEN: usize = 104;
+
+/// Secret key decrypted from BIP38
+pub struct Decrypted {
+    /// Secret key
+    pub secret_key: SecretScalar<Secp256k1>,
+    /// Whether the key corresponds to compressed public key
+    pub compressed: bool,
+}
+
+/// Encrypts the secret key under the passphrase
+///
+/// `compressed` determines which address the key corresponds to. Derived keys are normally
+/// used with compressed public keys.
+pub fn encrypt(
+    secret_key: &SecretScalar<Secp256k1>,
+    passphrase: &str,
+    compressed: bool,
+) -> impl fmt::Display {
+    let public_key = Point::generator() * secret_key;
+    let address_hash = address_hash(&public_key, compressed);
+
+    let mut derived = [0u8; 64];
+    scrypt(passphrase, &address_hash, &mut derived);
+    let (derived_half1, derived_half2) = derived.split_at(32);
+
+    let mut encrypted = [0u8; 32];
+    encrypted.copy_from_slice(&secret_key.as_ref().to_be_bytes());
+    xor(&mut encrypted, derived_half1);
+    aes_encrypt(derived_half2, &mut encrypted);
+
+    let mut payload = [0u8; PAYLOAD_LEN];
+    payload[..2].copy_from_slice(&PREFIX);
+    payload[2] = if compressed {
+        FLAG_COMPRESSED
+    } else {
+        FLAG_UNCOMPRESSED
+    };
+    payload[3..7].copy_from_slice(&address_hash);
+    payload[7..].copy_from_slice(&encrypted);
+    Base58Check(payload)
+}
+
+/// Decrypts the secret key encrypted by [`encrypt`] or by other BIP38 implementation
+///
+/// Returns error if passphrase is wrong, or the key is EC-multiplied.
+pub fn decrypt(encrypted: &str, passphrase: &str) -> Result<Decrypted, errors::Bip38Error> {
+    let mut payload = [0u8; PAYLOAD_LEN];
+    decode(encrypted, &mut payload)?;
+    if payload[..2] != PREFIX {
+        return Err(errors::Bip38Error::UnsupportedMode);
+    }
+    let compressed = match payload[2] {
+        FLAG_COMPRESSED => true,
+        FLAG_UNCOMPRESSED => false,
+        _ => return Err(errors::Bip38Error::UnsupportedMode),
+    };
+    let expected_address_hash = &payload[3..7];
+
+    let mut derived = [0u8; 64];
+    scrypt(passphrase, expected_address_hash, &mut derived);
+    let (derived_half1, derived_half2) = derived.split_at(32);
+
+    let mut secret_key = [0u8; 32];
+    secret_key.copy_from_slice(&payload[7..]);
+    aes_decrypt(derived_half2, &mut secret_key);
+    xor(&mut secret_key, derived_half1);
+
+    let mut secret_key = parse_secret_key(&secret_key)?;
+    let public_key = Point::generator() * secret_key;
+    if address_hash(&public_key, compressed) != expected_address_hash {
+        return Err(errors::Bip38Error::WrongPassphrase);
+    }
+    Ok(Decrypted {
+        secret_key: SecretScalar::new(&mut secret_key),
+        compressed,
+    })
+}
+
+/// Encrypts the extended secret key under the passphrase
+///
+/// The format is not defined in BIP38, see [module-level docs](self) for details.
+pub fn encrypt_extended_key(
+    key: &ExtendedSecretKey<Secp256k1>,
+    passphrase: &str,
+) -> impl fmt::Display {
+    let public_key = Point::generator() * &key.secret_key;
+    let address_hash = address_hash(&public_key, true);
+
+    let mut derived = [0u8; 64];
+    scrypt(passphrase, &address_hash, &mut derived);
+    let (derived_half1, derived_half2) = derived.split_at(32);
+
+    let mut encrypted = [0u8; 64];
+    let (encrypted_key, encrypted_chain_code) = encrypted.split_at_mut(32);
+    encrypted_key.copy_from_slice(&key.secret_key.as_ref().to_be_bytes());
+    xor(encrypted_key, derived_half1);
+    aes_encrypt(derived_half2, encrypted_key);
+    encrypted_chain_code.copy_from_slice(key.chain_code.as_bytes());
+    xor(encrypted_chain_code, encrypted_key);
+    aes_encrypt(derived_half2, encrypted_chain_code);
+
+    let mut payload = [0u8; EXTENDED_KEY_PAYLOAD_LEN];
+    payload[..2].copy_from_slice(&EXTENDED_KEY_PREFIX);
+    payload[2] = FLAG_COMPRESSED;
+    payload[3..7].copy_from_slice(&address_hash);
+    payload[7..].copy_from_slice(&encrypted);
+    Base58Check(payload)
+}
+
+/// Decrypts the extended secret key encrypted by [`encrypt_extended_key`]
+pub fn decrypt_extended_key(
+    encrypted: &str,
+    passphrase: &str,
+) -> Result<ExtendedSecretKey<Secp256k1>, errors::Bip38Error> {
+    let mut payload = [0u8; EXTENDED_KEY_PAYLOAD_LEN];
+    decode(encrypted, &mut payload)?;
+    if payload[..2] != EXTENDED_KEY_PREFIX || payload[2] != FLAG_COMPRESSED {
+        return Err(errors::Bip38Error::UnsupportedMode);
+    }
+    let expected_address_hash = &payload[3..7];
+
+    let mut derived = [0u8; 64];
+    scrypt(passphrase, expected_address_hash, &mut derived);
+    let (derived_half1, derived_half2) = derived.split_at(32);
+
+    let mut decrypted = [0u8; 64];
+    decrypted.copy_from_slice(&payload[7..]);
+    let (secret_key, chain_code) = decrypted.split_at_mut(32);
+    aes_decrypt(derived_half2, chain_code);
+    xor(chain_code, &payload[7..39]);
+    aes_decrypt(derived_half2, secret_key);
+    xor(secret_key, derived_half1);
+
+    let mut secret_key = parse_secret_key(secret_key)?;
+    let public_key = Point::generator() * secret_key;
+    if address_hash(&public_key, true) != expected_address_hash {
+        return Err(errors::Bip38Error::WrongPassphrase);
+    }
+    Ok(ExtendedSecretKey {
+        secret_key: SecretScalar::new(&mut secret_key),
+        chain_code: ChainCode::new(
+            (&*chain_code)
+                .try_into()
+                .expect("this never fails: exactly 32 bytes"),
+        ),
+    })
+}
+
+/// Computes `addresshash`: first 4 bytes of SHA256d of P2PKH address
+fn address_hash(public_key: &Point<Secp256k1>, compressed: bool) -> [u8; 4] {
+    let public_key = public_key.to_bytes(compressed);
+    let hash160 = ripemd::Ripemd160::digest(sha2::Sha256::digest(&public_key));
+    let mut payload = [0u8; 21];
+    payload[1..].copy_from_slice(&hash160);
+
+    let mut checked = [0u8; 25];
+    checked[..21].copy_from_slice(&payload);
+    checked[21..].copy_from_slice(&checksum(&payload));
+    let mut address = [0u8; 34];
+    let len = bs58::encode(checked)
+        .onto(&mut address[..])
+        .expect("this never fails: address fits into 34 characters");
+
+    checksum(&address[..len])
+}
+
+fn scrypt(passphrase: &str, salt: &[u8], out: &mut [u8]) {
+    let params = scrypt::Params::new(14, 8, 8, out.len())
+        .expect("this never fails: BIP38 parameters are valid");
+    scrypt::scrypt(passphrase.as_bytes(), salt, &params, out)
+        .expect("this never fails: output length is valid");
+}
+
+fn aes_encrypt(key: &[u8], data: &mut [u8]) {
+    let cipher = aes::Aes256::new(GenericArray::from_slice(key));
+    data.chunks_exact_mut(16)
+        .for_each(|block| cipher.encrypt_block(GenericArray::from_mut_slice(block)));
+}
+
+fn aes_decrypt(key: &[u8], data: &mut [u8]) {
+    let cipher = aes::Aes256::new(GenericArray::from_slice(key));
+    data.chunks_exact_mut(16)
+        .for_each(|block| cipher.decrypt_block(GenericArray::from_mut_slice(block)));
+}
+
+fn xor(data: &mut [u8], pad: &[u8]) {
+    data.iter_mut().zip(pad).for_each(|(x, y)| *x ^= y);
+}
+
+fn parse_secret_key(bytes: &[u8]) -> Result<Scalar<Secp256k1>, errors::Bip38Error> {
+    Scalar::from_be_bytes(bytes)
+        .ok()
+        .filter(|sk| !bool::from(subtle::ConstantTimeEq::ct_eq(sk, &Scalar::zero())))
+        .ok_or(errors::Bip38Error::WrongPassphrase)
+}
+
+/// Decodes Base58Check string into `payload`
+fn decode(s: &str, payload: &mut [u8]) -> Result<(), errors::Bip38Error> {
+    let mut checked = [0u8; EXTENDED_KEY_PAYLOAD_LEN + 4];
+    let len = bs58::decode(s)
+        .onto(&mut checked)
+        .map_err(|err| match err {
+            bs58::decode::Error::BufferTooSmall => errors::Bip38Error::InvalidLength,
+            _ => errors::Bip38Error::Base58,
+        })?;
+    if len != payload.len() + 4 {
+        return Err(errors::Bip38Error::InvalidLength);
+    }
+    let (data, expected_checksum) = checked[..len].split_at(payload.len());
+    if checksum(data) != expected_checksum {
+        return Err(errors::Bip38Error::InvalidChecksum);
+    }
+    payload.copy_from_slice(data);
+    Ok(())
+}
+
+/// Payload displayed as Base58Check string
+struct Base58Check<const N: usize>([u8; N]);
+
+impl<const N: usize> fmt::Display for Base58Check<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut checked = [0u8; EXTENDED_KEY_PAYLOAD_LEN + 4];
+        checked[..N].copy_from_slice(&self.0);
+        checked[N..N + 4].copy_from_slice(&checksum(&self.0));
+
+        let mut encoded = [0u8; ENCODED_MAX_LEN];
+        let len = bs58::encode(&checked[..N + 4])
+            .onto(&mut encoded[..])
+            .map_err(|_| fmt::Error)?;
+        let encoded = core::str::from_utf8(&encoded[..len]).map_err(|_| fmt::Error)?;
+        f.write_str(encoded)
+    }
+}
+
+fn checksum(payload: &[u8]) -> [u8; 4] {
+    let hash = sha2::Sha256::digest(sha2::Sha256::digest(payload));
+    hash[..4].try_into().expect("exactly 4 bytes")
+}
@@ -0,0 +1,212 @@
+//! Typed [BIP44][bip44] derivation paths
+//!
+//! BIP44 defines a 5 levels path `m / purpose' / coin_type' / account' / change / address_index`,
+//! where the first three levels are hardened and the last two are not. Types in this module make
+//! it impossible to build a path that violates this: hardened levels only accept
+//! [`HardenedIndex`], and non-hardened ones only accept [`Change`] and [`NonHardenedIndex`].
+//!
+//! A path is built level by level: [`Bip44Path`] is an account-level path, which can be extended
+//! with [`change`](Bip44Path::change) and then with [`index`](Bip44ChangePath::index). Any of
+//! these can be converted into [`DerivationPath`].
+//!
+//! ### Example
+//! Derive a key m/44<sub>H</sub>/0<sub>H</sub>/0<sub>H</sub>/0/1:
+//! ```rust
+//! use slip_10::bip44::{Bip44Path, Change, Purpose};
+//! use slip_10::{HardenedIndex, NonHardenedIndex};
+//! use slip_10::supported_curves::Secp256k1;
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//! let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+//!
+//! let path = Bip44Path::new(Purpose::Bip44, HardenedIndex::new(0), HardenedIndex::new(0))
+//!     .change(Change::External)
+//!     .index(NonHardenedIndex::new(1))
+//!     .to_path();
+//! let child_key_pair = slip_10::derive_child_key_pair_with_path(&master_key_pair, &path);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [bip44]: https://github.com/bitcoin/bips/blob/master/bip-0044.mediawiki
+
+use crate::{DerivationPath, HardenedIndex, NonHardenedIndex, H};
+
+/// Purpose level of the path
+///
+/// Determines which BIP the rest of the path follows. All of them share the same path structure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Purpose {
+    /// [BIP44](https://github.com/bitcoin/bips/blob/master/bip-0044.mediawiki): 44<sub>H</sub>
+    Bip44,
+    /// [BIP49](https://github.com/bitcoin/bips/blob/master/bip-0049.mediawiki): 49<sub>H</sub>
+    Bip49,
+    /// [BIP84](https://github.com/bitcoin/bips/blob/master/bip-0084.mediawiki): 84<sub>H</sub>
+    Bip84,
+    /// [BIP86](https://github.com/bitcoin/bips/blob/master/bip-0086.mediawiki): 86<sub>H</sub>
+    Bip86,
+}
+
+impl From<Purpose> for HardenedIndex {
+    fn from(purpose: Purpose) -> Self {
+        let purpose = match purpose {
+            Purpose::Bip44 => 44,
+            Purpose::Bip49 => 49,
+            Purpose::Bip84 => 84,
+            Purpose::Bip86 => 86,
+        };
+        HardenedIndex(H + purpose)
+    }
+}
+
+/// Change level of the path
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Change {
+    /// External chain (receiving addresses): 0
+    External,
+    /// Internal chain (change addresses): 1
+    Internal,
+}
+
+impl From<Change> for NonHardenedIndex {
+    fn from(change: Change) -> Self {
+        match change {
+            Change::External => NonHardenedIndex(0),
+            Change::Internal => NonHardenedIndex(1),
+        }
+    }
+}
+
+/// Account-level path `m / purpose' / coin_type' / account'`
+#[derive(Clone, Copy, Debug)]
+pub struct Bip44Path {
+    purpose: Purpose,
+    coin_type: HardenedIndex,
+    account: HardenedIndex,
+}
+
+/// Change-level path `m / purpose' / coin_type' / account' / change`
+#[derive(Clone, Copy, Debug)]
+pub struct Bip44ChangePath {
+    account: Bip44Path,
+    change: Change,
+}
+
+/// Full path `m / purpose' / coin_type' / account' / change / address_index`
+#[derive(Clone, Copy, Debug)]
+pub struct Bip44AddressPath {
+    change: Bip44ChangePath,
+    index: NonHardenedIndex,
+}
+
+impl Bip44Path {
+    /// Constructs an account-level path
+    pub fn new(purpose: Purpose, coin_type: HardenedIndex, account: HardenedIndex) -> Self {
+        Self {
+            purpose,
+            coin_type,
+            account,
+        }
+    }
+
+    /// Extends the path with a change level
+    pub fn change(self, change: Change) -> Bip44ChangePath {
+        Bip44ChangePath {
+            account: self,
+            change,
+        }
+    }
+
+    /// Returns purpose of the path
+    pub fn purpose(&self) -> Purpose {
+        self.purpose
+    }
+
+    /// Returns coin type of the path
+    pub fn coin_type(&self) -> HardenedIndex {
+        self.coin_type
+    }
+
+    /// Returns account of the path
+    pub fn account(&self) -> HardenedIndex {
+        self.account
+    }
+
+    /// Converts into [`DerivationPath`]
+    pub fn to_path(&self) -> DerivationPath {
+        let mut path = DerivationPath::new();
+        push(&mut path, HardenedIndex::from(self.purpose));
+        push(&mut path, self.coin_type);
+        push(&mut path, self.account);
+        path
+    }
+}
+
+impl Bip44ChangePath {
+    /// Extends the path with an address index
+    pub fn index(self, index: NonHardenedIndex) -> Bip44AddressPath {
+        Bip44AddressPath {
+            change: self,
+            index,
+        }
+    }
+
+    /// Returns account-level part of the path
+    pub fn account(&self) -> &Bip44Path {
+        &self.account
+    }
+
+    /// Returns change level of the path
+    pub fn change(&self) -> Change {
+        self.change
+    }
+
+    /// Converts into [`DerivationPath`]
+    pub fn to_path(&self) -> DerivationPath {
+        let mut path = self.account.to_path();
+        push(&mut path, NonHardenedIndex::from(self.change));
+        path
+    }
+}
+
+impl Bip44AddressPath {
+    /// Returns change-level part of the path
+    pub fn change(&self) -> &Bip44ChangePath {
+        &self.change
+    }
+
+    /// Returns address index of the path
+    pub fn index(&self) -> NonHardenedIndex {
+        self.index
+    }
+
+    /// Converts into [`DerivationPath`]
+    pub fn to_path(&self) -> DerivationPath {
+        let mut path = self.change.to_path();
+        push(&mut path, self.index);
+        path
+    }
+}
+
+impl From<Bip44Path> for DerivationPath {
+    fn from(path: Bip44Path) -> Self {
+        path.to_path()
+    }
+}
+
+impl From<Bip44ChangePath> for DerivationPath {
+    fn from(path: Bip44ChangePath) -> Self {
+        path.to_path()
+    }
+}
+
+impl From<Bip44AddressPath> for DerivationPath {
+    fn from(path: Bip44AddressPath) -> Self {
+        path.to_path()
+    }
+}
+
+fn push(path: &mut DerivationPath, child_index: impl Into<crate::ChildIndex>) {
+    path.push(child_index)
+        .expect("this never fails: BIP44 path is shorter than DerivationPath::MAX_LEN")
+}
@@ -0,0 +1,143 @@
+//! [BIP85][bip85] deterministic entropy derived from an extended key
+//!
+//! BIP85 derives application-specific entropy from a master key, so a single backed up seed can
+//! be used to restore many independent wallets. Entropy is derived along a fully hardened path
+//! `m / 83696968' / app' / ...`: child secret key $k$ at the end of the path is hashed with
+//! `HMAC-SHA512("bip-entropy-from-k", k)`. Entropy is then interpreted by the application:
+//! BIP39 mnemonics, WIF keys, extended keys, or raw hex entropy.
+//!
+//! ### Example
+//! Derive a 12 words child mnemonic:
+//! ```rust
+//! use slip_10::{bip85, supported_curves::Secp256k1, HardenedIndex};
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//! let child_mnemonic = bip85::derive_mnemonic(&master_key, 12, HardenedIndex::new(0))?;
+//! assert_eq!(child_mnemonic.word_count(), 12);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [bip85]: https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki
+
+use generic_ec::{Scalar, SecretScalar};
+use hmac::Mac as _;
+use subtle::ConstantTimeEq;
+
+use crate::{
+    errors, supported_curves::Secp256k1, ChainCode, ExtendedSecretKey, HardenedIndex, HmacSha512, H,
+};
+
+/// Purpose level of BIP85 paths: 83696968<sub>H</sub>
+pub const PURPOSE: HardenedIndex = HardenedIndex(H + 83696968);
+
+/// Application number of WIF keys: 2<sub>H</sub>
+const APP_WIF: HardenedIndex = HardenedIndex(H + 2);
+/// Application number of extended keys: 32<sub>H</sub>
+const APP_XPRV: HardenedIndex = HardenedIndex(H + 32);
+/// Application number of hex entropy: 128169<sub>H</sub>
+const APP_HEX: HardenedIndex = HardenedIndex(H + 128169);
+
+/// Derives 64 bytes of entropy at given derivation path
+///
+/// Path is a full path including [`PURPOSE`], e.g. m/83696968<sub>H</sub>/0<sub>H</sub>/0<sub>H</sub>.
+/// Application-specific functions of this module build the path on their own.
+pub fn derive_entropy(
+    master_key: &ExtendedSecretKey<Secp256k1>,
+    path: impl IntoIterator<Item = HardenedIndex>,
+) -> [u8; 64] {
+    let child_key = crate::derive_child_secret_key_with_path(master_key, path);
+    HmacSha512::new_from_slice(b"bip-entropy-from-k")
+        .expect("this never fails: hmac can handle keys of any size")
+        .chain_update(child_key.secret_key.as_ref().to_be_bytes())
+        .finalize()
+        .into_bytes()
+        .into()
+}
+
+/// Derives a child BIP39 mnemonic in English
+///
+/// Path is m/83696968<sub>H</sub>/39<sub>H</sub>/0<sub>H</sub>/{words}<sub>H</sub>/{index}<sub>H</sub>.
+/// Number of words must be 12, 18, or 24, otherwise an error is returned.
+#[cfg(feature = "bip39")]
+pub fn derive_mnemonic(
+    master_key: &ExtendedSecretKey<Secp256k1>,
+    words: u32,
+    index: HardenedIndex,
+) -> Result<bip39::Mnemonic, errors::OutOfRange> {
+    let len = match words {
+        12 => 16,
+        18 => 24,
+        24 => 32,
+        _ => return Err(errors::OutOfRange),
+    };
+    const APP_BIP39: HardenedIndex = HardenedIndex(H + 39);
+    let english = HardenedIndex(H);
+    let words = HardenedIndex::try_from(H + words).map_err(|_| errors::OutOfRange)?;
+    let entropy = derive_entropy(master_key, [PURPOSE, APP_BIP39, english, words, index]);
+    let mnemonic = bip39::Mnemonic::from_entropy(&entropy[..len])
+        .expect("this never fails: entropy has valid length");
+    Ok(mnemonic)
+}
+
+/// Derives a secp256k1 secret key to be exported in WIF
+///
+/// Path is m/83696968<sub>H</sub>/2<sub>H</sub>/{index}<sub>H</sub>. Key can be encoded with
+/// `wif` module (if `wif` feature is enabled). Returns an error if entropy doesn't correspond to
+/// a valid secret key, which is practically impossible.
+pub fn derive_wif_key(
+    master_key: &ExtendedSecretKey<Secp256k1>,
+    index: HardenedIndex,
+) -> Result<SecretScalar<Secp256k1>, errors::InvalidKey> {
+    let entropy = derive_entropy(master_key, [PURPOSE, APP_WIF, index]);
+    let secret_key = Scalar::from_be_bytes(&entropy[..32]);
+    match secret_key {
+        Ok(mut secret_key) if !bool::from(secret_key.ct_eq(&Scalar::zero())) => {
+            Ok(SecretScalar::new(&mut secret_key))
+        }
+        _ => Err(errors::InvalidKey),
+    }
+}
+
+/// Derives a master extended secret key
+///
+/// Path is m/83696968<sub>H</sub>/32<sub>H</sub>/{index}<sub>H</sub>. First 32 bytes of
+/// entropy are the chain code, the last 32 bytes are the secret key. Returns an error if
+/// entropy doesn't correspond to a valid secret key, which is practically impossible.
+pub fn derive_xprv(
+    master_key: &ExtendedSecretKey<Secp256k1>,
+    index: HardenedIndex,
+) -> Result<ExtendedSecretKey<Secp256k1>, errors::InvalidKey> {
+    let entropy = derive_entropy(master_key, [PURPOSE, APP_XPRV, index]);
+    let chain_code: [u8; 32] = entropy[..32]
+        .try_into()
+        .expect("this never fails: exactly 32 bytes");
+    let secret_key = Scalar::from_be_bytes(&entropy[32..]);
+    match secret_key {
+        Ok(mut secret_key) if !bool::from(secret_key.ct_eq(&Scalar::zero())) => {
+            Ok(ExtendedSecretKey {
+                secret_key: SecretScalar::new(&mut secret_key),
+                chain_code: ChainCode::new(chain_code),
+            })
+        }
+        _ => Err(errors::InvalidKey),
+    }
+}
+
+/// Derives hex entropy, filling `out`
+///
+/// Path is m/83696968<sub>H</sub>/128169<sub>H</sub>/{out.len()}<sub>H</sub>/{index}<sub>H</sub>.
+/// Length of `out` must be 16-64 bytes, otherwise an error is returned.
+pub fn derive_hex(
+    master_key: &ExtendedSecretKey<Secp256k1>,
+    index: HardenedIndex,
+    out: &mut [u8],
+) -> Result<(), errors::InvalidLength> {
+    if !(16 <= out.len() && out.len() <= 64) {
+        return Err(errors::InvalidLength);
+    }
+    let num_bytes = HardenedIndex(H + out.len() as u32);
+    let entropy = derive_entropy(master_key, [PURPOSE, APP_HEX, num_bytes, index]);
+    out.copy_from_slice(&entropy[..out.len()]);
+    Ok(())
+}
@@ -0,0 +1,405 @@
+//! Memoized derivation
+//!
+//! [`HdTreeCache`] keeps key pairs derived from a root key, keyed by derivation path. Derivation
+//! starts from the longest cached prefix of the path, so deriving many keys that share a prefix,
+//! like m/44<sub>H</sub>/0<sub>H</sub>/0<sub>H</sub>/0/i for many i, computes the common levels
+//! only once.
+//!
+//! ### Example
+//! ```rust
+//! use slip_10::{cache::HdTreeCache, supported_curves::Secp256k1, H};
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//! let mut cache = HdTreeCache::new(slip_10::ExtendedKeyPair::from(master_key));
+//!
+//! for i in 0..10 {
+//!     let key_pair = cache.derive([44 + H, H, H, 0, i]);
+//! }
+//! // m/44'/0'/0'/0 is derived once, then each key takes a single derivation
+//! assert_eq!(cache.stats().derivations, 4 + 10);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use generic_ec::Curve;
+
+use crate::{ChildIndex, ExtendedKeyPair};
+
+/// Cache of key pairs derived from the root key, keyed by derivation path
+///
+/// Every derived node (including intermediate ones) is cached. By default, the cache grows
+/// without bound, use [`HdTreeCache::with_max_entries`] or [`HdTreeCache::set_max_entries`] to
+/// limit amount of cached nodes, in which case the least recently used nodes are evicted first.
+///
+/// Cache contains secret keys. With `zeroize` feature, they're erased once evicted.
+#[derive(Clone, Debug)]
+pub struct HdTreeCache<E: Curve> {
+    root: ExtendedKeyPair<E>,
+    nodes: BTreeMap<Vec<ChildIndex>, Node<E>>,
+    /// Paths of cached nodes ordered by last use, the least recently used first
+    lru: BTreeMap<u64, Vec<ChildIndex>>,
+    clock: u64,
+    max_entries: Option<usize>,
+    stats: CacheStats,
+}
+
+#[derive(Clone, Debug)]
+struct Node<E: Curve> {
+    key_pair: ExtendedKeyPair<E>,
+    last_used: u64,
+}
+
+/// Statistics of [`HdTreeCache`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Number of lookups where the whole path was cached
+    pub hits: u64,
+    /// Number of lookups where at least one level of the path had to be derived
+    pub misses: u64,
+    /// Number of derived child keys
+    pub derivations: u64,
+    /// Number of nodes evicted to stay within the limit
+    pub evictions: u64,
+}
+
+impl<E: Curve> HdTreeCache<E> {
+    /// Constructs an empty cache of keys derived from `root`
+    pub fn new(root: ExtendedKeyPair<E>) -> Self {
+        Self {
+            root,
+            nodes: BTreeMap::new(),
+            lru: BTreeMap::new(),
+            clock: 0,
+            max_entries: None,
+            stats: CacheStats::default(),
+        }
+    }
+
+    /// Constructs an empty cache which holds at most `max_entries` derived nodes
+    ///
+    /// The root key is not counted.
+    pub fn with_max_entries(root: ExtendedKeyPair<E>, max_entries: usize) -> Self {
+        Self {
+            max_entries: Some(max_entries),
+            ..Self::new(root)
+        }
+    }
+
+    /// Returns the root key which all keys are derived from
+    pub fn root(&self) -> &ExtendedKeyPair<E> {
+        &self.root
+    }
+
+    /// Derives a key pair at the path relative to the root key
+    ///
+    /// Derivation starts from the longest cached prefix of the path, and all derived nodes are
+    /// cached. If path is empty, a copy of the root key is returned.
+    pub fn derive(
+        &mut self,
+        path: impl IntoIterator<Item = impl Into<ChildIndex>>,
+    ) -> ExtendedKeyPair<E> {
+        let path: Vec<ChildIndex> = path.into_iter().map(Into::into).collect();
+        let (cached_len, parent) = self.lookup(&path);
+        let derived = derive_levels(&parent, &path[cached_len..]);
+        self.record(&path, cached_len, parent, derived)
+    }
+
+    /// Checks whether a key pair at the path is cached
+    pub fn contains(&self, path: &[ChildIndex]) -> bool {
+        self.nodes.contains_key(path)
+    }
+
+    /// Removes a key pair at the path from the cache
+    ///
+    /// Descendants of the node are kept. Returns `false` if the node wasn't cached.
+    pub fn remove(&mut self, path: &[ChildIndex]) -> bool {
+        match self.nodes.remove(path) {
+            Some(node) => {
+                self.lru.remove(&node.last_used);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes all cached nodes
+    ///
+    /// The root key and statistics are kept.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.lru.clear();
+    }
+
+    /// Returns amount of cached nodes, not counting the root key
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Checks whether no nodes are cached
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns maximal amount of cached nodes, `None` if cache is unbounded
+    pub fn max_entries(&self) -> Option<usize> {
+        self.max_entries
+    }
+
+    /// Sets maximal amount of cached nodes, `None` makes the cache unbounded
+    ///
+    /// If the cache holds more nodes than the new limit, the least recently used ones are
+    /// evicted immediately.
+    pub fn set_max_entries(&mut self, max_entries: Option<usize>) {
+        self.max_entries = max_entries;
+        self.evict_excess();
+    }
+
+    /// Returns cache statistics
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// Resets cache statistics to zero
+    pub fn reset_stats(&mut self) {
+        self.stats = CacheStats::default()
+    }
+
+    /// Returns length of the longest cached prefix of the path, and a key pair at this prefix
+    fn lookup(&self, path: &[ChildIndex]) -> (usize, ExtendedKeyPair<E>) {
+        (1..=path.len())
+            .rev()
+            .find_map(|len| Some((len, self.nodes.get(&path[..len])?.key_pair.clone())))
+            .unwrap_or_else(|| (0, self.root.clone()))
+    }
+
+    /// Updates the cache after the path was derived from the prefix found by `lookup`
+    ///
+    /// `derived` contains key pairs at each level after the prefix. Returns a key pair at the
+    /// path.
+    fn record(
+        &mut self,
+        path: &[ChildIndex],
+        cached_len: usize,
+        parent: ExtendedKeyPair<E>,
+        derived: Vec<ExtendedKeyPair<E>>,
+    ) -> ExtendedKeyPair<E> {
+        self.touch(&path[..cached_len]);
+        if derived.is_empty() {
+            self.stats.hits += 1;
+        } else {
+            self.stats.misses += 1;
+        }
+        self.stats.derivations += derived.len() as u64;
+
+        let mut key_pair = parent;
+        for (len, child) in (cached_len + 1..).zip(derived) {
+            self.insert(path[..len].to_vec(), child.clone());
+            key_pair = child;
+        }
+        key_pair
+    }
+
+    /// Marks a node as the most recently used one, does nothing if the node isn't cached
+    fn touch(&mut self, path: &[ChildIndex]) {
+        let Some(node) = self.nodes.get_mut(path) else {
+            return;
+        };
+        let path = self
+            .lru
+            .remove(&node.last_used)
+            .expect("this never fails: every cached node is tracked in lru");
+        self.clock += 1;
+        node.last_used = self.clock;
+        self.lru.insert(self.clock, path);
+    }
+
+    fn insert(&mut self, path: Vec<ChildIndex>, key_pair: ExtendedKeyPair<E>) {
+        if self.max_entries == Some(0) {
+            return;
+        }
+        self.clock += 1;
+        let node = Node {
+            key_pair,
+            last_used: self.clock,
+        };
+        if let Some(replaced) = self.nodes.insert(path.clone(), node) {
+            self.lru.remove(&replaced.last_used);
+        }
+        self.lru.insert(self.clock, path);
+        self.evict_excess();
+    }
+
+    fn evict_excess(&mut self) {
+        let Some(max_entries) = self.max_entries else {
+            return;
+        };
+        while self.nodes.len() > max_entries {
+            let Some((_, path)) = self.lru.pop_first() else {
+                break;
+            };
+            self.nodes.remove(&path);
+            self.stats.evictions += 1;
+        }
+    }
+}
+
+/// Thread-safe [`HdTreeCache`]
+///
+/// Cache can be shared between threads (e.g. wrapped into `Arc`) and used via `&self`. Lookups
+/// take a read lock, and keys are derived without holding the lock, so concurrent derivations
+/// don't block each other. The lock is taken for writing only briefly, to store derived nodes
+/// and update statistics.
+///
+/// If two threads derive the same uncached path at the same time, both of them do the
+/// derivation.
+///
+/// ### Example
+/// ```rust
+/// use std::sync::Arc;
+/// use slip_10::{cache::SharedHdTreeCache, supported_curves::Secp256k1, H};
+///
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+/// let cache = Arc::new(SharedHdTreeCache::new(master_key.into()));
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|i| {
+///         let cache = cache.clone();
+///         std::thread::spawn(move || cache.derive([44 + H, H, H, 0, i]))
+///     })
+///     .collect();
+/// for handle in handles {
+///     let key_pair = handle.join().unwrap();
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct SharedHdTreeCache<E: Curve> {
+    inner: std::sync::RwLock<HdTreeCache<E>>,
+}
+
+#[cfg(feature = "std")]
+impl<E: Curve> SharedHdTreeCache<E> {
+    /// Constructs an empty cache of keys derived from `root`
+    ///
+    /// Same as [`HdTreeCache::new`]
+    pub fn new(root: ExtendedKeyPair<E>) -> Self {
+        HdTreeCache::new(root).into()
+    }
+
+    /// Constructs an empty cache which holds at most `max_entries` derived nodes
+    ///
+    /// Same as [`HdTreeCache::with_max_entries`]
+    pub fn with_max_entries(root: ExtendedKeyPair<E>, max_entries: usize) -> Self {
+        HdTreeCache::with_max_entries(root, max_entries).into()
+    }
+
+    /// Derives a key pair at the path relative to the root key
+    ///
+    /// Same as [`HdTreeCache::derive`]
+    pub fn derive(
+        &self,
+        path: impl IntoIterator<Item = impl Into<ChildIndex>>,
+    ) -> ExtendedKeyPair<E> {
+        let path: Vec<ChildIndex> = path.into_iter().map(Into::into).collect();
+        let (cached_len, parent) = self.read().lookup(&path);
+        let derived = derive_levels(&parent, &path[cached_len..]);
+        self.write().record(&path, cached_len, parent, derived)
+    }
+
+    /// Checks whether a key pair at the path is cached
+    pub fn contains(&self, path: &[ChildIndex]) -> bool {
+        self.read().contains(path)
+    }
+
+    /// Removes a key pair at the path from the cache
+    ///
+    /// Same as [`HdTreeCache::remove`]
+    pub fn remove(&self, path: &[ChildIndex]) -> bool {
+        self.write().remove(path)
+    }
+
+    /// Removes all cached nodes
+    ///
+    /// Same as [`HdTreeCache::clear`]
+    pub fn clear(&self) {
+        self.write().clear()
+    }
+
+    /// Returns amount of cached nodes, not counting the root key
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    /// Checks whether no nodes are cached
+    pub fn is_empty(&self) -> bool {
+        self.read().is_empty()
+    }
+
+    /// Returns maximal amount of cached nodes, `None` if cache is unbounded
+    pub fn max_entries(&self) -> Option<usize> {
+        self.read().max_entries()
+    }
+
+    /// Sets maximal amount of cached nodes, `None` makes the cache unbounded
+    ///
+    /// Same as [`HdTreeCache::set_max_entries`]
+    pub fn set_max_entries(&self, max_entries: Option<usize>) {
+        self.write().set_max_entries(max_entries)
+    }
+
+    /// Returns cache statistics
+    pub fn stats(&self) -> CacheStats {
+        self.read().stats()
+    }
+
+    /// Resets cache statistics to zero
+    pub fn reset_stats(&self) {
+        self.write().reset_stats()
+    }
+
+    /// Returns the underlying cache
+    pub fn into_inner(self) -> HdTreeCache<E> {
+        self.inner
+            .into_inner()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    // Cache is never left in inconsistent state, so a poisoned lock can be safely reused
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, HdTreeCache<E>> {
+        self.inner
+            .read()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, HdTreeCache<E>> {
+        self.inner
+            .write()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: Curve> From<HdTreeCache<E>> for SharedHdTreeCache<E> {
+    fn from(cache: HdTreeCache<E>) -> Self {
+        Self {
+            inner: std::sync::RwLock::new(cache),
+        }
+    }
+}
+
+/// Derives key pairs at each level of `path` starting from `parent`
+fn derive_levels<E: Curve>(
+    parent: &ExtendedKeyPair<E>,
+    path: &[ChildIndex],
+) -> Vec<ExtendedKeyPair<E>> {
+    let mut derived: Vec<ExtendedKeyPair<E>> = Vec::with_capacity(path.len());
+    for child_index in path {
+        let child = derived.last().unwrap_or(parent).derive_child(*child_index);
+        derived.push(child);
+    }
+    derived
+}
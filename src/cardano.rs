@@ -0,0 +1,162 @@
+//! Cardano key derivation
+//!
+//! Cardano wallets use [BIP32-Ed25519](crate::bip32_ed25519) derivation, but derive the master key
+//! differently: Icarus master key generation defined in [CIP-3][cip3] runs PBKDF2-HMAC-SHA512 over
+//! the mnemonic entropy (not over the BIP39 seed). Keys are derived along [CIP-1852][cip1852]
+//! paths `m / 1852' / 1815' / account' / role / index`.
+//!
+//! ### Example
+//! Derive the first payment key of the first account:
+//! ```rust
+//! use slip_10::{bip32_ed25519, bip39::Mnemonic, cardano, HardenedIndex, NonHardenedIndex};
+//!
+//! let mnemonic: Mnemonic = "abandon abandon abandon abandon abandon abandon \
+//!     abandon abandon abandon abandon abandon about".parse()?;
+//! let master_key = cardano::derive_master_key_from_mnemonic(&mnemonic, "");
+//! let master_key_pair = bip32_ed25519::ExtendedKeyPair::from(master_key);
+//!
+//! let path = cardano::address_path(
+//!     HardenedIndex::new(0),
+//!     cardano::Role::External,
+//!     NonHardenedIndex::new(0),
+//! );
+//! let payment_key_pair = bip32_ed25519::derive_child_key_pair_with_path(&master_key_pair, path);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [cip3]: https://cips.cardano.org/cip/CIP-0003
+//! [cip1852]: https://cips.cardano.org/cip/CIP-1852
+
+use hmac::Mac as _;
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use crate::{
+    bip32_ed25519::ExtendedSecretKey, errors, ChainCode, ChildIndex, HardenedIndex, HmacSha512,
+    NonHardenedIndex, H,
+};
+
+/// Purpose level of CIP-1852 paths: 1852<sub>H</sub>
+pub const PURPOSE: HardenedIndex = HardenedIndex(H + 1852);
+/// Coin type of Cardano: 1815<sub>H</sub>
+pub const COIN_TYPE: HardenedIndex = HardenedIndex(H + 1815);
+
+/// Role level of CIP-1852 paths
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    /// External chain (payment addresses): 0
+    External,
+    /// Internal chain (change addresses): 1
+    Internal,
+    /// Staking key: 2
+    Staking,
+    /// DRep key ([CIP-105](https://cips.cardano.org/cip/CIP-0105)): 3
+    DRep,
+    /// Constitutional committee cold key ([CIP-105](https://cips.cardano.org/cip/CIP-0105)): 4
+    CommitteeCold,
+    /// Constitutional committee hot key ([CIP-105](https://cips.cardano.org/cip/CIP-0105)): 5
+    CommitteeHot,
+}
+
+impl From<Role> for NonHardenedIndex {
+    fn from(role: Role) -> Self {
+        NonHardenedIndex(match role {
+            Role::External => 0,
+            Role::Internal => 1,
+            Role::Staking => 2,
+            Role::DRep => 3,
+            Role::CommitteeCold => 4,
+            Role::CommitteeHot => 5,
+        })
+    }
+}
+
+/// Returns account-level path `m / 1852' / 1815' / account'`
+pub fn account_path(account: HardenedIndex) -> [ChildIndex; 3] {
+    [PURPOSE.into(), COIN_TYPE.into(), account.into()]
+}
+
+/// Returns full path `m / 1852' / 1815' / account' / role / index`
+///
+/// Staking key conventionally uses index 0.
+pub fn address_path(
+    account: HardenedIndex,
+    role: Role,
+    index: NonHardenedIndex,
+) -> [ChildIndex; 5] {
+    let [purpose, coin_type, account] = account_path(account);
+    [
+        purpose,
+        coin_type,
+        account,
+        NonHardenedIndex::from(role).into(),
+        index.into(),
+    ]
+}
+
+/// Derives a master key from the mnemonic entropy as defined by Icarus scheme
+///
+/// $k_L || k_R || \text{chain code}$ is computed as `PBKDF2-HMAC-SHA512(passphrase, entropy)` with
+/// 4096 iterations, and then $k_L$ is clamped.
+///
+/// Entropy must be 16-32 bytes long, otherwise an error is returned
+pub fn derive_icarus_master_key(
+    entropy: &[u8],
+    passphrase: &[u8],
+) -> Result<ExtendedSecretKey, errors::InvalidLength> {
+    if !(16 <= entropy.len() && entropy.len() <= 32) {
+        return Err(errors::InvalidLength);
+    }
+
+    let mut data = [0u8; 96];
+    pbkdf2_hmac_sha512(passphrase, entropy, 4096, &mut data);
+    data[0] &= 0b1111_1000;
+    data[31] &= 0b0001_1111;
+    data[31] |= 0b0100_0000;
+
+    let mut secret_key = [0u8; 64];
+    secret_key.copy_from_slice(&data[..64]);
+    let mut chain_code = [0u8; 32];
+    chain_code.copy_from_slice(&data[64..]);
+    #[cfg(feature = "zeroize")]
+    data.zeroize();
+
+    Ok(ExtendedSecretKey {
+        secret_key,
+        chain_code: ChainCode::new(chain_code),
+    })
+}
+
+/// Derives a master key from BIP39 mnemonic as defined by Icarus scheme
+///
+/// Same as [`derive_icarus_master_key`] applied to the mnemonic entropy. Use an empty string if
+/// there's no passphrase.
+#[cfg(feature = "bip39")]
+pub fn derive_master_key_from_mnemonic(
+    mnemonic: &bip39::Mnemonic,
+    passphrase: &str,
+) -> ExtendedSecretKey {
+    let (entropy, len) = mnemonic.to_entropy_array();
+    derive_icarus_master_key(&entropy[..len], passphrase.as_bytes())
+        .expect("this never fails: mnemonic entropy is 16-32 bytes long")
+}
+
+/// PBKDF2-HMAC-SHA512 as defined in [RFC8018](https://datatracker.ietf.org/doc/html/rfc8018)
+fn pbkdf2_hmac_sha512(password: &[u8], salt: &[u8], rounds: u32, out: &mut [u8]) {
+    let prf = HmacSha512::new_from_slice(password)
+        .expect("this never fails: hmac can handle keys of any size");
+    for (i, chunk) in (1u32..).zip(out.chunks_mut(64)) {
+        let mut u = prf
+            .clone()
+            .chain_update(salt)
+            .chain_update(i.to_be_bytes())
+            .finalize()
+            .into_bytes();
+        let mut t = u;
+        for _ in 1..rounds {
+            u = prf.clone().chain_update(u).finalize().into_bytes();
+            t.iter_mut().zip(&u).for_each(|(t, u)| *t ^= u);
+        }
+        chunk.copy_from_slice(&t[..chunk.len()]);
+    }
+}
@@ -0,0 +1,180 @@
+use core::{fmt, hash, ops};
+
+use subtle::{Choice, ConstantTimeEq};
+
+/// Chain code of extended key as defined in SLIP-10
+///
+/// Chain codes are compared in constant time. [`Display`](fmt::Display) and
+/// [`LowerHex`](fmt::LowerHex) print the chain code as lower case hex. `Debug` only prints its
+/// [fingerprint](ChainCode::fingerprint) unless `insecure-debug` feature is enabled: chain code
+/// along with any leaked secret key of the subtree reveals all keys below it, so it's kept out
+/// of logs.
+///
+/// Derefs to `[u8; 32]`, and can be converted from and into it. With `serde` feature enabled, it's
+/// serialized as a hex string in human-readable formats, and as bytes otherwise.
+///
+/// ### Example
+/// ```rust
+/// use slip_10::ChainCode;
+///
+/// let chain_code = ChainCode::new([0xab; 32]);
+/// assert_eq!(chain_code, [0xab; 32]);
+/// assert_eq!(chain_code.to_string(), "ab".repeat(32));
+/// assert_eq!(chain_code.len(), 32);
+/// ```
+#[derive(Clone, Copy)]
+pub struct ChainCode([u8; 32]);
+
+impl ChainCode {
+    /// Wraps chain code bytes
+    ///
+    /// Can be used in const context
+    pub const fn new(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns chain code bytes
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Returns chain code bytes
+    pub const fn to_bytes(self) -> [u8; 32] {
+        self.0
+    }
+
+    /// Returns first 4 bytes of SHA256 hash of the chain code
+    ///
+    /// Identifies the chain code, e.g. in logs, without revealing it.
+    pub fn fingerprint(&self) -> [u8; 4] {
+        let hash = <sha2::Sha256 as sha2::Digest>::digest(self.0);
+        [hash[0], hash[1], hash[2], hash[3]]
+    }
+}
+
+impl From<[u8; 32]> for ChainCode {
+    fn from(bytes: [u8; 32]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<ChainCode> for [u8; 32] {
+    fn from(chain_code: ChainCode) -> Self {
+        chain_code.0
+    }
+}
+
+impl ops::Deref for ChainCode {
+    type Target = [u8; 32];
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for ChainCode {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl ConstantTimeEq for ChainCode {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl PartialEq for ChainCode {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for ChainCode {}
+
+impl hash::Hash for ChainCode {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl PartialEq<[u8; 32]> for ChainCode {
+    fn eq(&self, other: &[u8; 32]) -> bool {
+        self.0.ct_eq(other).into()
+    }
+}
+
+impl fmt::LowerHex for ChainCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl fmt::Display for ChainCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+#[cfg(not(feature = "insecure-debug"))]
+impl fmt::Debug for ChainCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d] = self.fingerprint();
+        write!(f, "ChainCode(fingerprint: {a:02x}{b:02x}{c:02x}{d:02x})")
+    }
+}
+
+#[cfg(feature = "insecure-debug")]
+impl fmt::Debug for ChainCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ChainCode({self:x})")
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for ChainCode {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ChainCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde_with::As::<crate::serde_utils::Bytes>::serialize(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ChainCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        serde_with::As::<crate::serde_utils::Bytes>::deserialize(deserializer).map(Self)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ChainCode {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        <[u8; 32]>::arbitrary(u).map(Self)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; 32]>::size_hint(depth)
+    }
+}
+
+/// Encoded as bytes tagged with `slip10.ChainCode`
+#[cfg(feature = "udigest")]
+impl udigest::Digestable for ChainCode {
+    fn unambiguously_encode<B: udigest::Buffer>(&self, encoder: udigest::encoding::EncodeValue<B>) {
+        encoder
+            .encode_leaf()
+            .with_tag(b"slip10.ChainCode")
+            .chain(self.0);
+    }
+}
@@ -0,0 +1,93 @@
+//! Commitments to arbitrary data in derived keys
+//!
+//! Tweaks a key as $P' = P + tG$, where $t = H(P \parallel data)$, which commits `data` into
+//! the key: anyone who knows $P$ and `data` can check that $P'$ commits to them, while
+//! holder of secret key of $P$ can compute the secret key of $P'$. This is the base of
+//! pay-to-contract (payer derives recipient key committing to a contract) and sign-to-contract
+//! constructions.
+//!
+//! $H$ is SHA-512 tagged hash: `SHA512(SHA512(tag) || SHA512(tag) || P || data)` with tag
+//! [`COMMITMENT_TAG`], reduced modulo curve order. $P$ is encoded in compressed form. Chain code
+//! of the key is preserved, so keys can be further derived after being tweaked.
+//!
+//! ### Example
+//! ```rust
+//! use slip_10::{commitment, supported_curves::Secp256k1};
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//! let master_key = slip_10::ExtendedKeyPair::from(master_key);
+//! let contract = b"deliver 1 unit of goods by 2025-01-01";
+//!
+//! // Payer derives the key to pay to
+//! let payment_key = commitment::tweak_with_commitment(master_key.public_key(), contract);
+//! // Recipient derives secret key to spend the payment
+//! let spending_key = commitment::tweak_secret_key_with_commitment(master_key.secret_key(), contract);
+//! assert_eq!(
+//!     generic_ec::Point::generator() * &spending_key.secret_key,
+//!     payment_key.public_key,
+//! );
+//!
+//! // Anyone can verify that the payment committed to the contract
+//! assert!(commitment::verify_commitment(master_key.public_key(), contract, &payment_key));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use generic_ec::{Curve, Point, Scalar, SecretScalar};
+use sha2::Digest as _;
+
+use crate::{ExtendedPublicKey, ExtendedSecretKey};
+
+/// Tag of the hash which commits data into the key
+pub const COMMITMENT_TAG: &[u8] = b"SLIP10/commitment";
+
+/// Computes tweak $t = H(P \parallel data)$ committing `data` into `public_key`
+pub fn commitment_tweak<E: Curve>(public_key: &ExtendedPublicKey<E>, data: &[u8]) -> Scalar<E> {
+    let tag = sha2::Sha512::digest(COMMITMENT_TAG);
+    let hash = sha2::Sha512::new()
+        .chain_update(tag)
+        .chain_update(tag)
+        .chain_update(public_key.public_key.to_bytes(true))
+        .chain_update(data)
+        .finalize();
+    Scalar::from_be_bytes_mod_order(hash)
+}
+
+/// Tweaks public key so that it commits to `data`
+///
+/// Returns $P' = P + tG$, see [`commitment_tweak`]
+pub fn tweak_with_commitment<E: Curve>(
+    public_key: &ExtendedPublicKey<E>,
+    data: &[u8],
+) -> ExtendedPublicKey<E> {
+    let tweak = commitment_tweak(public_key, data);
+    ExtendedPublicKey {
+        public_key: public_key.public_key + Point::generator() * tweak,
+        chain_code: public_key.chain_code,
+    }
+}
+
+/// Tweaks secret key so that its public key commits to `data`
+///
+/// Public key of the returned secret key is the one returned by [`tweak_with_commitment`]
+pub fn tweak_secret_key_with_commitment<E: Curve>(
+    secret_key: &ExtendedSecretKey<E>,
+    data: &[u8],
+) -> ExtendedSecretKey<E> {
+    let public_key = ExtendedPublicKey::from(secret_key);
+    let tweak = commitment_tweak(&public_key, data);
+    let mut tweaked = secret_key.secret_key.as_ref() + tweak;
+    ExtendedSecretKey {
+        secret_key: SecretScalar::new(&mut tweaked),
+        chain_code: secret_key.chain_code,
+    }
+}
+
+/// Checks that `tweaked_key` is `public_key` tweaked to commit to `data`
+pub fn verify_commitment<E: Curve>(
+    public_key: &ExtendedPublicKey<E>,
+    data: &[u8],
+    tweaked_key: &ExtendedPublicKey<E>,
+) -> bool {
+    tweak_with_commitment(public_key, data) == *tweaked_key
+}
@@ -0,0 +1,57 @@
+//! Cosmos SDK addresses of secp256k1 keys
+//!
+//! Account address is `RIPEMD160(SHA256(public_key))` of compressed public key, encoded as bech32
+//! with a chain-specific human-readable part, e.g. `cosmos1...` on Cosmos Hub or `osmo1...` on
+//! Osmosis. All chains of the IBC ecosystem that use standard secp256k1 accounts are covered by
+//! [`cosmos_address`].
+//!
+//! ### Example
+//! ```rust
+//! use slip_10::{cosmos, supported_curves::Secp256k1};
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//! let master_key = slip_10::ExtendedKeyPair::from(master_key);
+//! let key = slip_10::derive_child_key_pair_with_path(&master_key, slip_10::path!("m/44'/118'/0'/0/0"));
+//!
+//! let address = cosmos::cosmos_address(key.public_key(), "osmo")?.to_string();
+//! assert!(address.starts_with("osmo1"));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use core::fmt;
+
+use crate::{errors::InvalidHrp, supported_curves::Secp256k1, ExtendedPublicKey};
+
+/// Maximum length of bech32 string as defined in BIP-173
+const MAX_ADDRESS_LENGTH: usize = 90;
+
+/// Returns bech32 address of the public key with human-readable part `hrp`
+///
+/// Returns error if `hrp` is not a valid bech32 human-readable part, or if it's too long for the
+/// address to fit into 90 characters allowed by BIP-173.
+pub fn cosmos_address(
+    public_key: &ExtendedPublicKey<Secp256k1>,
+    hrp: &str,
+) -> Result<impl fmt::Display, InvalidHrp> {
+    let hrp = bech32::Hrp::parse(hrp).map_err(|_| InvalidHrp)?;
+    let hash = public_key.key_identifier();
+    let len = bech32::encoded_length::<bech32::Bech32>(hrp, &hash).map_err(|_| InvalidHrp)?;
+    if len > MAX_ADDRESS_LENGTH {
+        return Err(InvalidHrp);
+    }
+    Ok(Address { hrp, hash })
+}
+
+/// Address displayed as bech32 string
+struct Address {
+    hrp: bech32::Hrp,
+    hash: [u8; 20],
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        bech32::encode_lower_to_fmt::<bech32::Bech32, _>(f, self.hrp, &self.hash)
+            .map_err(|_| fmt::Error)
+    }
+}
@@ -0,0 +1,46 @@
+//! SLIP-10 derivation for curve25519
+//!
+//! Derived keys are X25519 keys that can be used for key agreement (e.g. for encryption).
+//! Similarly to ed25519, secret key is a raw 32 bytes string (not a scalar),
+//! and only hardened derivation is defined by SLIP10 specs. Types and functions in this module
+//! reflect that: a child index is always a [`HardenedIndex`](crate::HardenedIndex), and there's
+//! no way to derive a child key from the parent public key.
+//!
+//! ### Example
+//! Derive a child key m/0<sub>H</sub>/1<sub>H</sub>:
+//! ```rust
+//! use slip_10::curve25519;
+//!
+//! let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = curve25519::derive_master_key(&seed);
+//! let master_key_pair = curve25519::ExtendedKeyPair::from(master_key);
+//!
+//! let child_key_pair = curve25519::derive_child_key_pair_with_path(
+//!     &master_key_pair,
+//!     [(0 + slip_10::H).try_into()?, (1 + slip_10::H).try_into()?],
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+crate::hardened_only::impl_hardened_only_curve! {
+    curve: "curve25519",
+    curve_tag: b"curve25519 seed",
+    /// X25519 public key (u-coordinate of the point)
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<crate::serde_utils::Bytes>")
+    )]
+    public_key: [u8; 32],
+    /// X25519 secret key
+    ///
+    /// Note that it's not clamped, clamping is done as part of X25519 scalar multiplication.
+    secret_key,
+    public_key_from_secret_key: public_key_from_secret_key,
+}
+
+/// Computes X25519 public key from the secret key as defined in [RFC7748]
+///
+/// [RFC7748]: https://www.rfc-editor.org/rfc/rfc7748#section-6.1
+fn public_key_from_secret_key(secret_key: &[u8; 32]) -> [u8; 32] {
+    curve25519_dalek::MontgomeryPoint::mul_base_clamped(*secret_key).to_bytes()
+}
@@ -0,0 +1,233 @@
+//! Bitcoin [output descriptors][descriptors]
+//!
+//! [`Descriptor`] describes receive or change chain of a [`WatchOnlyAccount`], e.g.
+//! `wpkh([d34db33f/84'/0'/0']xpub.../0/*)#...`. Descriptors can be imported into Bitcoin
+//! Core (`importdescriptors`), BDK, and other wallets to watch the account.
+//!
+//! ### Example
+//! ```rust
+//! use slip_10::{
+//!     bip44::Change,
+//!     descriptor::{Descriptor, ScriptType},
+//!     supported_curves::Secp256k1,
+//!     watch_only::WatchOnlyAccount,
+//! };
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//! let master_key = slip_10::ExtendedKeyPair::from(master_key);
+//!
+//! let account = WatchOnlyAccount::from_master_key(&master_key, "m/84'/0'/0'".parse()?)?;
+//! let receive = Descriptor::new(ScriptType::Wpkh, &account, Change::External).to_string();
+//! assert!(receive.starts_with("wpkh(["));
+//! assert!(receive.contains("/0/*)#"));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [descriptors]: https://github.com/bitcoin/bips/blob/master/bip-0380.mediawiki
+
+use core::fmt::{self, Write as _};
+
+use crate::{
+    bip44::{Change, Purpose},
+    supported_curves::Secp256k1,
+    watch_only::WatchOnlyAccount,
+    NonHardenedIndex,
+};
+
+/// Script type of the descriptor
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScriptType {
+    /// Legacy P2PKH: `pkh(...)`
+    Pkh,
+    /// Nested SegWit P2SH-P2WPKH: `sh(wpkh(...))`
+    ShWpkh,
+    /// Native SegWit P2WPKH: `wpkh(...)`
+    Wpkh,
+    /// Taproot single key P2TR: `tr(...)`
+    Tr,
+}
+
+impl ScriptType {
+    /// Returns purpose which is conventionally used for accounts of this script type
+    pub fn purpose(&self) -> Purpose {
+        match self {
+            Self::Pkh => Purpose::Bip44,
+            Self::ShWpkh => Purpose::Bip49,
+            Self::Wpkh => Purpose::Bip84,
+            Self::Tr => Purpose::Bip86,
+        }
+    }
+
+    fn prefix(&self) -> &'static str {
+        match self {
+            Self::Pkh => "pkh(",
+            Self::ShWpkh => "sh(wpkh(",
+            Self::Wpkh => "wpkh(",
+            Self::Tr => "tr(",
+        }
+    }
+
+    fn suffix(&self) -> &'static str {
+        match self {
+            Self::ShWpkh => "))",
+            Self::Pkh | Self::Wpkh | Self::Tr => ")",
+        }
+    }
+}
+
+/// Descriptor of receive or change chain of the account
+///
+/// Displayed as a ranged descriptor along with its checksum, e.g.
+/// `wpkh([d34db33f/84'/0'/0']xpub.../0/*)#...`.
+#[derive(Clone, Copy, Debug)]
+pub struct Descriptor<'a> {
+    script_type: ScriptType,
+    account: &'a WatchOnlyAccount<Secp256k1>,
+    change: Change,
+}
+
+impl<'a> Descriptor<'a> {
+    /// Constructs a descriptor of `change` chain of the `account`
+    pub fn new(
+        script_type: ScriptType,
+        account: &'a WatchOnlyAccount<Secp256k1>,
+        change: Change,
+    ) -> Self {
+        Self {
+            script_type,
+            account,
+            change,
+        }
+    }
+}
+
+impl fmt::Display for Descriptor<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut writer = ChecksumWriter {
+            f,
+            checksum: Checksum::new(),
+        };
+        write!(
+            writer,
+            "{}{}/{}/*{}",
+            self.script_type.prefix(),
+            self.account,
+            u32::from(NonHardenedIndex::from(self.change)),
+            self.script_type.suffix(),
+        )?;
+        let checksum = writer.checksum.finalize();
+        writer.f.write_str("#")?;
+        checksum
+            .iter()
+            .try_for_each(|c| writer.f.write_char(char::from(*c)))
+    }
+}
+
+/// Computes a descriptor checksum
+///
+/// Returns 8 characters of checksum which follow `#` at the end of descriptor. Returns `None` if
+/// descriptor contains characters that are not allowed in descriptors.
+///
+/// ### Example
+/// ```rust
+/// let checksum = slip_10::descriptor::checksum("raw(deadbeef)").unwrap();
+/// assert_eq!(&checksum, b"89f8spxm");
+/// ```
+pub fn checksum(descriptor: &str) -> Option<[u8; 8]> {
+    let mut checksum = Checksum::new();
+    descriptor
+        .chars()
+        .try_for_each(|c| checksum.update(c))
+        .ok()?;
+    Some(checksum.finalize())
+}
+
+/// Characters allowed in descriptors, position in the charset is what goes into the checksum
+const INPUT_CHARSET: &[u8] =
+    b"0123456789()[],'/*abcdefgh@:$%{}IJKLMNOPQRSTUVWXYZ&+-.;<=>?!^_|~ijklmnopqrstuvwxyzABCDEFGH`#\"\\ ";
+/// Characters of the checksum
+const CHECKSUM_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Descriptor checksum as defined in [BIP380](https://github.com/bitcoin/bips/blob/master/bip-0380.mediawiki#checksum)
+struct Checksum {
+    c: u64,
+    cls: u64,
+    cls_count: u8,
+}
+
+impl Checksum {
+    fn new() -> Self {
+        Self {
+            c: 1,
+            cls: 0,
+            cls_count: 0,
+        }
+    }
+
+    fn update(&mut self, c: char) -> Result<(), fmt::Error> {
+        let pos = INPUT_CHARSET
+            .iter()
+            .position(|x| u32::from(*x) == u32::from(c))
+            .ok_or(fmt::Error)? as u64;
+        self.c = poly_mod(self.c, pos & 31);
+        self.cls = self.cls * 3 + (pos >> 5);
+        self.cls_count += 1;
+        if self.cls_count == 3 {
+            self.c = poly_mod(self.c, self.cls);
+            self.cls = 0;
+            self.cls_count = 0;
+        }
+        Ok(())
+    }
+
+    fn finalize(mut self) -> [u8; 8] {
+        if self.cls_count > 0 {
+            self.c = poly_mod(self.c, self.cls);
+        }
+        for _ in 0..8 {
+            self.c = poly_mod(self.c, 0);
+        }
+        self.c ^= 1;
+
+        let mut out = [0u8; 8];
+        for (j, out) in out.iter_mut().enumerate() {
+            *out = CHECKSUM_CHARSET[((self.c >> (5 * (7 - j))) & 31) as usize];
+        }
+        out
+    }
+}
+
+fn poly_mod(mut c: u64, val: u64) -> u64 {
+    let c0 = c >> 35;
+    c = ((c & 0x7ffffffff) << 5) ^ val;
+    if c0 & 1 != 0 {
+        c ^= 0xf5dee51989;
+    }
+    if c0 & 2 != 0 {
+        c ^= 0xa9fdca3312;
+    }
+    if c0 & 4 != 0 {
+        c ^= 0x1bab10e32d;
+    }
+    if c0 & 8 != 0 {
+        c ^= 0x3706b1677a;
+    }
+    if c0 & 16 != 0 {
+        c ^= 0x644d626ffd;
+    }
+    c
+}
+
+/// Writes into formatter, feeding written characters into the checksum
+struct ChecksumWriter<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    checksum: Checksum,
+}
+
+impl fmt::Write for ChecksumWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|c| self.checksum.update(c))?;
+        self.f.write_str(s)
+    }
+}
@@ -0,0 +1,198 @@
+//! SLIP-10 derivation for ed25519 curve
+//!
+//! ed25519 is handled differently from other curves by SLIP10 specs: secret key is a raw
+//! 32 bytes string (not a scalar), and only hardened derivation is defined. Types and functions
+//! in this module reflect that: a child index is always a [`HardenedIndex`](crate::HardenedIndex),
+//! and there's no way to derive a child key from the parent public key.
+//!
+//! ### Example
+//! Derive a child key m/0<sub>H</sub>/1<sub>H</sub>:
+//! ```rust
+//! use slip_10::ed25519;
+//!
+//! let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = ed25519::derive_master_key(&seed);
+//! let master_key_pair = ed25519::ExtendedKeyPair::from(master_key);
+//!
+//! let child_key_pair = ed25519::derive_child_key_pair_with_path(
+//!     &master_key_pair,
+//!     [(0 + slip_10::H).try_into()?, (1 + slip_10::H).try_into()?],
+//! );
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! With `ed25519-dalek` feature, derived keys can sign and verify messages with standard Ed25519,
+//! as expected by Solana, Stellar, and other ed25519 chains:
+//! ```rust
+//! use slip_10::ed25519;
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key_pair = ed25519::ExtendedKeyPair::from(ed25519::derive_master_key(&seed));
+//! let key_pair = master_key_pair.derive_path([(44 + slip_10::H).try_into()?]);
+//!
+//! let signature = key_pair.sign(b"message");
+//! key_pair.public_key().verify(b"message", &signature)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//! Signature and key types are re-exported as [`ed25519_dalek`].
+//!
+//! Keys in this module are distinct from generic [`ExtendedPublicKey`](crate::ExtendedPublicKey)
+//! and [`ExtendedSecretKey`](crate::ExtendedSecretKey), so they can't be accidentally passed
+//! to generic functions like non-hardened derivation:
+//! ```rust,compile_fail
+//! use slip_10::{ed25519, NonHardenedIndex};
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! # let master_key = ed25519::derive_master_key(&seed);
+//! let master_key_pair = ed25519::ExtendedKeyPair::from(master_key);
+//! let child_public_key =
+//!     slip_10::derive_child_public_key(master_key_pair.public_key(), NonHardenedIndex::MIN);
+//! ```
+
+use generic_ec::{curves::Ed25519, Point, Scalar, SecretScalar};
+use sha2::Digest as _;
+
+#[cfg(feature = "ed25519-dalek")]
+pub use ed25519_dalek;
+
+crate::hardened_only::impl_hardened_only_curve! {
+    curve: "ed25519",
+    curve_tag: b"ed25519 seed",
+    /// The public key that can be used for signature verification
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<generic_ec::serde::PreferCompact>")
+    )]
+    public_key: Point<Ed25519>,
+    /// The secret key that can be used for signing
+    ///
+    /// Note that, as defined in ed25519, it's a 32 bytes string that is used to
+    /// derive a signing scalar, and not a scalar itself.
+    secret_key,
+    public_key_from_secret_key: public_key_from_secret_key,
+}
+
+#[cfg(feature = "ed25519-dalek")]
+impl ExtendedSecretKey {
+    /// Converts the secret key into [`ed25519_dalek::SigningKey`]
+    ///
+    /// Chain code is dropped.
+    pub fn to_signing_key(&self) -> ed25519_dalek::SigningKey {
+        ed25519_dalek::SigningKey::from_bytes(&self.secret_key)
+    }
+
+    /// Signs the message with standard Ed25519 ([RFC 8032])
+    ///
+    /// [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032
+    pub fn sign(&self, msg: &[u8]) -> ed25519_dalek::Signature {
+        ed25519_dalek::Signer::sign(&self.to_signing_key(), msg)
+    }
+}
+
+#[cfg(feature = "ed25519-dalek")]
+impl ExtendedPublicKey {
+    /// Converts the public key into [`ed25519_dalek::VerifyingKey`]
+    ///
+    /// Chain code is dropped.
+    pub fn to_verifying_key(&self) -> ed25519_dalek::VerifyingKey {
+        let bytes = self.public_key.to_bytes(true);
+        let bytes = bytes
+            .as_bytes()
+            .try_into()
+            .expect("this never fails: ed25519 point is 32 bytes long");
+        ed25519_dalek::VerifyingKey::from_bytes(bytes)
+            .expect("this never fails: public key is a valid ed25519 point")
+    }
+
+    /// Verifies standard Ed25519 ([RFC 8032]) signature of the message
+    ///
+    /// [RFC 8032]: https://www.rfc-editor.org/rfc/rfc8032
+    pub fn verify(
+        &self,
+        msg: &[u8],
+        signature: &ed25519_dalek::Signature,
+    ) -> Result<(), crate::errors::InvalidSignature> {
+        ed25519_dalek::Verifier::verify(&self.to_verifying_key(), msg, signature)
+            .map_err(|_| crate::errors::InvalidSignature)
+    }
+}
+
+#[cfg(feature = "ed25519-dalek")]
+impl ExtendedKeyPair {
+    /// Signs the message with standard Ed25519
+    ///
+    /// Same as [`ExtendedSecretKey::sign`]
+    pub fn sign(&self, msg: &[u8]) -> ed25519_dalek::Signature {
+        self.secret_key.sign(msg)
+    }
+
+    /// Verifies standard Ed25519 signature of the message
+    ///
+    /// Same as [`ExtendedPublicKey::verify`]
+    pub fn verify(
+        &self,
+        msg: &[u8],
+        signature: &ed25519_dalek::Signature,
+    ) -> Result<(), crate::errors::InvalidSignature> {
+        self.public_key.verify(msg, signature)
+    }
+}
+
+#[cfg(feature = "ed25519-dalek")]
+impl ed25519_dalek::Signer<ed25519_dalek::Signature> for ExtendedSecretKey {
+    fn try_sign(
+        &self,
+        msg: &[u8],
+    ) -> Result<ed25519_dalek::Signature, ed25519_dalek::SignatureError> {
+        Ok(self.sign(msg))
+    }
+}
+
+#[cfg(feature = "ed25519-dalek")]
+impl ed25519_dalek::Verifier<ed25519_dalek::Signature> for ExtendedPublicKey {
+    fn verify(
+        &self,
+        msg: &[u8],
+        signature: &ed25519_dalek::Signature,
+    ) -> Result<(), ed25519_dalek::SignatureError> {
+        ed25519_dalek::Verifier::verify(&self.to_verifying_key(), msg, signature)
+    }
+}
+
+#[cfg(feature = "ed25519-dalek")]
+impl ed25519_dalek::Signer<ed25519_dalek::Signature> for ExtendedKeyPair {
+    fn try_sign(
+        &self,
+        msg: &[u8],
+    ) -> Result<ed25519_dalek::Signature, ed25519_dalek::SignatureError> {
+        Ok(self.sign(msg))
+    }
+}
+
+#[cfg(feature = "ed25519-dalek")]
+impl ed25519_dalek::Verifier<ed25519_dalek::Signature> for ExtendedKeyPair {
+    fn verify(
+        &self,
+        msg: &[u8],
+        signature: &ed25519_dalek::Signature,
+    ) -> Result<(), ed25519_dalek::SignatureError> {
+        ed25519_dalek::Verifier::verify(&self.public_key, msg, signature)
+    }
+}
+
+/// Computes ed25519 public key from the secret key as defined in [RFC8032]
+///
+/// [RFC8032]: https://www.rfc-editor.org/rfc/rfc8032#section-5.1.5
+fn public_key_from_secret_key(secret_key: &[u8; 32]) -> Point<Ed25519> {
+    let mut h = sha2::Sha512::digest(secret_key);
+    let mut s = [0u8; 32];
+    s.copy_from_slice(&h[..32]);
+    crate::erase(&mut h);
+    s[0] &= 0b1111_1000;
+    s[31] &= 0b0111_1111;
+    s[31] |= 0b0100_0000;
+
+    let mut s_scalar = Scalar::<Ed25519>::from_le_bytes_mod_order(s);
+    crate::erase(&mut s);
+    let s = SecretScalar::new(&mut s_scalar);
+    Point::generator() * &s
+}
@@ -0,0 +1,217 @@
+//! [EIP-2333][eip2333] key derivation for BLS12-381
+//!
+//! Ethereum validator keys are BLS12-381 keys derived as defined in EIP-2333. It's not SLIP10:
+//! child keys are derived from parent secret keys via HKDF-SHA256 and Lamport one-time keys,
+//! there are no chain codes, no public derivation, and no distinction between hardened and
+//! non-hardened indexes. Any [`ChildIndex`] can be used, which also means that derivation paths
+//! (e.g. [EIP-2334][eip2334] `m/12381/3600/0/0/0`) can be parsed into [`DerivationPath`](crate::DerivationPath).
+//!
+//! ### Example
+//! Derive a validator signing key m/12381/3600/0/0/0:
+//! ```rust
+//! use slip_10::{eip2333, DerivationPath};
+//!
+//! # let seed = [0u8; 32];
+//! let master_sk = eip2333::derive_master_sk(&seed)?;
+//! let path: DerivationPath = "m/12381/3600/0/0/0".parse()?;
+//! let signing_sk = eip2333::derive_sk_with_path(&master_sk, &path);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [eip2333]: https://eips.ethereum.org/EIPS/eip-2333
+//! [eip2334]: https://eips.ethereum.org/EIPS/eip-2334
+
+use crypto_bigint::{Encoding, NonZero, U384};
+use hmac::Mac as _;
+use subtle::{Choice, ConstantTimeEq};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use crate::{errors, ChildIndex};
+
+type HmacSha256 = hmac::Hmac<sha2::Sha256>;
+
+/// Order of BLS12-381 scalar field $r$
+const R: NonZero<U384> = NonZero::<U384>::from_uint(U384::from_be_hex(
+    "0000000000000000000000000000000073eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+));
+
+/// BLS12-381 secret key
+///
+/// An integer in range $[1, r)$, where $r$ is the order of BLS12-381 scalar field. Keys are
+/// compared in constant time. `Debug` output doesn't include the secret key unless
+/// `insecure-debug` feature is enabled.
+#[derive(Clone)]
+pub struct SecretKey([u8; 32]);
+
+impl SecretKey {
+    /// Returns big-endian bytes representation of the secret key
+    pub fn as_be_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Derives a child key
+    ///
+    /// Same as [`derive_child_sk`]
+    pub fn derive_child(&self, child_index: impl Into<ChildIndex>) -> Self {
+        derive_child_sk(self, child_index)
+    }
+}
+
+impl ConstantTimeEq for SecretKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl PartialEq for SecretKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for SecretKey {}
+
+impl core::fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("SecretKey")
+            .field(&crate::DebugSecret("SecretKey", &self.0[..]))
+            .finish()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for SecretKey {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.zeroize()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for SecretKey {}
+
+/// Derives a master secret key from the seed
+///
+/// Seed must be at least 32 bytes long, otherwise an error is returned
+pub fn derive_master_sk(seed: &[u8]) -> Result<SecretKey, errors::InvalidLength> {
+    if seed.len() < 32 {
+        return Err(errors::InvalidLength);
+    }
+    Ok(hkdf_mod_r(seed))
+}
+
+/// Derives a child secret key from the parent secret key
+pub fn derive_child_sk(parent_sk: &SecretKey, child_index: impl Into<ChildIndex>) -> SecretKey {
+    let child_index = u32::from(child_index.into());
+    let compressed_lamport_pk = parent_sk_to_lamport_pk(parent_sk, child_index);
+    hkdf_mod_r(&compressed_lamport_pk)
+}
+
+/// Derives a child secret key with specified derivation path
+///
+/// Derivation path is an iterator that yields child indexes. If derivation path is empty, a copy
+/// of `parent_sk` is returned.
+pub fn derive_sk_with_path(
+    parent_sk: &SecretKey,
+    path: impl IntoIterator<Item = impl Into<ChildIndex>>,
+) -> SecretKey {
+    let mut derived_sk = parent_sk.clone();
+    for child_index in path {
+        derived_sk = derive_child_sk(&derived_sk, child_index);
+    }
+    derived_sk
+}
+
+/// Computes compressed Lamport public key as defined in EIP-2333
+fn parent_sk_to_lamport_pk(parent_sk: &SecretKey, index: u32) -> [u8; 32] {
+    use sha2::Digest;
+
+    let salt = index.to_be_bytes();
+    let ikm = parent_sk.0;
+    let mut not_ikm = ikm;
+    not_ikm.iter_mut().for_each(|b| *b = !*b);
+
+    let mut lamport_pk = sha2::Sha256::new();
+    for ikm in [&ikm, &not_ikm] {
+        let mut lamport_sk = [0u8; 8160];
+        hkdf(&salt, ikm, &[], &mut lamport_sk);
+        for chunk in lamport_sk.chunks_exact(32) {
+            lamport_pk.update(sha2::Sha256::digest(chunk));
+        }
+        #[cfg(feature = "zeroize")]
+        lamport_sk.zeroize();
+    }
+    #[cfg(feature = "zeroize")]
+    not_ikm.zeroize();
+
+    lamport_pk.finalize().into()
+}
+
+/// Derives a secret key from the input keying material as defined in EIP-2333
+fn hkdf_mod_r(ikm: &[u8]) -> SecretKey {
+    use sha2::Digest;
+
+    const L: u16 = 48;
+
+    let mut salt: [u8; 32] = sha2::Sha256::digest(b"BLS-SIG-KEYGEN-SALT-").into();
+    loop {
+        let prk = HmacSha256::new_from_slice(&salt)
+            .expect("this never fails: hmac can handle keys of any size")
+            .chain_update(ikm)
+            .chain_update([0])
+            .finalize()
+            .into_bytes();
+        let mut okm = [0u8; L as usize];
+        hkdf_expand(&prk, &L.to_be_bytes(), &mut okm);
+
+        let sk = U384::from_be_slice(&okm).rem(&R);
+        #[cfg(feature = "zeroize")]
+        okm.zeroize();
+        if !bool::from(sk.ct_eq(&U384::ZERO)) {
+            let sk = sk.to_be_bytes();
+            return SecretKey(
+                sk[16..]
+                    .try_into()
+                    .expect("this never fails: sk is less than r, so it fits into 32 bytes"),
+            );
+        }
+
+        salt = sha2::Sha256::digest(salt).into();
+    }
+}
+
+/// HKDF-SHA256 as defined in [RFC5869](https://datatracker.ietf.org/doc/html/rfc5869)
+fn hkdf(salt: &[u8], ikm: &[u8], info: &[u8], okm: &mut [u8]) {
+    let prk = HmacSha256::new_from_slice(salt)
+        .expect("this never fails: hmac can handle keys of any size")
+        .chain_update(ikm)
+        .finalize()
+        .into_bytes();
+    hkdf_expand(&prk, info, okm)
+}
+
+/// HKDF-Expand step, `okm` must not be longer than `255 * 32` bytes
+fn hkdf_expand(prk: &[u8], info: &[u8], okm: &mut [u8]) {
+    let hmac = HmacSha256::new_from_slice(prk)
+        .expect("this never fails: hmac can handle keys of any size");
+    let mut t = hmac::digest::Output::<HmacSha256>::default();
+    for (i, chunk) in okm.chunks_mut(32).enumerate() {
+        let mut hmac = hmac.clone();
+        if i > 0 {
+            hmac.update(&t);
+        }
+        t = hmac
+            .chain_update(info)
+            .chain_update([u8::try_from(i + 1).expect("okm is too long")])
+            .finalize()
+            .into_bytes();
+        chunk.copy_from_slice(&t[..chunk.len()]);
+    }
+}
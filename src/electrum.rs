@@ -0,0 +1,233 @@
+//! Electrum seeds
+//!
+//! Electrum (since 2.0) uses its own mnemonic seeds instead of BIP39: the mnemonic carries no
+//! checksum, instead, HMAC-SHA512 of the mnemonic keyed with `"Seed version"` must start with
+//! a prefix defining the [type of the wallet](SeedType). Seed is derived from the mnemonic with
+//! PBKDF2-HMAC-SHA512 salted with `"electrum" || passphrase`, and then master key is derived
+//! from the seed as in BIP32.
+//!
+//! Keys are derived at m/change/index for standard wallets and at
+//! m/0<sub>H</sub>/change/index for segwit wallets, so they match the keys (and addresses)
+//! shown by Electrum.
+//!
+//! Old (pre-2.0) Electrum seeds are not supported.
+//!
+//! ### Example
+//! ```rust
+//! use slip_10::{electrum, NonHardenedIndex};
+//!
+//! let mnemonic = "wild father tree among universe such mobile favorite target dynamic \
+//!     credit identify";
+//! assert_eq!(electrum::seed_type(mnemonic), Some(electrum::SeedType::Segwit));
+//!
+//! // First receiving address key
+//! let key = electrum::derive_address_key(mnemonic, "", false, NonHardenedIndex::new(0))?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use alloc::string::String;
+
+use hmac::Mac as _;
+use unicode_normalization::{char::canonical_combining_class, UnicodeNormalization};
+
+use crate::{
+    errors, supported_curves::Secp256k1, ChildIndex, ExtendedKeyPair, ExtendedSecretKey,
+    HardenedIndex, NonHardenedIndex, Seed, H,
+};
+
+/// Number of PBKDF2 iterations
+const PBKDF2_ROUNDS: u32 = 2048;
+
+/// Path of segwit account key: m/0<sub>H</sub>
+const SEGWIT_ACCOUNT_PATH: [ChildIndex; 1] = [ChildIndex::Hardened(HardenedIndex(H))];
+
+/// Type of the Electrum wallet, defined by the seed version
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SeedType {
+    /// Standard wallet, P2PKH addresses
+    Standard,
+    /// Segwit wallet, P2WPKH addresses
+    Segwit,
+    /// Two-factor authenticated wallet with P2SH multisig addresses
+    TwoFactor,
+    /// Two-factor authenticated wallet with P2WSH multisig addresses
+    TwoFactorSegwit,
+}
+
+impl SeedType {
+    /// Prefix of hex-encoded seed version
+    pub const fn prefix(self) -> &'static str {
+        match self {
+            Self::Standard => "01",
+            Self::Segwit => "100",
+            Self::TwoFactor => "101",
+            Self::TwoFactorSegwit => "102",
+        }
+    }
+
+    /// Path of the account key, which receiving and change keys are derived from
+    ///
+    /// Returns `None` for two-factor wallets: their addresses are multisig with keys held by
+    /// the 2FA service, and cannot be derived from the seed alone.
+    pub fn account_path(self) -> Option<&'static [ChildIndex]> {
+        match self {
+            Self::Standard => Some(&[]),
+            Self::Segwit => Some(&SEGWIT_ACCOUNT_PATH),
+            Self::TwoFactor | Self::TwoFactorSegwit => None,
+        }
+    }
+}
+
+/// Normalizes the mnemonic or passphrase as Electrum does
+///
+/// Text is NFKD-normalized, lowercased, and stripped of combining marks (accents). Words are
+/// separated by a single space, except between CJK characters where whitespace is removed.
+pub fn normalize(text: &str) -> String {
+    let text: String = text
+        .nfkd()
+        .flat_map(char::to_lowercase)
+        .filter(|c| canonical_combining_class(*c) == 0)
+        .collect();
+
+    let mut normalized = String::with_capacity(text.len());
+    for word in text.split_whitespace() {
+        if let (Some(last), Some(first)) = (normalized.chars().next_back(), word.chars().next()) {
+            if !(is_cjk(last) && is_cjk(first)) {
+                normalized.push(' ');
+            }
+        }
+        normalized.push_str(word);
+    }
+    erase_string(text);
+    normalized
+}
+
+/// Returns type of the seed, or `None` if mnemonic is not a valid Electrum seed
+pub fn seed_type(mnemonic: &str) -> Option<SeedType> {
+    let mnemonic = normalize(mnemonic);
+    let version = hmac::Hmac::<sha2::Sha512>::new_from_slice(b"Seed version")
+        .expect("this never fails: hmac can handle keys of any size")
+        .chain_update(mnemonic.as_bytes())
+        .finalize()
+        .into_bytes();
+    erase_string(mnemonic);
+    // First three hex digits of the version
+    let version = [version[0] >> 4, version[0] & 0xf, version[1] >> 4];
+    [
+        SeedType::Standard,
+        SeedType::Segwit,
+        SeedType::TwoFactor,
+        SeedType::TwoFactorSegwit,
+    ]
+    .into_iter()
+    .find(|seed_type| {
+        seed_type
+            .prefix()
+            .chars()
+            .zip(version)
+            .all(|(digit, nibble)| char::from_digit(nibble.into(), 16) == Some(digit))
+    })
+}
+
+/// Derives 64 bytes seed from the mnemonic and passphrase
+///
+/// Use an empty string if there's no passphrase. Returns an error if mnemonic doesn't have a
+/// valid Electrum seed version.
+pub fn to_seed(
+    mnemonic: &str,
+    passphrase: &str,
+) -> Result<(SeedType, Seed), errors::ElectrumSeedError> {
+    let seed_type = seed_type(mnemonic).ok_or(errors::ElectrumSeedError::InvalidVersion)?;
+    let passphrase = normalize(passphrase);
+    let mut salt = [b"electrum", passphrase.as_bytes()].concat();
+    erase_string(passphrase);
+    let mnemonic = normalize(mnemonic);
+    let mut seed =
+        pbkdf2::pbkdf2_hmac_array::<sha2::Sha512, 64>(mnemonic.as_bytes(), &salt, PBKDF2_ROUNDS);
+    erase_string(mnemonic);
+    crate::erase(&mut salt);
+    let result = Seed::from(seed);
+    crate::erase(&mut seed);
+    Ok((seed_type, result))
+}
+
+/// Derives the master key from the mnemonic and passphrase
+///
+/// Use an empty string if there's no passphrase. Returns an error if mnemonic doesn't have a
+/// valid Electrum seed version.
+pub fn derive_master_key(
+    mnemonic: &str,
+    passphrase: &str,
+) -> Result<(SeedType, ExtendedSecretKey<Secp256k1>), errors::ElectrumSeedError> {
+    let (seed_type, seed) = to_seed(mnemonic, passphrase)?;
+    Ok((seed_type, crate::derive_master_key(seed)))
+}
+
+/// Derives key of the receiving (or change, if `change` is `true`) address at `index`
+///
+/// Key is derived at m/change/index for standard wallets, and at m/0<sub>H</sub>/change/index
+/// for segwit wallets. Two-factor wallets are not supported.
+pub fn derive_address_key(
+    mnemonic: &str,
+    passphrase: &str,
+    change: bool,
+    index: NonHardenedIndex,
+) -> Result<ExtendedKeyPair<Secp256k1>, errors::ElectrumSeedError> {
+    let (seed_type, master_key) = derive_master_key(mnemonic, passphrase)?;
+    let account_path = seed_type
+        .account_path()
+        .ok_or(errors::ElectrumSeedError::TwoFactor)?;
+    let path = account_path.iter().copied().chain([
+        ChildIndex::NonHardened(NonHardenedIndex(change.into())),
+        ChildIndex::NonHardened(index),
+    ]);
+    Ok(crate::derive_child_key_pair_with_path(
+        &ExtendedKeyPair::from(master_key),
+        path,
+    ))
+}
+
+/// Erases a string holding secret text, e.g. normalized mnemonic
+fn erase_string(text: String) {
+    let mut bytes = text.into_bytes();
+    crate::erase(&mut bytes);
+}
+
+/// Checks whether the character belongs to one of CJK ranges, as defined by Electrum
+fn is_cjk(c: char) -> bool {
+    const CJK_RANGES: [(u32, u32); 29] = [
+        (0x4E00, 0x9FFF),   // CJK Unified Ideographs
+        (0x3400, 0x4DBF),   // CJK Unified Ideographs Extension A
+        (0x20000, 0x2A6DF), // CJK Unified Ideographs Extension B
+        (0x2A700, 0x2B73F), // CJK Unified Ideographs Extension C
+        (0x2B740, 0x2B81F), // CJK Unified Ideographs Extension D
+        (0xF900, 0xFAFF),   // CJK Compatibility Ideographs
+        (0x2F800, 0x2FA1D), // CJK Compatibility Ideographs Supplement
+        (0x3190, 0x319F),   // Kanbun
+        (0x2E80, 0x2EFF),   // CJK Radicals Supplement
+        (0x2F00, 0x2FDF),   // CJK Radicals
+        (0x31C0, 0x31EF),   // CJK Strokes
+        (0x2FF0, 0x2FFF),   // Ideographic Description Characters
+        (0xE0100, 0xE01EF), // Variation Selectors Supplement
+        (0x3100, 0x312F),   // Bopomofo
+        (0x31A0, 0x31BF),   // Bopomofo Extended
+        (0xFF00, 0xFFEF),   // Halfwidth and Fullwidth Forms
+        (0x3040, 0x309F),   // Hiragana
+        (0x30A0, 0x30FF),   // Katakana
+        (0x31F0, 0x31FF),   // Katakana Phonetic Extensions
+        (0x1B000, 0x1B0FF), // Kana Supplement
+        (0xAC00, 0xD7AF),   // Hangul Syllables
+        (0x1100, 0x11FF),   // Hangul Jamo
+        (0xA960, 0xA97F),   // Hangul Jamo Extended A
+        (0xD7B0, 0xD7FF),   // Hangul Jamo Extended B
+        (0x3130, 0x318F),   // Hangul Compatibility Jamo
+        (0xA4D0, 0xA4FF),   // Lisu
+        (0x16F00, 0x16F9F), // Miao
+        (0xA000, 0xA48F),   // Yi Syllables
+        (0xA490, 0xA4CF),   // Yi Radicals
+    ];
+    let c = u32::from(c);
+    CJK_RANGES
+        .iter()
+        .any(|(start, end)| (*start..=*end).contains(&c))
+}
@@ -2,6 +2,8 @@
 
 use core::fmt;
 
+use crate::ChildIndex;
+
 /// Length of the argument is not valid
 #[derive(Debug)]
 pub struct InvalidLength;
@@ -12,8 +14,7 @@ impl fmt::Display for InvalidLength {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for InvalidLength {}
+impl core::error::Error for InvalidLength {}
 
 /// Value was out of range
 #[derive(Debug)]
@@ -25,8 +26,98 @@ impl fmt::Display for OutOfRange {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for OutOfRange {}
+impl core::error::Error for OutOfRange {}
+
+/// Key is not valid
+#[derive(Debug)]
+pub struct InvalidKey;
+
+impl fmt::Display for InvalidKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid key")
+    }
+}
+
+impl core::error::Error for InvalidKey {}
+
+/// Signing failed
+///
+/// Returned when the secret key is zero, or, with negligible probability, when signing
+/// produced an invalid signature
+#[cfg(any(feature = "k256", feature = "p256"))]
+#[derive(Debug)]
+pub struct SignError;
+
+#[cfg(any(feature = "k256", feature = "p256"))]
+impl fmt::Display for SignError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("signing failed")
+    }
+}
+
+#[cfg(any(feature = "k256", feature = "p256"))]
+impl core::error::Error for SignError {}
+
+/// Signature is not valid
+#[cfg(feature = "ed25519-dalek")]
+#[derive(Debug)]
+pub struct InvalidSignature;
+
+#[cfg(feature = "ed25519-dalek")]
+impl fmt::Display for InvalidSignature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid signature")
+    }
+}
+
+#[cfg(feature = "ed25519-dalek")]
+impl core::error::Error for InvalidSignature {}
+
+/// Extended public key doesn't correspond to extended secret key
+#[derive(Debug)]
+pub struct MismatchedKeyPair;
+
+impl fmt::Display for MismatchedKeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("public key doesn't correspond to secret key")
+    }
+}
+
+impl core::error::Error for MismatchedKeyPair {}
+
+/// Error returned by decoding hex string
+#[cfg(feature = "hex")]
+#[derive(Debug)]
+pub enum ParseHexError {
+    /// String contains non-hex characters
+    InvalidHex,
+    /// Decoded bytes have invalid length
+    InvalidLength(InvalidLength),
+    /// Decoded bytes are not a valid key
+    InvalidKey(InvalidKey),
+}
+
+#[cfg(feature = "hex")]
+impl fmt::Display for ParseHexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHex => f.write_str("invalid hex string"),
+            Self::InvalidLength(_) => f.write_str("decoded bytes have invalid length"),
+            Self::InvalidKey(_) => f.write_str("invalid key"),
+        }
+    }
+}
+
+#[cfg(feature = "hex")]
+impl core::error::Error for ParseHexError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParseHexError::InvalidLength(e) => Some(e),
+            ParseHexError::InvalidKey(e) => Some(e),
+            ParseHexError::InvalidHex => None,
+        }
+    }
+}
 
 /// Error returned by parsing child index
 #[derive(Debug)]
@@ -46,12 +137,645 @@ impl fmt::Display for ParseChildIndexError {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for ParseChildIndexError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for ParseChildIndexError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match self {
             ParseChildIndexError::ParseInt(e) => Some(e),
             ParseChildIndexError::IndexNotInRange(e) => Some(e),
         }
     }
 }
+
+/// Derivation path exceeds maximum supported length
+#[derive(Debug)]
+pub struct PathTooLong;
+
+impl fmt::Display for PathTooLong {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("derivation path is too long")
+    }
+}
+
+impl core::error::Error for PathTooLong {}
+
+/// Error returned by parsing derivation path
+#[derive(Debug)]
+pub enum ParsePathError {
+    /// Path starts with `m` which is not followed by `/`
+    InvalidPrefix,
+    /// Child index within the path is invalid
+    ParseIndex(ParseChildIndexError),
+    /// Path contains more child indexes than supported
+    TooLong(PathTooLong),
+}
+
+impl fmt::Display for ParsePathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidPrefix => f.write_str("path must start with `m/`"),
+            Self::ParseIndex(_) => f.write_str("path contains invalid child index"),
+            Self::TooLong(_) => f.write_str("path is too long"),
+        }
+    }
+}
+
+impl core::error::Error for ParsePathError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParsePathError::InvalidPrefix => None,
+            ParsePathError::ParseIndex(e) => Some(e),
+            ParsePathError::TooLong(e) => Some(e),
+        }
+    }
+}
+
+/// Error returned by derivation
+///
+/// Tells what went wrong and, where applicable, which level of the path failed along with the
+/// child index at this level. Levels are counted from 0, i.e. level 0 is the first index of the
+/// path. Functions deriving a single child report it at level 0.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DeriveError {
+    /// Seed is not 16-64 bytes long
+    InvalidSeedLength(InvalidLength),
+    /// Derivation path cannot be parsed
+    ParsePath(ParsePathError),
+    /// Path contains a hardened index, which cannot be derived from a public key
+    HardenedInPublicPath {
+        /// Level of the path which failed
+        level: usize,
+        /// Child index at this level
+        child_index: ChildIndex,
+    },
+    /// Derived key would exceed maximum depth of 255
+    DepthOverflow {
+        /// Level of the path which failed
+        level: usize,
+        /// Child index at this level
+        child_index: ChildIndex,
+    },
+    /// Valid key wasn't found within maximum number of retries
+    TooManyRetries {
+        /// Level of the path which failed
+        level: usize,
+        /// Child index at this level
+        child_index: ChildIndex,
+    },
+    /// Valid master key wasn't found within maximum number of retries
+    TooManyRetriesForMasterKey,
+}
+
+impl DeriveError {
+    /// Returns level of the path which failed, if the error relates to a specific level
+    pub fn level(&self) -> Option<usize> {
+        match self {
+            Self::InvalidSeedLength(_) | Self::ParsePath(_) | Self::TooManyRetriesForMasterKey => {
+                None
+            }
+            Self::HardenedInPublicPath { level, .. }
+            | Self::DepthOverflow { level, .. }
+            | Self::TooManyRetries { level, .. } => Some(*level),
+        }
+    }
+
+    /// Returns child index at the level which failed, if the error relates to a specific level
+    pub fn child_index(&self) -> Option<ChildIndex> {
+        match self {
+            Self::InvalidSeedLength(_) | Self::ParsePath(_) | Self::TooManyRetriesForMasterKey => {
+                None
+            }
+            Self::HardenedInPublicPath { child_index, .. }
+            | Self::DepthOverflow { child_index, .. }
+            | Self::TooManyRetries { child_index, .. } => Some(*child_index),
+        }
+    }
+
+    /// Attributes the error to the given level of the path
+    pub(crate) fn at_level(mut self, new_level: usize) -> Self {
+        match &mut self {
+            Self::HardenedInPublicPath { level, .. }
+            | Self::DepthOverflow { level, .. }
+            | Self::TooManyRetries { level, .. } => *level = new_level,
+            Self::InvalidSeedLength(_) | Self::ParsePath(_) | Self::TooManyRetriesForMasterKey => {}
+        }
+        self
+    }
+}
+
+impl fmt::Display for DeriveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSeedLength(_) => f.write_str("seed must be 16-64 bytes long"),
+            Self::ParsePath(_) => f.write_str("invalid derivation path"),
+            Self::HardenedInPublicPath { level, child_index } => write!(
+                f,
+                "hardened index {child_index} at level {level} cannot be derived from public key"
+            ),
+            Self::DepthOverflow { level, child_index } => write!(
+                f,
+                "child index {child_index} at level {level} exceeds maximum depth of 255"
+            ),
+            Self::TooManyRetries { level, child_index } => write!(
+                f,
+                "valid key wasn't found within maximum number of retries for child index \
+                 {child_index} at level {level}"
+            ),
+            Self::TooManyRetriesForMasterKey => {
+                f.write_str("valid master key wasn't found within maximum number of retries")
+            }
+        }
+    }
+}
+
+impl core::error::Error for DeriveError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            DeriveError::InvalidSeedLength(e) => Some(e),
+            DeriveError::ParsePath(e) => Some(e),
+            DeriveError::HardenedInPublicPath { .. }
+            | DeriveError::DepthOverflow { .. }
+            | DeriveError::TooManyRetries { .. }
+            | DeriveError::TooManyRetriesForMasterKey => None,
+        }
+    }
+}
+
+impl From<InvalidLength> for DeriveError {
+    fn from(err: InvalidLength) -> Self {
+        Self::InvalidSeedLength(err)
+    }
+}
+
+impl From<ParsePathError> for DeriveError {
+    fn from(err: ParsePathError) -> Self {
+        Self::ParsePath(err)
+    }
+}
+
+/// Error returned by [`derive_master_key_with_curve_type`](crate::derive_master_key_with_curve_type)
+#[derive(Debug)]
+pub enum DeriveMasterKeyError {
+    /// Curve type doesn't match the curve that the key is derived on
+    CurveMismatch,
+}
+
+impl fmt::Display for DeriveMasterKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CurveMismatch => f.write_str("curve type doesn't match the curve"),
+        }
+    }
+}
+
+impl core::error::Error for DeriveMasterKeyError {}
+
+/// Error returned by parsing BIP32 extended key
+#[cfg(feature = "bip32-serialization")]
+#[derive(Debug)]
+pub enum ParseExtendedKeyError {
+    /// String is not valid base58
+    Base58,
+    /// Serialized key has invalid length
+    InvalidLength(InvalidLength),
+    /// Base58Check checksum doesn't match
+    InvalidChecksum,
+    /// Version bytes don't correspond to expected key type
+    UnknownVersion,
+    /// Depth, parent fingerprint, and child number are inconsistent
+    InvalidMetadata,
+    /// Serialized key is not a valid public or secret key
+    InvalidKey,
+}
+
+#[cfg(feature = "bip32-serialization")]
+impl fmt::Display for ParseExtendedKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Base58 => f.write_str("invalid base58 string"),
+            Self::InvalidLength(_) => f.write_str("serialized key has invalid length"),
+            Self::InvalidChecksum => f.write_str("checksum mismatched"),
+            Self::UnknownVersion => f.write_str("unknown version bytes"),
+            Self::InvalidMetadata => f.write_str("inconsistent key metadata"),
+            Self::InvalidKey => f.write_str("invalid key"),
+        }
+    }
+}
+
+#[cfg(feature = "bip32-serialization")]
+impl core::error::Error for ParseExtendedKeyError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParseExtendedKeyError::InvalidLength(e) => Some(e),
+            ParseExtendedKeyError::Base58
+            | ParseExtendedKeyError::InvalidChecksum
+            | ParseExtendedKeyError::UnknownVersion
+            | ParseExtendedKeyError::InvalidMetadata
+            | ParseExtendedKeyError::InvalidKey => None,
+        }
+    }
+}
+
+/// Key would exceed maximum depth of 255
+#[cfg(feature = "bip32-serialization")]
+#[derive(Debug)]
+pub struct DepthOverflow;
+
+#[cfg(feature = "bip32-serialization")]
+impl fmt::Display for DepthOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("key would exceed maximum depth of 255")
+    }
+}
+
+#[cfg(feature = "bip32-serialization")]
+impl core::error::Error for DepthOverflow {}
+
+/// Account key is inconsistent with its origin
+#[cfg(feature = "bip32-serialization")]
+#[derive(Debug)]
+pub enum InvalidAccount {
+    /// Origin path contains a non-hardened index
+    NonHardenedOrigin,
+    /// Key depth doesn't match length of origin path
+    DepthMismatch,
+    /// Key child number doesn't match the last index of origin path
+    ChildNumberMismatch,
+    /// Key parent fingerprint doesn't match master fingerprint
+    FingerprintMismatch,
+}
+
+#[cfg(feature = "bip32-serialization")]
+impl fmt::Display for InvalidAccount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonHardenedOrigin => f.write_str("origin path contains non-hardened index"),
+            Self::DepthMismatch => f.write_str("key depth doesn't match origin path"),
+            Self::ChildNumberMismatch => f.write_str("key child number doesn't match origin path"),
+            Self::FingerprintMismatch => {
+                f.write_str("key parent fingerprint doesn't match master fingerprint")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "bip32-serialization")]
+impl core::error::Error for InvalidAccount {}
+
+/// Error returned by parsing key expression `[fingerprint/path]xpub`
+#[cfg(feature = "bip32-serialization")]
+#[derive(Debug)]
+pub enum ParseKeyExpressionError {
+    /// String is not in `[fingerprint/path]xpub` format
+    InvalidFormat,
+    /// Master fingerprint is not 8 hex digits
+    InvalidFingerprint,
+    /// Origin path is invalid
+    ParsePath(ParsePathError),
+    /// Extended public key is invalid
+    ParseXpub(ParseExtendedKeyError),
+}
+
+#[cfg(feature = "bip32-serialization")]
+impl fmt::Display for ParseKeyExpressionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFormat => f.write_str("invalid key expression format"),
+            Self::InvalidFingerprint => f.write_str("invalid master fingerprint"),
+            Self::ParsePath(_) => f.write_str("invalid origin path"),
+            Self::ParseXpub(_) => f.write_str("invalid extended public key"),
+        }
+    }
+}
+
+#[cfg(feature = "bip32-serialization")]
+impl core::error::Error for ParseKeyExpressionError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParseKeyExpressionError::ParsePath(e) => Some(e),
+            ParseKeyExpressionError::ParseXpub(e) => Some(e),
+            ParseKeyExpressionError::InvalidFormat
+            | ParseKeyExpressionError::InvalidFingerprint => None,
+        }
+    }
+}
+
+/// Error returned by parsing watch-only account
+#[cfg(feature = "bip32-serialization")]
+#[derive(Debug)]
+pub enum ParseWatchOnlyAccountError {
+    /// String is not a valid key expression
+    ParseKeyExpression(ParseKeyExpressionError),
+    /// Account key is inconsistent with its origin
+    InvalidAccount(InvalidAccount),
+}
+
+#[cfg(feature = "bip32-serialization")]
+impl fmt::Display for ParseWatchOnlyAccountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ParseKeyExpression(_) => f.write_str("invalid key expression"),
+            Self::InvalidAccount(_) => f.write_str("invalid account"),
+        }
+    }
+}
+
+#[cfg(feature = "bip32-serialization")]
+impl core::error::Error for ParseWatchOnlyAccountError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParseWatchOnlyAccountError::ParseKeyExpression(e) => Some(e),
+            ParseWatchOnlyAccountError::InvalidAccount(e) => Some(e),
+        }
+    }
+}
+
+/// Error returned by parsing WIF
+#[cfg(feature = "wif")]
+#[derive(Debug)]
+pub enum ParseWifError {
+    /// String is not valid base58
+    Base58,
+    /// Decoded payload has invalid length
+    InvalidLength(InvalidLength),
+    /// Base58Check checksum doesn't match
+    InvalidChecksum,
+    /// Payload is not a valid secret key
+    InvalidKey,
+}
+
+#[cfg(feature = "wif")]
+impl fmt::Display for ParseWifError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Base58 => f.write_str("invalid base58 string"),
+            Self::InvalidLength(_) => f.write_str("decoded payload has invalid length"),
+            Self::InvalidChecksum => f.write_str("checksum mismatched"),
+            Self::InvalidKey => f.write_str("invalid key"),
+        }
+    }
+}
+
+#[cfg(feature = "wif")]
+impl core::error::Error for ParseWifError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParseWifError::InvalidLength(e) => Some(e),
+            ParseWifError::Base58 | ParseWifError::InvalidChecksum | ParseWifError::InvalidKey => {
+                None
+            }
+        }
+    }
+}
+
+/// Human-readable part of bech32 address is not valid
+#[cfg(feature = "cosmos")]
+#[derive(Debug)]
+pub struct InvalidHrp;
+
+#[cfg(feature = "cosmos")]
+impl fmt::Display for InvalidHrp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid human-readable part")
+    }
+}
+
+#[cfg(feature = "cosmos")]
+impl core::error::Error for InvalidHrp {}
+
+/// KDF parameters are not valid
+#[cfg(feature = "keystore")]
+#[derive(Debug)]
+pub struct InvalidKdfParams;
+
+#[cfg(feature = "keystore")]
+impl fmt::Display for InvalidKdfParams {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid kdf params")
+    }
+}
+
+#[cfg(feature = "keystore")]
+impl core::error::Error for InvalidKdfParams {}
+
+/// Error returned by unsealing the key
+#[cfg(feature = "keystore")]
+#[derive(Debug)]
+pub enum UnsealError {
+    /// Sealed key has invalid length
+    InvalidLength,
+    /// Version of the format is not supported
+    UnsupportedVersion,
+    /// KDF parameters are not valid
+    InvalidKdfParams,
+    /// Password is wrong, or sealed key is corrupted
+    Decryption,
+    /// Decrypted key is not valid
+    InvalidKey,
+}
+
+#[cfg(feature = "keystore")]
+impl fmt::Display for UnsealError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength => f.write_str("sealed key has invalid length"),
+            Self::UnsupportedVersion => f.write_str("unsupported version"),
+            Self::InvalidKdfParams => f.write_str("invalid kdf params"),
+            Self::Decryption => f.write_str("wrong password or corrupted data"),
+            Self::InvalidKey => f.write_str("invalid key"),
+        }
+    }
+}
+
+#[cfg(feature = "keystore")]
+impl core::error::Error for UnsealError {}
+
+/// Error returned by decrypting BIP38 key
+#[cfg(feature = "bip38")]
+#[derive(Debug)]
+pub enum Bip38Error {
+    /// Invalid base58 string
+    Base58,
+    /// Decoded payload has invalid length
+    InvalidLength,
+    /// Base58Check checksum doesn't match
+    InvalidChecksum,
+    /// Key is EC-multiplied, or encoded in unknown mode
+    UnsupportedMode,
+    /// Passphrase is wrong
+    WrongPassphrase,
+}
+
+#[cfg(feature = "bip38")]
+impl fmt::Display for Bip38Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Base58 => f.write_str("invalid base58 string"),
+            Self::InvalidLength => f.write_str("decoded payload has invalid length"),
+            Self::InvalidChecksum => f.write_str("checksum mismatched"),
+            Self::UnsupportedMode => f.write_str("unsupported mode"),
+            Self::WrongPassphrase => f.write_str("wrong passphrase"),
+        }
+    }
+}
+
+#[cfg(feature = "bip38")]
+impl core::error::Error for Bip38Error {}
+
+/// Error returned by decrypting keystore JSON
+#[cfg(feature = "web3-keystore")]
+#[derive(Debug)]
+pub enum Web3KeystoreError {
+    /// Version of the keystore is not supported
+    UnsupportedVersion,
+    /// KDF parameters are not valid or not supported
+    InvalidKdfParams,
+    /// Password is wrong, or keystore is corrupted
+    WrongPassword,
+    /// Decrypted key is not valid
+    InvalidKey,
+}
+
+#[cfg(feature = "web3-keystore")]
+impl fmt::Display for Web3KeystoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedVersion => f.write_str("unsupported version"),
+            Self::InvalidKdfParams => f.write_str("invalid kdf params"),
+            Self::WrongPassword => f.write_str("wrong password or corrupted keystore"),
+            Self::InvalidKey => f.write_str("invalid key"),
+        }
+    }
+}
+
+#[cfg(feature = "web3-keystore")]
+impl core::error::Error for Web3KeystoreError {}
+
+/// Error returned by parsing SLIP-39 share
+#[cfg(feature = "slip39")]
+#[derive(Debug)]
+pub enum ParseSlip39ShareError {
+    /// Word is not in the wordlist
+    UnknownWord,
+    /// Mnemonic has invalid number of words
+    InvalidLength,
+    /// Checksum mismatched
+    InvalidChecksum,
+    /// Padding bits are not zero
+    InvalidPadding,
+    /// Group threshold exceeds number of groups
+    InvalidThreshold,
+}
+
+#[cfg(feature = "slip39")]
+impl fmt::Display for ParseSlip39ShareError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownWord => f.write_str("unknown word"),
+            Self::InvalidLength => f.write_str("invalid number of words"),
+            Self::InvalidChecksum => f.write_str("checksum mismatched"),
+            Self::InvalidPadding => f.write_str("invalid padding"),
+            Self::InvalidThreshold => f.write_str("group threshold exceeds number of groups"),
+        }
+    }
+}
+
+#[cfg(feature = "slip39")]
+impl core::error::Error for ParseSlip39ShareError {}
+
+/// Error returned by splitting master secret into SLIP-39 shares
+#[cfg(feature = "slip39")]
+#[derive(Debug)]
+pub enum Slip39Error {
+    /// Master secret is shorter than 16 bytes, or its length is odd
+    InvalidSecretLength,
+    /// Passphrase contains characters other than printable ASCII
+    InvalidPassphrase,
+    /// Iteration exponent is too large
+    InvalidIterationExponent,
+    /// Thresholds or numbers of shares are invalid
+    InvalidThreshold,
+}
+
+#[cfg(feature = "slip39")]
+impl fmt::Display for Slip39Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSecretLength => f.write_str("invalid master secret length"),
+            Self::InvalidPassphrase => f.write_str("passphrase must be printable ascii"),
+            Self::InvalidIterationExponent => f.write_str("iteration exponent is too large"),
+            Self::InvalidThreshold => f.write_str("invalid threshold or number of shares"),
+        }
+    }
+}
+
+#[cfg(feature = "slip39")]
+impl core::error::Error for Slip39Error {}
+
+/// Error returned by recovering master secret from SLIP-39 shares
+#[cfg(feature = "slip39")]
+#[derive(Debug)]
+pub enum CombineSlip39Error {
+    /// Mnemonic is not a valid share
+    InvalidShare(ParseSlip39ShareError),
+    /// Passphrase contains characters other than printable ASCII
+    InvalidPassphrase,
+    /// Shares do not belong to the same backup
+    MismatchedShares,
+    /// Two shares of the same group have the same member index
+    DuplicateShare,
+    /// Not enough shares to recover the secret
+    InsufficientShares,
+    /// Digest of recovered secret mismatched
+    InvalidDigest,
+    /// Recovered master secret cannot be used as a seed
+    InvalidSecretLength,
+}
+
+#[cfg(feature = "slip39")]
+impl fmt::Display for CombineSlip39Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidShare(_) => f.write_str("invalid share"),
+            Self::InvalidPassphrase => f.write_str("passphrase must be printable ascii"),
+            Self::MismatchedShares => f.write_str("shares do not belong to the same backup"),
+            Self::DuplicateShare => f.write_str("duplicate member index"),
+            Self::InsufficientShares => f.write_str("insufficient number of shares"),
+            Self::InvalidDigest => f.write_str("digest mismatched"),
+            Self::InvalidSecretLength => f.write_str("invalid master secret length"),
+        }
+    }
+}
+
+#[cfg(feature = "slip39")]
+impl core::error::Error for CombineSlip39Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::InvalidShare(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Error returned by importing Electrum seed
+#[cfg(feature = "electrum")]
+#[derive(Debug)]
+pub enum ElectrumSeedError {
+    /// Mnemonic doesn't have a valid Electrum seed version
+    InvalidVersion,
+    /// Addresses of two-factor wallets cannot be derived from the seed alone
+    TwoFactor,
+}
+
+#[cfg(feature = "electrum")]
+impl fmt::Display for ElectrumSeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidVersion => f.write_str("invalid electrum seed version"),
+            Self::TwoFactor => f.write_str("two-factor wallets are not supported"),
+        }
+    }
+}
+
+#[cfg(feature = "electrum")]
+impl core::error::Error for ElectrumSeedError {}
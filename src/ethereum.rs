@@ -0,0 +1,82 @@
+//! Ethereum addresses of secp256k1 keys
+//!
+//! Ethereum address is the last 20 bytes of Keccak-256 hash of uncompressed public key (without
+//! `0x04` prefix). Addresses are conventionally displayed with [EIP-55][eip55] mixed-case
+//! checksum.
+//!
+//! ### Example
+//! Derive the first 5 deposit addresses m/44<sub>H</sub>/60<sub>H</sub>/0<sub>H</sub>/0/i:
+//! ```rust
+//! use slip_10::{ethereum, supported_curves::Secp256k1, NonHardenedIndex};
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//! let master_key = slip_10::ExtendedKeyPair::from(master_key);
+//! let chain = slip_10::derive_child_key_pair_with_path(
+//!     &master_key,
+//!     [44 + slip_10::H, 60 + slip_10::H, 0 + slip_10::H, 0],
+//! );
+//!
+//! let indexes = (0..5).map(NonHardenedIndex::new);
+//! let addresses = slip_10::derive_child_public_keys(chain.public_key(), indexes)
+//!     .map(|public_key| ethereum::ethereum_address(&public_key))
+//!     .map(|address| ethereum::to_checksum_address(&address).to_string())
+//!     .collect::<Vec<_>>();
+//! assert!(addresses[0].starts_with("0x"));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [eip55]: https://eips.ethereum.org/EIPS/eip-55
+
+use core::fmt;
+
+use generic_ec::Point;
+use sha3::Digest as _;
+
+use crate::{supported_curves::Secp256k1, ExtendedPublicKey};
+
+/// Returns Ethereum address of the public key
+pub fn ethereum_address(public_key: &ExtendedPublicKey<Secp256k1>) -> [u8; 20] {
+    address_of(&public_key.public_key)
+}
+
+/// Returns Ethereum address of the point
+pub(crate) fn address_of(public_key: &Point<Secp256k1>) -> [u8; 20] {
+    let public_key = public_key.to_bytes(false);
+    let hash = sha3::Keccak256::digest(&public_key[1..]);
+    hash[12..]
+        .try_into()
+        .expect("this never fails: exactly 20 bytes")
+}
+
+/// Formats the address as `0x`-prefixed hex string with EIP-55 checksum
+pub fn to_checksum_address(address: &[u8; 20]) -> impl fmt::Display {
+    ChecksumAddress(*address)
+}
+
+/// Address displayed with EIP-55 checksum
+struct ChecksumAddress([u8; 20]);
+
+impl fmt::Display for ChecksumAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const ALPHABET: &[u8; 16] = b"0123456789abcdef";
+
+        let mut lower = [0u8; 40];
+        for (i, byte) in self.0.iter().enumerate() {
+            lower[2 * i] = ALPHABET[usize::from(byte >> 4)];
+            lower[2 * i + 1] = ALPHABET[usize::from(byte & 0xf)];
+        }
+        let hash = sha3::Keccak256::digest(lower);
+
+        f.write_str("0x")?;
+        lower.iter().enumerate().try_for_each(|(i, c)| {
+            let nibble = (hash[i / 2] >> (4 * (1 - i % 2))) & 0xf;
+            let c = if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                *c
+            };
+            fmt::Write::write_char(f, char::from(c))
+        })
+    }
+}
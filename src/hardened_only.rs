@@ -0,0 +1,326 @@
+//! SLIP-10 derivation shared by curves that only define hardened derivation
+//!
+//! For such curves (ed25519 and curve25519), secret key is a raw 32 bytes string (not a scalar),
+//! and derivation only differs in the curve tag used to derive the master key, and in how the
+//! public key is computed from the secret key. [`impl_hardened_only_curve!`] generates
+//! extended key types and derivation functions for a curve given these two parameters.
+
+use hmac::Mac as _;
+
+use crate::{ChainCode, HardenedIndex, HmacSha512, Seed};
+
+/// Derives master secret key and chain code for curves which secret key is a raw 32 bytes
+/// string (ed25519 and curve25519)
+pub(crate) fn derive_master_raw_key(curve_tag: &[u8], seed: &Seed) -> ([u8; 32], ChainCode) {
+    let mut i = HmacSha512::new_from_slice(curve_tag)
+        .expect("this never fails: hmac can handle keys of any size")
+        .chain_update(seed.as_bytes())
+        .finalize()
+        .into_bytes();
+    let (i_left, i_right) = crate::split_into_two_halfes(&i);
+    let key = ((*i_left).into(), ChainCode::new((*i_right).into()));
+    crate::erase(&mut i);
+    key
+}
+
+/// Derives hardened child secret key and chain code for curves which secret key is a raw 32 bytes
+/// string (ed25519 and curve25519)
+pub(crate) fn derive_hardened_raw_key(
+    parent_secret_key: &[u8; 32],
+    parent_chain_code: &ChainCode,
+    child_index: HardenedIndex,
+) -> ([u8; 32], ChainCode) {
+    let mut i = HmacSha512::new_from_slice(parent_chain_code.as_slice())
+        .expect("this never fails: hmac can handle keys of any size")
+        .chain_update([0x00])
+        .chain_update(parent_secret_key)
+        .chain_update(child_index.to_be_bytes())
+        .finalize()
+        .into_bytes();
+    let (i_left, i_right) = crate::split_into_two_halfes(&i);
+    let key = ((*i_left).into(), ChainCode::new((*i_right).into()));
+    crate::erase(&mut i);
+    key
+}
+
+/// Generates extended key types and hardened-only derivation functions for a curve
+///
+/// Takes the curve name (also used as the module name in doc examples), the curve tag used
+/// to derive the master key, the public key field (with its docs and attributes), the secret
+/// key field docs, and a function that computes the public key from the raw secret key.
+macro_rules! impl_hardened_only_curve {
+    (
+        curve: $curve:literal,
+        curve_tag: $curve_tag:expr,
+        $(#[$public_key_attr:meta])*
+        public_key: $public_key:ty,
+        $(#[$secret_key_attr:meta])*
+        secret_key,
+        public_key_from_secret_key: $public_key_from_secret_key:path $(,)?
+    ) => {
+        #[doc = concat!("Extended ", $curve, " public key")]
+        #[derive(Clone, Copy, Debug)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct ExtendedPublicKey {
+            $(#[$public_key_attr])*
+            pub public_key: $public_key,
+            /// A chain code that is used to derive child keys
+            pub chain_code: $crate::ChainCode,
+        }
+
+        #[doc = concat!("Extended ", $curve, " secret key")]
+        ///
+        /// `Debug` output doesn't include the secret key unless `insecure-debug` feature is enabled.
+        #[derive(Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct ExtendedSecretKey {
+            $(#[$secret_key_attr])*
+            #[cfg_attr(
+                feature = "serde",
+                serde(with = "serde_with::As::<crate::serde_utils::Bytes>")
+            )]
+            pub secret_key: [u8; 32],
+            /// A chain code that is used to derive child keys
+            pub chain_code: $crate::ChainCode,
+        }
+
+        #[doc = concat!("Pair of extended ", $curve, " secret and public keys")]
+        #[derive(Clone, Debug)]
+        pub struct ExtendedKeyPair {
+            public_key: ExtendedPublicKey,
+            secret_key: ExtendedSecretKey,
+        }
+
+        impl core::fmt::Debug for ExtendedSecretKey {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct("ExtendedSecretKey")
+                    .field(
+                        "secret_key",
+                        &$crate::DebugSecret("SecretKey", &self.secret_key[..]),
+                    )
+                    .field("chain_code", &self.chain_code)
+                    .finish()
+            }
+        }
+
+        impl From<&ExtendedSecretKey> for ExtendedPublicKey {
+            fn from(sk: &ExtendedSecretKey) -> Self {
+                ExtendedPublicKey {
+                    public_key: $public_key_from_secret_key(&sk.secret_key),
+                    chain_code: sk.chain_code,
+                }
+            }
+        }
+
+        impl From<ExtendedSecretKey> for ExtendedKeyPair {
+            fn from(secret_key: ExtendedSecretKey) -> Self {
+                Self {
+                    public_key: (&secret_key).into(),
+                    secret_key,
+                }
+            }
+        }
+
+        impl ExtendedKeyPair {
+            /// Returns chain code of the key
+            pub fn chain_code(&self) -> &$crate::ChainCode {
+                debug_assert_eq!(self.public_key.chain_code, self.secret_key.chain_code);
+                &self.public_key.chain_code
+            }
+
+            /// Returns extended public key
+            pub fn public_key(&self) -> &ExtendedPublicKey {
+                &self.public_key
+            }
+
+            /// Returns extended secret key
+            pub fn secret_key(&self) -> &ExtendedSecretKey {
+                &self.secret_key
+            }
+
+            /// Derives a child key pair
+            ///
+            #[doc = concat!("Same as [`derive_child_key_pair`]. Only hardened derivation is defined for ", $curve, ", so")]
+            /// non-hardened indexes are rejected at compile time:
+            /// ```rust,compile_fail
+            #[doc = concat!("use slip_10::{", $curve, ", NonHardenedIndex};")]
+            /// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+            #[doc = concat!("# let master_key = ", $curve, "::derive_master_key(&seed);")]
+            #[doc = concat!("# let master_key_pair = ", $curve, "::ExtendedKeyPair::from(master_key);")]
+            /// let child_key_pair = master_key_pair.derive_child(NonHardenedIndex::MIN);
+            /// ```
+            pub fn derive_child(&self, child_index: $crate::HardenedIndex) -> Self {
+                derive_child_key_pair(self, child_index)
+            }
+
+            /// Derives a child key pair with specified derivation path
+            ///
+            /// Same as [`derive_child_key_pair_with_path`]. If derivation path is empty,
+            /// a copy of `self` is returned.
+            pub fn derive_path(
+                &self,
+                path: impl IntoIterator<Item = $crate::HardenedIndex>,
+            ) -> Self {
+                derive_child_key_pair_with_path(self, path)
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize::Zeroize for ExtendedSecretKey {
+            fn zeroize(&mut self) {
+                zeroize::Zeroize::zeroize(&mut self.secret_key);
+                zeroize::Zeroize::zeroize(&mut self.chain_code);
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl zeroize::Zeroize for ExtendedKeyPair {
+            fn zeroize(&mut self) {
+                zeroize::Zeroize::zeroize(&mut self.public_key.public_key);
+                zeroize::Zeroize::zeroize(&mut self.public_key.chain_code);
+                zeroize::Zeroize::zeroize(&mut self.secret_key);
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for ExtendedKeyPair {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                self.secret_key.serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for ExtendedKeyPair {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let secret_key = ExtendedSecretKey::deserialize(deserializer)?;
+                Ok(secret_key.into())
+            }
+        }
+
+        /// Derives a master key from the seed
+        pub fn derive_master_key(seed: impl AsRef<$crate::Seed>) -> ExtendedSecretKey {
+            let (secret_key, chain_code) =
+                $crate::hardened_only::derive_master_raw_key($curve_tag, seed.as_ref());
+            ExtendedSecretKey {
+                secret_key,
+                chain_code,
+            }
+        }
+
+        /// Derives child extended secret key from parent extended secret key
+        ///
+        #[doc = concat!("Only hardened derivation is defined for ", $curve, ", so child index is")]
+        /// always a [`HardenedIndex`](crate::HardenedIndex).
+        pub fn derive_child_secret_key(
+            parent_key: &ExtendedSecretKey,
+            child_index: $crate::HardenedIndex,
+        ) -> ExtendedSecretKey {
+            let (secret_key, chain_code) = $crate::hardened_only::derive_hardened_raw_key(
+                &parent_key.secret_key,
+                &parent_key.chain_code,
+                child_index,
+            );
+            ExtendedSecretKey {
+                secret_key,
+                chain_code,
+            }
+        }
+
+        /// Derives child key pair (extended secret key + public key) from parent key pair
+        ///
+        /// ### Example
+        /// Derive child key m/1<sub>H</sub> from master key
+        /// ```rust
+        #[doc = concat!("use slip_10::", $curve, ";")]
+        ///
+        /// # let seed = slip_10::Seed::new(b"do not use this seed :)")?;
+        #[doc = concat!("let master_key = ", $curve, "::derive_master_key(&seed);")]
+        #[doc = concat!("let master_key_pair = ", $curve, "::ExtendedKeyPair::from(master_key);")]
+        ///
+        #[doc = concat!("let derived_key = ", $curve, "::derive_child_key_pair(")]
+        ///     &master_key_pair,
+        ///     (1 + slip_10::H).try_into()?,
+        /// );
+        /// # Ok::<(), Box<dyn std::error::Error>>(())
+        /// ```
+        pub fn derive_child_key_pair(
+            parent_key: &ExtendedKeyPair,
+            child_index: $crate::HardenedIndex,
+        ) -> ExtendedKeyPair {
+            derive_child_secret_key(&parent_key.secret_key, child_index).into()
+        }
+
+        /// Derives a child key pair with specified derivation path from parent key pair
+        ///
+        /// Derivation path is an iterator that yields hardened child indexes. Public key
+        /// is computed only once for the resulting child key.
+        ///
+        /// If derivation path is empty, `parent_key` is returned
+        pub fn derive_child_key_pair_with_path(
+            parent_key: &ExtendedKeyPair,
+            path: impl IntoIterator<Item = $crate::HardenedIndex>,
+        ) -> ExtendedKeyPair {
+            let result = try_derive_child_key_pair_with_path(
+                parent_key,
+                path.into_iter().map(Ok::<_, core::convert::Infallible>),
+            );
+            match result {
+                Ok(key) => key,
+                Err(err) => match err {},
+            }
+        }
+
+        /// Derives a child key pair with specified derivation path from parent key pair
+        ///
+        /// Derivation path is a fallible iterator that yields hardened child indexes. If
+        /// iterator yields an error, it's propagated to the caller.
+        ///
+        /// ### Example
+        /// Parse a path from the string and derive a child without extra allocations:
+        /// ```rust
+        #[doc = concat!("use slip_10::", $curve, ";")]
+        /// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+        #[doc = concat!("let master_key = ", $curve, "::derive_master_key(&seed);")]
+        #[doc = concat!("let master_key_pair = ", $curve, "::ExtendedKeyPair::from(master_key);")]
+        ///
+        /// let path = "2147483649/2147483658";
+        /// let child_indexes = path.split('/').map(str::parse);
+        #[doc = concat!("let child_key = ", $curve, "::try_derive_child_key_pair_with_path(")]
+        ///     &master_key_pair,
+        ///     child_indexes,
+        /// )?;
+        /// # Ok::<_, Box<dyn std::error::Error>>(())
+        /// ```
+        pub fn try_derive_child_key_pair_with_path<Err>(
+            parent_key: &ExtendedKeyPair,
+            path: impl IntoIterator<Item = Result<$crate::HardenedIndex, Err>>,
+        ) -> Result<ExtendedKeyPair, Err> {
+            let mut path = path.into_iter().peekable();
+            if path.peek().is_none() {
+                return Ok(parent_key.clone());
+            }
+
+            let mut derived_key = parent_key.secret_key.clone();
+            for child_index in path {
+                let child_key = match child_index {
+                    Ok(child_index) => derive_child_secret_key(&derived_key, child_index),
+                    Err(err) => {
+                        $crate::erase_key(&mut derived_key);
+                        return Err(err);
+                    }
+                };
+                $crate::erase_key(&mut derived_key);
+                derived_key = child_key;
+            }
+            Ok(derived_key.into())
+        }
+    };
+}
+
+pub(crate) use impl_hardened_only_curve;
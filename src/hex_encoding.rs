@@ -0,0 +1,92 @@
+use alloc::string::String;
+
+use generic_ec::{Curve, Point};
+
+use crate::{errors, ChainCode, ExtendedPublicKey, ExtendedSecretKey, SupportedCurve};
+
+/// Encoding to and decoding from hex strings
+///
+/// Implemented for chain codes, public keys (in compressed form), and raw encodings of extended
+/// keys (see [`ExtendedPublicKey::to_bytes`] and [`ExtendedSecretKey::to_bytes`]). Decoding
+/// accepts both lower and upper case hex.
+///
+/// ### Example
+/// ```rust
+/// use slip_10::{supported_curves::Secp256k1, ExtendedPublicKey, HexEncoding};
+///
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+/// let master_public_key = ExtendedPublicKey::from(&master_key);
+///
+/// let encoded = master_public_key.to_hex();
+/// assert_eq!(encoded.len(), 130);
+/// let decoded = ExtendedPublicKey::<Secp256k1>::from_hex(&encoded)?;
+/// assert_eq!(decoded.public_key, master_public_key.public_key);
+///
+/// let chain_code = master_key.chain_code.to_hex();
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub trait HexEncoding: Sized {
+    /// Encodes value into lower case hex string
+    fn to_hex(&self) -> String;
+    /// Decodes value from hex string
+    fn from_hex(s: &str) -> Result<Self, errors::ParseHexError>;
+}
+
+impl HexEncoding for ChainCode {
+    fn to_hex(&self) -> String {
+        hex::encode(self)
+    }
+
+    fn from_hex(s: &str) -> Result<Self, errors::ParseHexError> {
+        decode_array(s).map(ChainCode::new)
+    }
+}
+
+impl<E: Curve> HexEncoding for Point<E> {
+    fn to_hex(&self) -> String {
+        hex::encode(self.to_bytes(true))
+    }
+
+    /// Decodes a point from compressed or uncompressed form. Identity point is rejected.
+    fn from_hex(s: &str) -> Result<Self, errors::ParseHexError> {
+        let bytes = hex::decode(s).map_err(|_| errors::ParseHexError::InvalidHex)?;
+        Point::from_bytes(bytes)
+            .ok()
+            .filter(|p| !p.is_zero())
+            .ok_or(errors::ParseHexError::InvalidKey(errors::InvalidKey))
+    }
+}
+
+impl<E: Curve + SupportedCurve> HexEncoding for ExtendedPublicKey<E> {
+    fn to_hex(&self) -> String {
+        hex::encode(self.to_bytes())
+    }
+
+    fn from_hex(s: &str) -> Result<Self, errors::ParseHexError> {
+        let bytes: [u8; 65] = decode_array(s)?;
+        Self::try_from(&bytes[..]).map_err(errors::ParseHexError::InvalidKey)
+    }
+}
+
+impl<E: Curve + SupportedCurve> HexEncoding for ExtendedSecretKey<E> {
+    fn to_hex(&self) -> String {
+        hex::encode(self.to_bytes())
+    }
+
+    fn from_hex(s: &str) -> Result<Self, errors::ParseHexError> {
+        let bytes: [u8; 64] = decode_array(s)?;
+        Self::from_bytes(&bytes).map_err(errors::ParseHexError::InvalidKey)
+    }
+}
+
+fn decode_array<const N: usize>(s: &str) -> Result<[u8; N], errors::ParseHexError> {
+    let mut out = [0u8; N];
+    hex::decode_to_slice(s, &mut out).map_err(|err| match err {
+        hex::FromHexError::InvalidStringLength | hex::FromHexError::OddLength => {
+            errors::ParseHexError::InvalidLength(errors::InvalidLength)
+        }
+        hex::FromHexError::InvalidHexCharacter { .. } => errors::ParseHexError::InvalidHex,
+    })?;
+    Ok(out)
+}
@@ -0,0 +1,235 @@
+//! Password-encrypted storage of extended secret keys
+//!
+//! [`ExtendedSecretKey::seal`] encrypts the key under a password, producing a blob of
+//! [`SEALED_LEN`] bytes which is safe to store at rest. [`ExtendedSecretKey::unseal`] decrypts
+//! it back. Encryption key is derived from the password with Argon2id, and the key is encrypted
+//! with ChaCha20-Poly1305.
+//!
+//! Blob layout:
+//!
+//! | Bytes  | Content                                              |
+//! |--------|------------------------------------------------------|
+//! | 1      | version, currently `1`                               |
+//! | 4      | Argon2id memory cost in KiB (big-endian)              |
+//! | 4      | Argon2id number of iterations (big-endian)            |
+//! | 4      | Argon2id parallelism (big-endian)                     |
+//! | 16     | salt                                                 |
+//! | 12     | nonce                                                |
+//! | 64     | encrypted [serialized](ExtendedSecretKey::to_bytes) key |
+//! | 16     | authentication tag                                   |
+//!
+//! Header (everything before the encrypted key) and the curve name are authenticated, so a blob
+//! can only be unsealed as a key on the same curve it was sealed for. KDF parameters are read
+//! from the blob before the password is checked, so they're bounded by [`KdfParams::MAX`] to
+//! prevent a malformed blob from exhausting memory or CPU.
+//!
+//! ### Example
+//! ```rust
+//! use slip_10::{supported_curves::Secp256k1, ExtendedSecretKey};
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//!
+//! let mut rng = rand_core::OsRng;
+//! let sealed = master_key.seal(b"password", &mut rng);
+//!
+//! let unsealed = ExtendedSecretKey::<Secp256k1>::unseal(&sealed, b"password")?;
+//! assert_eq!(unsealed.to_bytes(), master_key.to_bytes());
+//! assert!(ExtendedSecretKey::<Secp256k1>::unseal(&sealed, b"wrong password").is_err());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use chacha20poly1305::{aead::AeadInPlace, ChaCha20Poly1305, KeyInit};
+use generic_ec::Curve;
+use rand_core::{CryptoRng, RngCore};
+use zeroize::Zeroize;
+
+use crate::{errors, ExtendedSecretKey, SupportedCurve};
+
+/// Size of the sealed key in bytes
+pub const SEALED_LEN: usize = HEADER_LEN + 64 + 16;
+
+/// Current version of the blob format
+const VERSION: u8 = 1;
+/// Size of header: version, KDF params, salt, and nonce
+const HEADER_LEN: usize = 1 + 3 * 4 + 16 + 12;
+
+/// Argon2id parameters of password-based key derivation
+///
+/// Defaults are the ones recommended by OWASP: 19 MiB of memory, 2 iterations, parallelism 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KdfParams {
+    /// Memory cost in KiB
+    pub memory_kib: u32,
+    /// Number of iterations
+    pub iterations: u32,
+    /// Degree of parallelism
+    pub parallelism: u32,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self {
+            memory_kib: argon2::Params::DEFAULT_M_COST,
+            iterations: argon2::Params::DEFAULT_T_COST,
+            parallelism: argon2::Params::DEFAULT_P_COST,
+        }
+    }
+}
+
+impl KdfParams {
+    /// The largest parameters accepted by [`ExtendedSecretKey::unseal`]: 1 GiB of memory,
+    /// 32 iterations, parallelism 16
+    ///
+    /// Use [`ExtendedSecretKey::unseal_with_max_params`] to unseal blobs with larger parameters.
+    pub const MAX: Self = Self {
+        memory_kib: 1 << 20,
+        iterations: 32,
+        parallelism: 16,
+    };
+
+    /// Checks whether every parameter is within the corresponding bound of `max`
+    fn is_within(&self, max: &Self) -> bool {
+        self.memory_kib <= max.memory_kib
+            && self.iterations <= max.iterations
+            && self.parallelism <= max.parallelism
+    }
+
+    /// Derives encryption key from the password
+    fn derive_key(
+        &self,
+        password: &[u8],
+        salt: &[u8],
+    ) -> Result<[u8; 32], errors::InvalidKdfParams> {
+        let params =
+            argon2::Params::new(self.memory_kib, self.iterations, self.parallelism, Some(32))
+                .map_err(|_| errors::InvalidKdfParams)?;
+        let argon2 =
+            argon2::Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params);
+        let mut key = [0u8; 32];
+        argon2
+            .hash_password_into(password, salt, &mut key)
+            .map_err(|_| errors::InvalidKdfParams)?;
+        Ok(key)
+    }
+}
+
+impl<E: Curve + SupportedCurve> ExtendedSecretKey<E> {
+    /// Encrypts the key under the password
+    ///
+    /// Uses [default](KdfParams::default) KDF parameters. See [module-level docs](crate::keystore)
+    /// for details.
+    pub fn seal(&self, password: &[u8], rng: &mut (impl RngCore + CryptoRng)) -> [u8; SEALED_LEN] {
+        self.seal_with_params(password, KdfParams::default(), rng)
+            .expect("this never fails: default params are valid")
+    }
+
+    /// Encrypts the key under the password using given KDF parameters
+    ///
+    /// Returns error if the parameters are not accepted by Argon2id
+    pub fn seal_with_params(
+        &self,
+        password: &[u8],
+        params: KdfParams,
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> Result<[u8; SEALED_LEN], errors::InvalidKdfParams> {
+        let mut sealed = [0u8; SEALED_LEN];
+        sealed[0] = VERSION;
+        sealed[1..5].copy_from_slice(&params.memory_kib.to_be_bytes());
+        sealed[5..9].copy_from_slice(&params.iterations.to_be_bytes());
+        sealed[9..13].copy_from_slice(&params.parallelism.to_be_bytes());
+        rng.fill_bytes(&mut sealed[13..HEADER_LEN]);
+
+        let (header, rest) = sealed.split_at_mut(HEADER_LEN);
+        let (ciphertext, tag) = rest.split_at_mut(64);
+        let salt = &header[13..29];
+        let nonce = &header[29..];
+
+        let mut key = params.derive_key(password, salt)?;
+        let cipher = ChaCha20Poly1305::new(&key.into());
+        key.zeroize();
+
+        let mut plaintext = self.to_bytes();
+        ciphertext.copy_from_slice(&plaintext);
+        plaintext.zeroize();
+        let tag_value = cipher
+            .encrypt_in_place_detached(nonce.into(), &associated_data::<E>(header), ciphertext)
+            .expect("this never fails: plaintext is short");
+        tag.copy_from_slice(&tag_value);
+
+        Ok(sealed)
+    }
+
+    /// Decrypts the key sealed by [`seal`](Self::seal)
+    ///
+    /// Returns error if blob is malformed, password is wrong, or blob was sealed for key on
+    /// another curve. KDF parameters exceeding [`KdfParams::MAX`] are rejected.
+    pub fn unseal(sealed: &[u8], password: &[u8]) -> Result<Self, errors::UnsealError> {
+        Self::unseal_with_max_params(sealed, password, KdfParams::MAX)
+    }
+
+    /// Decrypts the key sealed by [`seal`](Self::seal), accepting KDF parameters up to `max`
+    ///
+    /// Same as [`unseal`](Self::unseal), but with custom bounds on KDF parameters. Returns
+    /// [`UnsealError::InvalidKdfParams`](errors::UnsealError::InvalidKdfParams) if any parameter
+    /// in the blob exceeds the bound.
+    pub fn unseal_with_max_params(
+        sealed: &[u8],
+        password: &[u8],
+        max: KdfParams,
+    ) -> Result<Self, errors::UnsealError> {
+        if sealed.len() != SEALED_LEN {
+            return Err(errors::UnsealError::InvalidLength);
+        }
+        if sealed[0] != VERSION {
+            return Err(errors::UnsealError::UnsupportedVersion);
+        }
+        let read_u32 = |bytes: &[u8]| {
+            u32::from_be_bytes(bytes.try_into().expect("this never fails: exactly 4 bytes"))
+        };
+        let params = KdfParams {
+            memory_kib: read_u32(&sealed[1..5]),
+            iterations: read_u32(&sealed[5..9]),
+            parallelism: read_u32(&sealed[9..13]),
+        };
+        if !params.is_within(&max) {
+            return Err(errors::UnsealError::InvalidKdfParams);
+        }
+
+        let (header, rest) = sealed.split_at(HEADER_LEN);
+        let (ciphertext, tag) = rest.split_at(64);
+        let salt = &header[13..29];
+        let nonce = &header[29..];
+
+        let mut key = params
+            .derive_key(password, salt)
+            .map_err(|_| errors::UnsealError::InvalidKdfParams)?;
+        let cipher = ChaCha20Poly1305::new(&key.into());
+        key.zeroize();
+
+        let mut plaintext: [u8; 64] = ciphertext
+            .try_into()
+            .expect("this never fails: exactly 64 bytes");
+        let result = cipher
+            .decrypt_in_place_detached(
+                nonce.into(),
+                &associated_data::<E>(header),
+                &mut plaintext,
+                tag.into(),
+            )
+            .map_err(|_| errors::UnsealError::Decryption)
+            .and_then(|()| {
+                Self::from_bytes(&plaintext).map_err(|_| errors::UnsealError::InvalidKey)
+            });
+        plaintext.zeroize();
+        result
+    }
+}
+
+/// Associated data of the AEAD: header followed by the curve name
+fn associated_data<E: Curve>(header: &[u8]) -> alloc::vec::Vec<u8> {
+    let mut ad = alloc::vec::Vec::with_capacity(header.len() + E::CURVE_NAME.len());
+    ad.extend_from_slice(header);
+    ad.extend_from_slice(E::CURVE_NAME.as_bytes());
+    ad
+}
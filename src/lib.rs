@@ -7,18 +7,105 @@
 //! elliptic curve arithmetic. The crate is `no_std` and `no_alloc` friendly.
 //!
 //! ### Curves support
-//! Implementation currently does not support ed25519 curve. All other curves are
-//! supported: both secp256k1 and secp256r1. In fact, implementation may work with any
-//! curve, but only those are covered by the SLIP10 specs.
+//! secp256k1 and secp256r1 curves are supported via generic API. In fact, implementation may
+//! work with any curve, but only those are covered by the SLIP10 specs.
+//!
+//! ed25519 and curve25519 are supported via dedicated [ed25519] and [curve25519] modules (require
+//! `curve-ed25519` and `curve-curve25519` features), as SLIP10 defines only hardened derivation for
+//! them, and secret key is not a scalar.
 //!
 //! The crate also re-exports supported curves in [supported_curves] module (requires
 //! enabling a feature), but any other curve implementation will work with the crate.
 //!
 //! ### Features
-//! * `std`: enables std library support (mainly, it just implements [`Error`](std::error::Error)
-//!   trait for the error types)
+//! * `std`: enables std library support in dependencies and thread-safe
+//!   [`SharedHdTreeCache`](cache::SharedHdTreeCache), implies `alloc`. Error types implement
+//!   [`core::error::Error`] regardless of this feature
+//! * `alloc`: makes [`DerivationPath`] growable, so it can contain any amount of child indexes.
+//!   Without it, [`StaticPath`] can be used to pick a path capacity other than
+//!   [`DerivationPath::MAX_LEN`]. Also enables gap-limit [`scan`] and memoized derivation in
+//!   [`cache`] module
+//! * `serde`: enables (de)serialization of keys, indexes, and paths. Points, scalars, and chain codes
+//!   are serialized compactly: as hex strings in human-readable formats, and as bytes otherwise.
+//!   Paths are serialized as `m/44'/0'/0'` strings in human-readable formats
 //! * `curve-secp256k1` and `curve-secp256r1` add curve implementation into the crate [supported_curves]
 //!   module
+//! * `curve-ed25519` enables [ed25519] module
+//! * `curve-curve25519` enables [curve25519] module
+//! * `bip32-ed25519` enables [bip32_ed25519] module implementing BIP32-Ed25519 derivation scheme
+//!   (not part of SLIP10) that supports non-hardened ed25519 derivation
+//! * `bip32-serialization` enables [bip32] module which serializes extended keys to
+//!   `xpub...`/`xprv...` strings (implies `ripemd`), [slip132] registry of `ypub`/`zpub`/...
+//!   version bytes, and [watch_only] module
+//! * `bip39` enables [`derive_master_key_from_mnemonic`] that derives a master key from
+//!   BIP39 mnemonic, and re-exports [`bip39`] crate
+//! * `ripemd` enables [`ExtendedPublicKey::key_identifier`] and [`ExtendedPublicKey::fingerprint`]
+//!   which are used to identify keys in BIP32
+//! * `descriptors` enables [descriptor] module which exports Bitcoin output descriptors of
+//!   watch-only accounts (implies `bip32-serialization` and `curve-secp256k1`)
+//! * `wif` enables [wif] module which exports and imports secp256k1 secret keys in Wallet Import
+//!   Format (implies `curve-secp256k1`)
+//! * `electrum` enables [electrum] module which imports Electrum (2.0+) mnemonic seeds (implies
+//!   `curve-secp256k1` and `alloc`)
+//! * `hex` enables [`HexEncoding`] trait which encodes chain codes, public keys, and raw
+//!   extended keys to hex strings (implies `alloc`)
+//! * `bitcoin` converts [`bip32::Xpub`] and [`bip32::Xprv`] from and into `bitcoin::bip32`
+//!   extended keys of [rust-bitcoin], along with chain codes and child numbers (implies
+//!   `bip32-serialization` and `curve-secp256k1`)
+//! * `ed25519-dalek` adds Ed25519 `sign` and `verify` helpers to [ed25519] keys, and converts
+//!   them into `ed25519_dalek` signing and verifying keys (implies `curve-ed25519`)
+//! * `k256` and `p256` enable [rustcrypto] module which converts secp256k1 and secp256r1 keys
+//!   into [RustCrypto] key types, e.g. `elliptic_curve::SecretKey` or `k256::ecdsa::SigningKey`,
+//!   and implements ECDSA `signature::Signer` and `signature::Verifier` for derived keys (imply
+//!   `curve-secp256k1` and `curve-secp256r1` respectively)
+//! * `rayon` enables [`par_derive_children`] that derives many child public keys in parallel
+//!   (implies `std`)
+//! * `slip39` enables [slip39] module with SLIP-39 Shamir backup of the master secret (implies
+//!   `alloc`)
+//! * `slip44` enables [slip44] module with registered coin types
+//! * `zeroize` implements `Zeroize` trait for secret keys and derived shifts, and erases
+//!   intermediate secrets (e.g. HMAC outputs) during derivation
+//! * `secrecy` makes secret keys usable with [`secrecy::SecretBox`]: implements `CloneableSecret`
+//!   for [`ExtendedSecretKey`] and [`ExtendedKeyPair`], and `ExposeSecret` for [`ExtendedKeyPair`]
+//!   (implies `zeroize` and `alloc`). `secrecy-serde` additionally implements `SerializableSecret`
+//!   so boxed keys can be serialized
+//! * `bitcoin-address` enables [address] module which computes P2PKH, P2WPKH, and P2TR Bitcoin
+//!   addresses of secp256k1 keys (implies `ripemd` and `taproot`)
+//! * `bip38` enables [bip38] module with BIP38 passphrase encryption of secp256k1 secret keys
+//!   (implies `ripemd` and `curve-secp256k1`)
+//! * `bip85` enables [bip85] module with BIP85 deterministic entropy (implies `curve-secp256k1`)
+//! * `cardano` enables [cardano] module with Icarus master key generation and CIP-1852 paths
+//!   (implies `bip32-ed25519`)
+//! * `cosmos` enables [cosmos] module which computes Cosmos SDK bech32 addresses of secp256k1 keys
+//!   (implies `ripemd` and `curve-secp256k1`)
+//! * `eip2333` enables [eip2333] module with EIP-2333 key derivation for BLS12-381
+//! * `keccak` enables [ethereum] module which computes Ethereum addresses of secp256k1 keys
+//!   (implies `curve-secp256k1`)
+//! * `keystore` enables [keystore] module with password-encrypted storage of extended secret keys
+//!   (implies `zeroize` and `alloc`)
+//! * `musig2` enables [musig2] module with BIP328 derivation from MuSig2 aggregate keys (implies
+//!   `curve-secp256k1`)
+//! * `nip06` enables [nip06] module with Nostr key derivation (implies `curve-secp256k1`)
+//! * `solana` enables [solana] module with Solana key derivation (implies `curve-ed25519`)
+//! * `stellar` enables [stellar] module with SEP-0005 Stellar key derivation (implies
+//!   `curve-ed25519`)
+//! * `taproot` enables [taproot] module with BIP341 key-path tweak (implies `curve-secp256k1`)
+//! * `udigest` implements `udigest::Digestable` for extended public keys, chain codes, child
+//!   indexes, and derivation paths, so they can be hashed unambiguously, e.g. bound into
+//!   Fiat-Shamir transcripts
+//! * `arbitrary` implements `arbitrary::Arbitrary` for child indexes, derivation paths, chain
+//!   codes, and seeds, so fuzzers can generate structured inputs (implies `std`). Generated seeds
+//!   aren't random, only use them in tests
+//! * `proptest` enables [strategies] module with `proptest` strategies generating indexes, paths,
+//!   and seeds (implies `std`)
+//! * `wasm` enables [wasm] module with JavaScript bindings via wasm-bindgen (implies
+//!   `bip32-serialization`, `curve-secp256k1`, and `std`)
+//! * `web3-keystore` enables [web3_keystore] module with keystore JSON v3 export and import of
+//!   Ethereum keys (implies `keccak`, `serde`, and `alloc`)
+//! * `insecure-debug` makes `Debug` output of secret keys include the secret key itself, and
+//!   `Debug` output of chain codes include the chain code instead of its fingerprint. By default,
+//!   secrets are replaced with a placeholder so they don't leak into logs. Only use it for
+//!   development.
 //!
 //! ### Examples
 //!
@@ -26,8 +113,8 @@
 //! ```rust
 //! use slip_10::supported_curves::Secp256k1;
 //!
-//! let seed = b"16-64 bytes of high entropy".as_slice();
-//! let master_key = slip_10::derive_master_key::<Secp256k1>(seed)?;
+//! let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
 //! let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
 //!
 //! let child_key_pair = slip_10::derive_child_key_pair_with_path(
@@ -37,12 +124,54 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 //!
+//! Derivation path can also be parsed from a string:
+//! ```rust
+//! use slip_10::{supported_curves::Secp256k1, DerivationPath};
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! # let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//! # let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+//! let path: DerivationPath = "m/1'/10".parse()?;
+//! let child_key_pair = slip_10::derive_child_key_pair_with_path(&master_key_pair, &path);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Path can be validated at compile time with [`path!`] macro:
+//! ```rust
+//! # use slip_10::supported_curves::Secp256k1;
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! # let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//! # let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+//! let child_key_pair =
+//!     slip_10::derive_child_key_pair_with_path(&master_key_pair, slip_10::path!("m/1'/10"));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Common [BIP44](bip44) paths can be built with typed builder which makes sure that the right
+//! levels are hardened:
+//! ```rust
+//! use slip_10::bip44::{Bip44Path, Change, Purpose};
+//! use slip_10::{HardenedIndex, NonHardenedIndex};
+//!
+//! let path = Bip44Path::new(Purpose::Bip44, HardenedIndex::new(0), HardenedIndex::new(0))
+//!     .change(Change::Internal)
+//!     .index(NonHardenedIndex::new(5))
+//!     .to_path();
+//! assert_eq!(path.as_slice().len(), 5);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
 //! [slip10-spec]: https://github.com/satoshilabs/slips/blob/master/slip-0010.md
 //! [bip32-spec]: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki
+//! [RustCrypto]: https://github.com/RustCrypto/elliptic-curves
+//! [rust-bitcoin]: https://github.com/rust-bitcoin/rust-bitcoin
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(missing_docs, unsafe_code)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::ops;
 
 use generic_array::{
@@ -51,15 +180,146 @@ use generic_array::{
 };
 use generic_ec::{Curve, Point, Scalar, SecretScalar};
 use hmac::Mac as _;
+use subtle::ConstantTimeEq;
+
+use scheme::{DerivationScheme, Slip10};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
 
 #[cfg(any(
     feature = "curve-secp256k1",
     feature = "curve-secp256r1",
+    feature = "curve-ed25519",
     feature = "all-curves"
 ))]
 pub use generic_ec::curves as supported_curves;
 
+#[cfg(feature = "bip39")]
+pub use bip39;
+#[cfg(feature = "secrecy")]
+pub use secrecy;
+
+#[cfg(feature = "bitcoin-address")]
+pub mod address;
+#[cfg(feature = "bip32-serialization")]
+pub mod bip32;
+#[cfg(feature = "bip32-ed25519")]
+pub mod bip32_ed25519;
+#[cfg(feature = "bip38")]
+pub mod bip38;
+pub mod bip44;
+#[cfg(feature = "bip85")]
+pub mod bip85;
+#[cfg(feature = "alloc")]
+pub mod cache;
+#[cfg(feature = "cardano")]
+pub mod cardano;
+mod chain_code;
+pub mod commitment;
+#[cfg(feature = "cosmos")]
+pub mod cosmos;
+#[cfg(feature = "curve-curve25519")]
+pub mod curve25519;
+#[cfg(feature = "descriptors")]
+pub mod descriptor;
+#[cfg(feature = "curve-ed25519")]
+pub mod ed25519;
+#[cfg(feature = "eip2333")]
+pub mod eip2333;
+#[cfg(feature = "electrum")]
+pub mod electrum;
 pub mod errors;
+#[cfg(feature = "keccak")]
+pub mod ethereum;
+#[cfg(any(feature = "curve-ed25519", feature = "curve-curve25519"))]
+mod hardened_only;
+#[cfg(feature = "hex")]
+mod hex_encoding;
+#[cfg(feature = "keystore")]
+pub mod keystore;
+#[cfg(feature = "musig2")]
+pub mod musig2;
+#[cfg(feature = "nip06")]
+pub mod nip06;
+mod path;
+#[cfg(feature = "bitcoin")]
+mod rust_bitcoin;
+#[cfg(any(feature = "k256", feature = "p256"))]
+pub mod rustcrypto;
+pub mod scheme;
+mod seed;
+#[cfg(feature = "serde")]
+mod serde_utils;
+#[cfg(feature = "bip32-serialization")]
+pub mod slip132;
+#[cfg(feature = "slip39")]
+pub mod slip39;
+#[cfg(feature = "slip44")]
+pub mod slip44;
+#[cfg(feature = "solana")]
+pub mod solana;
+#[cfg(feature = "stellar")]
+pub mod stellar;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+#[cfg(feature = "taproot")]
+pub mod taproot;
+pub mod threshold;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "bip32-serialization")]
+pub mod watch_only;
+#[cfg(feature = "web3-keystore")]
+pub mod web3_keystore;
+#[cfg(feature = "wif")]
+pub mod wif;
+
+pub use chain_code::ChainCode;
+#[cfg(feature = "hex")]
+pub use hex_encoding::HexEncoding;
+pub use path::{DerivationPath, StaticPath};
+pub use seed::Seed;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::path::{const_parse_path, const_path_len};
+}
+
+/// Constructs a derivation path validated at compile time
+///
+/// Takes a path in the same format as [`DerivationPath`] parsing (e.g. `m/44'/60'/0'/0/0`), and
+/// expands to a const array of [`ChildIndex`]. Malformed path or out of range child index is a
+/// compile-time error.
+///
+/// ### Example
+/// ```rust
+/// use slip_10::{supported_curves::Secp256k1, ChildIndex};
+///
+/// const PATH: [ChildIndex; 5] = slip_10::path!("m/44'/60'/0'/0/0");
+///
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+/// let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+/// let child_key_pair = slip_10::derive_child_key_pair_with_path(&master_key_pair, PATH);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// Malformed path doesn't compile:
+/// ```rust,compile_fail
+/// let path = slip_10::path!("m/44'/60'/0'/0//0");
+/// ```
+/// ```rust,compile_fail
+/// let path = slip_10::path!("m/2147483648'");
+/// ```
+#[macro_export]
+macro_rules! path {
+    ($path:expr) => {{
+        const PATH: &str = $path;
+        const INDEXES: [$crate::ChildIndex; $crate::__private::const_path_len(PATH)] =
+            $crate::__private::const_parse_path(PATH);
+        INDEXES
+    }};
+}
 
 type HmacSha512 = hmac::Hmac<sha2::Sha512>;
 /// Beggining of hardened child indexes
@@ -71,8 +331,8 @@ type HmacSha512 = hmac::Hmac<sha2::Sha512>;
 /// ```rust
 /// use slip_10::supported_curves::Secp256k1;
 ///
-/// # let seed = b"do not use this seed in prod :)".as_slice();
-/// let master_key = slip_10::derive_master_key::<Secp256k1>(seed)?;
+/// # let seed = slip_10::Seed::new(b"do not use this seed in prod :)")?;
+/// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
 /// let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
 ///
 /// let hardened_child = slip_10::derive_child_key_pair(
@@ -85,7 +345,9 @@ type HmacSha512 = hmac::Hmac<sha2::Sha512>;
 pub const H: u32 = 1 << 31;
 
 /// Child index, whether hardened or not
-#[derive(Clone, Copy, Debug)]
+///
+/// Indexes are ordered by their `u32` value, i.e. all non-hardened indexes go before hardened ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(into = "u32"))]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(from = "u32"))]
 pub enum ChildIndex {
@@ -96,13 +358,13 @@ pub enum ChildIndex {
 }
 
 /// Child index in range $2^{31} \le i < 2^{32}$ corresponing to a hardened wallet
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(into = "u32"))]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(try_from = "u32"))]
 pub struct HardenedIndex(u32);
 
 /// Child index in range $0 \le i < 2^{31}$ corresponing to a non-hardened wallet
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize), serde(into = "u32"))]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(try_from = "u32"))]
 pub struct NonHardenedIndex(u32);
@@ -116,13 +378,19 @@ pub struct NonHardenedIndex(u32);
 )]
 pub struct ExtendedPublicKey<E: Curve> {
     /// The public key that can be used for signature verification
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<generic_ec::serde::PreferCompact>")
+    )]
     pub public_key: Point<E>,
     /// A chain code that is used to derive child keys
     pub chain_code: ChainCode,
 }
 
 /// Extended secret key
-#[derive(Clone, Debug)]
+///
+/// `Debug` output doesn't include the secret key unless `insecure-debug` feature is enabled.
+#[derive(Clone)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
@@ -130,6 +398,10 @@ pub struct ExtendedPublicKey<E: Curve> {
 )]
 pub struct ExtendedSecretKey<E: Curve> {
     /// The secret key that can be used for signing
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<generic_ec::serde::PreferCompact>")
+    )]
     pub secret_key: SecretScalar<E>,
     /// A chain code that is used to derive child keys
     pub chain_code: ChainCode,
@@ -154,25 +426,105 @@ pub struct ExtendedKeyPair<E: Curve> {
 )]
 pub struct DerivedShift<E: Curve> {
     /// Derived shift
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<generic_ec::serde::PreferCompact>")
+    )]
     pub shift: Scalar<E>,
     /// Derived child extended public key
     pub child_public_key: ExtendedPublicKey<E>,
 }
 
-/// Chain code of extended key as defined in SLIP-10
-pub type ChainCode = [u8; 32];
+/// Kind of secret sharing of the parent secret key, see [`DerivedShift::apply_to_share`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SecretSharing {
+    /// Polynomial (Shamir) secret sharing: secret key is $f(0)$, and $i$-th signer holds $f(i)$
+    ///
+    /// Every signer adds the shift to its share. It's also what needs to be done if signers
+    /// convert their shares into additive ones by multiplying them by Lagrange coefficients
+    /// at signing time: coefficients sum up to $1$, so the shift is added exactly once.
+    Polynomial,
+    /// Additive secret sharing: secret key is a sum of all shares
+    ///
+    /// Only one, designated, signer adds the shift to its share. Others keep their shares
+    /// unchanged.
+    Additive {
+        /// Whether this signer is the designated one
+        designated: bool,
+    },
+}
+
+/// Statistics of a single derivation step
+///
+/// SLIP10 derivation retries with a different HMAC input if the derived key is invalid. It's
+/// astronomically unlikely to happen for supported curves, but SLIP10 test vectors cover it.
+/// Stats tell how many retries occurred, so conformance tests can check that retry path behaves
+/// as specified.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DerivationStats {
+    /// Number of retries
+    pub retries: usize,
+}
 
 impl HardenedIndex {
     /// The smallest possible value of hardened index. Equals to $2^{31}$
     pub const MIN: Self = Self(H);
     /// The largest possible value of hardened index. Equals to $2^{32} - 1$
     pub const MAX: Self = Self(u32::MAX);
+
+    /// Constructs hardened index $i + 2^{31}$, i.e. `new(0)` corresponds to `0'`
+    ///
+    /// Can be used in const context. Panics if $i \ge 2^{31}$, see [`checked_new`](Self::checked_new)
+    /// for a non-panicking version.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use slip_10::HardenedIndex;
+    ///
+    /// const ACCOUNT: HardenedIndex = HardenedIndex::new(0);
+    /// assert_eq!(*ACCOUNT, slip_10::H);
+    /// ```
+    pub const fn new(i: u32) -> Self {
+        match Self::checked_new(i) {
+            Some(index) => index,
+            None => panic!("hardened index is out of range"),
+        }
+    }
+
+    /// Constructs hardened index $i + 2^{31}$, returns `None` if $i \ge 2^{31}$
+    pub const fn checked_new(i: u32) -> Option<Self> {
+        if i < H {
+            Some(Self(H + i))
+        } else {
+            None
+        }
+    }
 }
 impl NonHardenedIndex {
     /// The smallest possible value of non-hardened index. Equals to $0$
     pub const MIN: Self = Self(0);
     /// The largest possible value of non-hardened index. Equals to $2^{31} - 1$
     pub const MAX: Self = Self(H - 1);
+
+    /// Constructs non-hardened index $i$
+    ///
+    /// Can be used in const context. Panics if $i \ge 2^{31}$, see [`checked_new`](Self::checked_new)
+    /// for a non-panicking version.
+    pub const fn new(i: u32) -> Self {
+        match Self::checked_new(i) {
+            Some(index) => index,
+            None => panic!("non-hardened index is out of range"),
+        }
+    }
+
+    /// Constructs non-hardened index $i$, returns `None` if $i \ge 2^{31}$
+    pub const fn checked_new(i: u32) -> Option<Self> {
+        if i < H {
+            Some(Self(i))
+        } else {
+            None
+        }
+    }
 }
 impl ops::Deref for HardenedIndex {
     type Target = u32;
@@ -203,6 +555,16 @@ impl From<u32> for ChildIndex {
         }
     }
 }
+impl From<HardenedIndex> for ChildIndex {
+    fn from(value: HardenedIndex) -> Self {
+        Self::Hardened(value)
+    }
+}
+impl From<NonHardenedIndex> for ChildIndex {
+    fn from(value: NonHardenedIndex) -> Self {
+        Self::NonHardened(value)
+    }
+}
 impl TryFrom<u32> for HardenedIndex {
     type Error = errors::OutOfRange;
     fn try_from(value: u32) -> Result<Self, Self::Error> {
@@ -221,6 +583,24 @@ impl TryFrom<u32> for NonHardenedIndex {
         }
     }
 }
+impl TryFrom<ChildIndex> for HardenedIndex {
+    type Error = errors::OutOfRange;
+    fn try_from(value: ChildIndex) -> Result<Self, Self::Error> {
+        match value {
+            ChildIndex::Hardened(v) => Ok(v),
+            _ => Err(errors::OutOfRange),
+        }
+    }
+}
+impl TryFrom<ChildIndex> for NonHardenedIndex {
+    type Error = errors::OutOfRange;
+    fn try_from(value: ChildIndex) -> Result<Self, Self::Error> {
+        match value {
+            ChildIndex::NonHardened(v) => Ok(v),
+            _ => Err(errors::OutOfRange),
+        }
+    }
+}
 impl From<ChildIndex> for u32 {
     fn from(value: ChildIndex) -> Self {
         match value {
@@ -239,31 +619,174 @@ impl From<NonHardenedIndex> for u32 {
         value.0
     }
 }
+/// Parses child index like `5` or `5'`
+///
+/// Hardened index is marked with `'`, `h` or `H` suffix, i.e. `5'` corresponds to child index
+/// $5 + 2^{31}$. Index without suffix is a raw `u32` index, so `2147483653` parses as `5'` as well.
+///
+/// Note that error type is [`ParseChildIndexError`](errors::ParseChildIndexError) rather than
+/// `ParseIntError`, as index with a suffix may also be out of range.
 impl core::str::FromStr for ChildIndex {
-    type Err = core::num::ParseIntError;
+    type Err = errors::ParseChildIndexError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        s.parse::<u32>().map(Into::into)
+        if s.ends_with(['\'', 'h', 'H']) {
+            path::parse_child_index(s)
+        } else {
+            path::parse_u32(s).map(Into::into)
+        }
+    }
+}
+/// Displays child index like `5` or `5'` (for hardened index $5 + 2^{31}$)
+impl core::fmt::Display for ChildIndex {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Hardened(i) => write!(f, "{}'", i.0 - H),
+            Self::NonHardened(i) => write!(f, "{}", i.0),
+        }
     }
 }
+/// Parses hardened index like `5'` or raw `u32` index like `2147483653`
+///
+/// Accepts the same syntax as [`ChildIndex`], returns an error if index is not hardened.
 impl core::str::FromStr for HardenedIndex {
     type Err = errors::ParseChildIndexError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let index = s
-            .parse::<u32>()
-            .map_err(errors::ParseChildIndexError::ParseInt)?;
+        let index = s.parse::<ChildIndex>()?;
         HardenedIndex::try_from(index).map_err(errors::ParseChildIndexError::IndexNotInRange)
     }
 }
+/// Parses non-hardened index like `5`
+///
+/// Accepts the same syntax as [`ChildIndex`], returns an error if index is hardened.
 impl core::str::FromStr for NonHardenedIndex {
     type Err = errors::ParseChildIndexError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let index = s
-            .parse::<u32>()
-            .map_err(errors::ParseChildIndexError::ParseInt)?;
+        let index = s.parse::<ChildIndex>()?;
         NonHardenedIndex::try_from(index).map_err(errors::ParseChildIndexError::IndexNotInRange)
     }
 }
 
+impl<E: Curve> ExtendedPublicKey<E> {
+    /// Derives a child public key
+    ///
+    /// Same as [`derive_child_public_key`], but reads fluently in method chains.
+    pub fn derive_child(&self, child_index: NonHardenedIndex) -> Self {
+        derive_child_public_key(self, child_index)
+    }
+
+    /// Derives a child public key with specified derivation path
+    ///
+    /// Path may be given as [`NonHardenedIndex`]es, or as [`ChildIndex`]es (e.g. a
+    /// [`DerivationPath`]), in which case an error is returned if the path contains a hardened
+    /// index, as it cannot be derived from the public key. If derivation path is empty,
+    /// a copy of `self` is returned.
+    ///
+    /// ### Example
+    /// Derive a chain of receive addresses on a watch-only server:
+    /// ```rust
+    /// use slip_10::{supported_curves::Secp256k1, DerivationPath};
+    /// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+    /// # let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+    /// let account_key = slip_10::ExtendedPublicKey::from(&master_key);
+    ///
+    /// for i in 0..5 {
+    ///     let address_key = account_key.derive_path([0, i])?;
+    /// }
+    ///
+    /// let hardened_path: DerivationPath = "m/0/1'".parse()?;
+    /// assert!(account_key.derive_path(&hardened_path).is_err());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn derive_path(
+        &self,
+        path: impl IntoIterator<Item = impl Into<ChildIndex>>,
+    ) -> Result<Self, errors::DeriveError> {
+        let mut derived_key = *self;
+        for (level, child_index) in path.into_iter().enumerate() {
+            derived_key = match child_index.into() {
+                ChildIndex::NonHardened(child_index) => {
+                    derive_child_public_key(&derived_key, child_index)
+                }
+                child_index @ ChildIndex::Hardened(_) => {
+                    return Err(errors::DeriveError::HardenedInPublicPath { level, child_index })
+                }
+            };
+        }
+        Ok(derived_key)
+    }
+}
+
+#[cfg(feature = "ripemd")]
+impl<E: Curve> ExtendedPublicKey<E> {
+    /// Returns key identifier as defined in [BIP32][bip32-id]
+    ///
+    /// Identifier is `RIPEMD160(SHA256(public_key))`, where public key is in compressed form.
+    ///
+    /// [bip32-id]: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#key-identifiers
+    pub fn key_identifier(&self) -> [u8; 20] {
+        use sha2::Digest as _;
+        let hash = sha2::Sha256::digest(self.public_key.to_bytes(true));
+        ripemd::Ripemd160::digest(hash).into()
+    }
+
+    /// Returns key fingerprint: the first 4 bytes of [key identifier](Self::key_identifier)
+    ///
+    /// Fingerprint of the parent key is used in BIP32 serialization of child keys.
+    pub fn fingerprint(&self) -> [u8; 4] {
+        let id = self.key_identifier();
+        [id[0], id[1], id[2], id[3]]
+    }
+}
+
+impl<E: Curve + SupportedCurve> ExtendedPublicKey<E> {
+    /// Serializes the key into 65 bytes: compressed public key followed by chain code
+    ///
+    /// Key can be deserialized back via `TryFrom<&[u8]>`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use slip_10::{supported_curves::Secp256k1, ExtendedPublicKey};
+    ///
+    /// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+    /// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+    /// let master_public_key = ExtendedPublicKey::from(&master_key);
+    /// let bytes = master_public_key.to_bytes();
+    ///
+    /// let restored = ExtendedPublicKey::<Secp256k1>::try_from(&bytes[..])?;
+    /// assert_eq!(restored.public_key, master_public_key.public_key);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_bytes(&self) -> [u8; 65] {
+        let () = AssertEncodingSizes::<E>::OK;
+        let mut out = [0u8; 65];
+        out[..33].copy_from_slice(&self.public_key.to_bytes(true));
+        out[33..].copy_from_slice(self.chain_code.as_slice());
+        out
+    }
+}
+
+/// Deserializes the key from 65 bytes produced by [`ExtendedPublicKey::to_bytes`]
+///
+/// Returns an error if input has invalid length, or public key is not a valid point or is
+/// the identity point.
+impl<E: Curve + SupportedCurve> TryFrom<&[u8]> for ExtendedPublicKey<E> {
+    type Error = errors::InvalidKey;
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != 65 {
+            return Err(errors::InvalidKey);
+        }
+        let (public_key, chain_code) = bytes.split_at(33);
+        let public_key = Point::<E>::from_bytes(public_key)
+            .ok()
+            .filter(|p| !p.is_zero())
+            .ok_or(errors::InvalidKey)?;
+        Ok(Self {
+            public_key,
+            chain_code: ChainCode::new(chain_code.try_into().expect("exactly 32 bytes")),
+        })
+    }
+}
+
 impl<E: Curve> From<&ExtendedSecretKey<E>> for ExtendedPublicKey<E> {
     fn from(sk: &ExtendedSecretKey<E>) -> Self {
         ExtendedPublicKey {
@@ -273,6 +796,159 @@ impl<E: Curve> From<&ExtendedSecretKey<E>> for ExtendedPublicKey<E> {
     }
 }
 
+impl<E: Curve> ExtendedSecretKey<E> {
+    /// Derives a hardened child secret key
+    ///
+    /// Same as [`derive_child_secret_key`], but reads fluently in method chains.
+    pub fn derive_child(&self, child_index: HardenedIndex) -> Self {
+        derive_child_secret_key(self, child_index)
+    }
+
+    /// Derives a child secret key with specified hardened derivation path
+    ///
+    /// Same as [`derive_child_secret_key_with_path`]. If derivation path is empty,
+    /// a copy of `self` is returned.
+    pub fn derive_path(&self, path: impl IntoIterator<Item = HardenedIndex>) -> Self {
+        derive_child_secret_key_with_path(self, path)
+    }
+}
+
+impl<E: Curve + SupportedCurve> ExtendedSecretKey<E> {
+    /// Serializes the key into 64 bytes: secret scalar (big-endian) followed by chain code
+    ///
+    /// Output contains secret key, make sure it's handled accordingly.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use slip_10::{supported_curves::Secp256k1, ExtendedSecretKey};
+    ///
+    /// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+    /// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+    /// let bytes = master_key.to_bytes();
+    ///
+    /// let restored = ExtendedSecretKey::<Secp256k1>::from_bytes(&bytes)?;
+    /// assert_eq!(restored.chain_code, master_key.chain_code);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let () = AssertEncodingSizes::<E>::OK;
+        let mut out = [0u8; 64];
+        out[..32].copy_from_slice(&self.secret_key.as_ref().to_be_bytes());
+        out[32..].copy_from_slice(self.chain_code.as_slice());
+        out
+    }
+
+    /// Deserializes the key from 64 bytes produced by [`to_bytes`](Self::to_bytes)
+    ///
+    /// Returns an error if secret scalar is zero or not less than the curve order. Validation
+    /// doesn't branch on the secret key value.
+    pub fn from_bytes(bytes: &[u8; 64]) -> Result<Self, errors::InvalidKey> {
+        let (secret_key, chain_code) = bytes.split_at(32);
+        let mut secret_key = Scalar::<E>::from_be_bytes(secret_key)
+            .ok()
+            .filter(|sk| !bool::from(subtle::ConstantTimeEq::ct_eq(sk, &Scalar::zero())))
+            .ok_or(errors::InvalidKey)?;
+        Ok(Self {
+            secret_key: SecretScalar::new(&mut secret_key),
+            chain_code: ChainCode::new(chain_code.try_into().expect("exactly 32 bytes")),
+        })
+    }
+}
+
+impl<E: Curve> DerivedShift<E> {
+    /// Applies the shift to a share of the parent secret key
+    ///
+    /// Returns a share of the child secret key along with the child chain code. Secret sharing
+    /// scheme determines whether the shift needs to be added to the share, see [`SecretSharing`].
+    ///
+    /// ### Example
+    /// ```rust
+    /// use generic_ec::{Point, Scalar, SecretScalar};
+    /// use slip_10::{supported_curves::Secp256k1, SecretSharing};
+    ///
+    /// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+    /// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+    /// let master_public_key = slip_10::ExtendedPublicKey::from(&master_key);
+    ///
+    /// // Secret key is shared additively between two signers
+    /// let share1 = SecretScalar::<Secp256k1>::new(&mut Scalar::from(42));
+    /// let share2 = SecretScalar::new(&mut (master_key.secret_key.as_ref() - share1.as_ref()));
+    ///
+    /// let shift = slip_10::derive_path_shift(&master_public_key, [1.try_into()?, 10.try_into()?]);
+    /// let (child_share1, chain_code) =
+    ///     shift.apply_to_share(&share1, SecretSharing::Additive { designated: true });
+    /// let (child_share2, _) =
+    ///     shift.apply_to_share(&share2, SecretSharing::Additive { designated: false });
+    ///
+    /// assert_eq!(
+    ///     Point::generator() * (child_share1.as_ref() + child_share2.as_ref()),
+    ///     shift.child_public_key.public_key,
+    /// );
+    /// assert_eq!(chain_code, shift.child_public_key.chain_code);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn apply_to_share(
+        &self,
+        share: &SecretScalar<E>,
+        sharing: SecretSharing,
+    ) -> (SecretScalar<E>, ChainCode) {
+        let mut child_share = match sharing {
+            SecretSharing::Polynomial | SecretSharing::Additive { designated: true } => {
+                share.as_ref() + self.shift
+            }
+            SecretSharing::Additive { designated: false } => *share.as_ref(),
+        };
+        (
+            SecretScalar::new(&mut child_share),
+            self.child_public_key.chain_code,
+        )
+    }
+}
+
+impl<E: Curve + SupportedCurve> DerivedShift<E> {
+    /// Serializes the shift into 97 bytes: shift (big-endian) followed by
+    /// [serialized](ExtendedPublicKey::to_bytes) child public key
+    ///
+    /// It can be sent to parties which hold shares of the parent secret key, so they don't need
+    /// to derive the shift on their own.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use slip_10::{supported_curves::Secp256k1, DerivedShift};
+    ///
+    /// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+    /// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+    /// let master_public_key = slip_10::ExtendedPublicKey::from(&master_key);
+    ///
+    /// let shift = slip_10::derive_path_shift(&master_public_key, [1.try_into()?, 10.try_into()?]);
+    /// let bytes = shift.to_bytes();
+    ///
+    /// let received = DerivedShift::<Secp256k1>::from_bytes(&bytes)?;
+    /// assert_eq!(received.shift, shift.shift);
+    /// assert_eq!(received.child_public_key, shift.child_public_key);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_bytes(&self) -> [u8; 97] {
+        let mut out = [0u8; 97];
+        out[..32].copy_from_slice(&self.shift.to_be_bytes());
+        out[32..].copy_from_slice(&self.child_public_key.to_bytes());
+        out
+    }
+
+    /// Deserializes the shift from 97 bytes produced by [`to_bytes`](Self::to_bytes)
+    ///
+    /// Returns an error if shift is not less than the curve order, or child public key is
+    /// invalid. Note that it's not checked that the shift corresponds to the child public key:
+    /// it can't be done without knowing the parent public key.
+    pub fn from_bytes(bytes: &[u8; 97]) -> Result<Self, errors::InvalidKey> {
+        let (shift, child_public_key) = bytes.split_at(32);
+        Ok(Self {
+            shift: Scalar::from_be_bytes(shift).map_err(|_| errors::InvalidKey)?,
+            child_public_key: ExtendedPublicKey::try_from(child_public_key)?,
+        })
+    }
+}
+
 impl<E: Curve> From<ExtendedSecretKey<E>> for ExtendedKeyPair<E> {
     fn from(secret_key: ExtendedSecretKey<E>) -> Self {
         Self {
@@ -283,6 +959,36 @@ impl<E: Curve> From<ExtendedSecretKey<E>> for ExtendedKeyPair<E> {
 }
 
 impl<E: Curve> ExtendedKeyPair<E> {
+    /// Constructs a key pair from extended secret and public keys
+    ///
+    /// Returns an error if the public key doesn't correspond to the secret key, or if chain codes
+    /// differ. Use [`From<ExtendedSecretKey>`](#impl-From<ExtendedSecretKey<E>>-for-ExtendedKeyPair<E>)
+    /// when there's only a secret key.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use slip_10::{supported_curves::Secp256k1, ExtendedKeyPair, ExtendedPublicKey};
+    ///
+    /// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+    /// let secret_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+    /// let public_key = ExtendedPublicKey::from(&secret_key);
+    /// let key_pair = ExtendedKeyPair::try_from_parts(secret_key, public_key)?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_from_parts(
+        secret_key: ExtendedSecretKey<E>,
+        public_key: ExtendedPublicKey<E>,
+    ) -> Result<Self, errors::MismatchedKeyPair> {
+        let expected_public_key = ExtendedPublicKey::from(&secret_key);
+        if !bool::from(expected_public_key.ct_eq(&public_key)) {
+            return Err(errors::MismatchedKeyPair);
+        }
+        Ok(Self {
+            public_key,
+            secret_key,
+        })
+    }
+
     /// Returns chain code of the key
     pub fn chain_code(&self) -> &ChainCode {
         debug_assert_eq!(self.public_key.chain_code, self.secret_key.chain_code);
@@ -298,8 +1004,265 @@ impl<E: Curve> ExtendedKeyPair<E> {
     pub fn secret_key(&self) -> &ExtendedSecretKey<E> {
         &self.secret_key
     }
+
+    /// Returns extended public key, consuming the key pair
+    pub fn into_public_key(self) -> ExtendedPublicKey<E> {
+        self.public_key
+    }
+
+    /// Returns extended secret key, consuming the key pair
+    ///
+    /// Secret key is moved out of the key pair, no copies of it are made.
+    pub fn into_secret_key(self) -> ExtendedSecretKey<E> {
+        self.into_parts().0
+    }
+
+    /// Returns extended secret and public keys, consuming the key pair
+    ///
+    /// Inverse of [`ExtendedKeyPair::try_from_parts`]. Secret key is moved out of the key pair,
+    /// no copies of it are made.
+    pub fn into_parts(self) -> (ExtendedSecretKey<E>, ExtendedPublicKey<E>) {
+        (self.secret_key, self.public_key)
+    }
+
+    /// Derives a child key pair
+    ///
+    /// Same as [`derive_child_key_pair`], but reads fluently in method chains.
+    ///
+    /// ### Example
+    /// Derive child key m/44<sub>H</sub>/0 from master key
+    /// ```rust
+    /// use slip_10::{supported_curves::Secp256k1, H};
+    ///
+    /// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+    /// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+    /// let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+    ///
+    /// let child_key_pair = master_key_pair.derive_child(44 + H).derive_child(0);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn derive_child(&self, child_index: impl Into<ChildIndex>) -> Self {
+        derive_child_key_pair(self, child_index)
+    }
+
+    /// Derives a child key pair with specified derivation path
+    ///
+    /// Same as [`derive_child_key_pair_with_path`]. If derivation path is empty,
+    /// a copy of `self` is returned.
+    pub fn derive_path(&self, path: impl IntoIterator<Item = impl Into<ChildIndex>>) -> Self {
+        derive_child_key_pair_with_path(self, path)
+    }
+}
+
+impl PartialOrd for ChildIndex {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ChildIndex {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        u32::from(*self).cmp(&u32::from(*other))
+    }
+}
+
+/// Encoded as `u32` value tagged with `slip10.ChildIndex`
+///
+/// Hardened flag is the top bit of the value, so it doesn't need to be encoded separately.
+#[cfg(feature = "udigest")]
+impl udigest::Digestable for ChildIndex {
+    fn unambiguously_encode<B: udigest::Buffer>(&self, encoder: udigest::encoding::EncodeValue<B>) {
+        encoder
+            .encode_leaf()
+            .with_tag(b"slip10.ChildIndex")
+            .chain(u32::from(*self).to_be_bytes());
+    }
+}
+
+/// Encoded exactly as [`ChildIndex`] with the same value
+#[cfg(feature = "udigest")]
+impl udigest::Digestable for HardenedIndex {
+    fn unambiguously_encode<B: udigest::Buffer>(&self, encoder: udigest::encoding::EncodeValue<B>) {
+        ChildIndex::from(*self).unambiguously_encode(encoder)
+    }
+}
+
+/// Encoded exactly as [`ChildIndex`] with the same value
+#[cfg(feature = "udigest")]
+impl udigest::Digestable for NonHardenedIndex {
+    fn unambiguously_encode<B: udigest::Buffer>(&self, encoder: udigest::encoding::EncodeValue<B>) {
+        ChildIndex::from(*self).unambiguously_encode(encoder)
+    }
+}
+
+/// Generates any `u32` value, so hardened and non-hardened indexes are equally likely
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ChildIndex {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u32::arbitrary(u).map(Self::from)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u32::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for HardenedIndex {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u32::arbitrary(u).map(|i| Self(i | H))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u32::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for NonHardenedIndex {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u32::arbitrary(u).map(|i| Self(i & !H))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u32::size_hint(depth)
+    }
+}
+
+impl<E: Curve> ConstantTimeEq for ExtendedPublicKey<E> {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.public_key.ct_eq(&other.public_key) & self.chain_code.ct_eq(&other.chain_code)
+    }
+}
+
+impl<E: Curve> PartialEq for ExtendedPublicKey<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.public_key == other.public_key && self.chain_code == other.chain_code
+    }
+}
+
+impl<E: Curve> Eq for ExtendedPublicKey<E> {}
+
+impl<E: Curve> core::hash::Hash for ExtendedPublicKey<E> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.public_key.hash(state);
+        self.chain_code.hash(state);
+    }
+}
+
+/// Encoded as a struct tagged with `slip10.ExtendedPublicKey`, public key encoding includes the
+/// curve name
+#[cfg(feature = "udigest")]
+impl<E: Curve> udigest::Digestable for ExtendedPublicKey<E> {
+    fn unambiguously_encode<B: udigest::Buffer>(&self, encoder: udigest::encoding::EncodeValue<B>) {
+        let mut s = encoder
+            .encode_struct()
+            .with_tag(b"slip10.ExtendedPublicKey");
+        self.public_key
+            .unambiguously_encode(s.add_field("public_key"));
+        self.chain_code
+            .unambiguously_encode(s.add_field("chain_code"));
+        s.finish();
+    }
+}
+
+impl<E: Curve> core::fmt::Debug for ExtendedSecretKey<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ExtendedSecretKey")
+            .field(
+                "secret_key",
+                &DebugSecret("SecretScalar", self.secret_key.as_ref()),
+            )
+            .field("chain_code", &self.chain_code)
+            .finish()
+    }
+}
+
+impl<E: Curve> ConstantTimeEq for ExtendedSecretKey<E> {
+    /// Compares secret keys and chain codes in constant time
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.secret_key.as_ref().ct_eq(other.secret_key.as_ref())
+            & self.chain_code.ct_eq(&other.chain_code)
+    }
+}
+
+/// Compares keys in constant time, see [`ConstantTimeEq`] implementation
+impl<E: Curve> PartialEq for ExtendedSecretKey<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<E: Curve> Eq for ExtendedSecretKey<E> {}
+
+impl<E: Curve> ConstantTimeEq for ExtendedKeyPair<E> {
+    /// Compares extended secret keys in constant time
+    ///
+    /// Public key is determined by the secret key, so it doesn't need to be compared.
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.secret_key.ct_eq(&other.secret_key)
+    }
+}
+
+/// Compares keys in constant time, see [`ConstantTimeEq`] implementation
+impl<E: Curve> PartialEq for ExtendedKeyPair<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<E: Curve> Eq for ExtendedKeyPair<E> {}
+
+#[cfg(feature = "zeroize")]
+impl<E: Curve> Zeroize for ExtendedSecretKey<E> {
+    /// Erases the secret key and the chain code
+    ///
+    /// [`SecretScalar`] is replaced with zero. The original scalar is erased from
+    /// memory once all its clones are dropped.
+    fn zeroize(&mut self) {
+        self.secret_key = SecretScalar::new(&mut Scalar::zero());
+        self.chain_code.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<E: Curve> Zeroize for ExtendedKeyPair<E> {
+    fn zeroize(&mut self) {
+        self.public_key.public_key.zeroize();
+        self.public_key.chain_code.zeroize();
+        self.secret_key.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<E: Curve> Zeroize for DerivedShift<E> {
+    fn zeroize(&mut self) {
+        self.shift.zeroize();
+        self.child_public_key.public_key.zeroize();
+        self.child_public_key.chain_code.zeroize();
+    }
+}
+
+#[cfg(feature = "secrecy")]
+impl<E: Curve> secrecy::CloneableSecret for ExtendedSecretKey<E> {}
+
+#[cfg(feature = "secrecy")]
+impl<E: Curve> secrecy::CloneableSecret for ExtendedKeyPair<E> {}
+
+/// Exposes the extended secret key of the pair
+#[cfg(feature = "secrecy")]
+impl<E: Curve> secrecy::ExposeSecret<ExtendedSecretKey<E>> for ExtendedKeyPair<E> {
+    fn expose_secret(&self) -> &ExtendedSecretKey<E> {
+        &self.secret_key
+    }
 }
 
+#[cfg(feature = "secrecy-serde")]
+impl<E: Curve> secrecy::SerializableSecret for ExtendedSecretKey<E> {}
+
+#[cfg(feature = "secrecy-serde")]
+impl<E: Curve> secrecy::SerializableSecret for ExtendedKeyPair<E> {}
+
 #[cfg(feature = "serde")]
 impl<E: Curve> serde::Serialize for ExtendedKeyPair<E> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -323,13 +1286,41 @@ impl<'de, E: Curve> serde::Deserialize<'de> for ExtendedKeyPair<E> {
 
 /// Marker for a curve supported by SLIP10 specs and this library
 ///
-/// Only implement this trait for the curves that are supported by SLIP10 specs.
-/// Curves provided by the crate out-of-box in [supported_curves] module already
-/// implement this trait.
+/// Only implement this trait for the curves that are supported by SLIP10 specs, or
+/// use [`CurveType::Custom`] for curves that SLIP10 doesn't cover. Curves provided by
+/// the crate out-of-box in [supported_curves] module already implement this trait.
+///
+/// Serialization of keys of supported curves (e.g. [`ExtendedPublicKey::to_bytes`] or BIP32
+/// `xpub`) assumes 33 bytes compressed points and 32 bytes scalars, like in secp256k1 and
+/// secp256r1. Serializing keys of a curve with different encoding sizes fails to compile.
+///
+/// Curve type is inferred from `E` in [`derive_master_key`], so it can't be mismatched with
+/// the curve. Curves that don't implement this trait can't be used to derive a master key:
+/// ```rust,compile_fail
+/// // ed25519 is handled by `slip_10::ed25519` module, it doesn't implement `SupportedCurve`
+/// let master_key = slip_10::derive_master_key::<slip_10::supported_curves::Ed25519>(
+///     &slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap(),
+/// );
+/// ```
 pub trait SupportedCurve {
     /// Specifies which curve it is
     const CURVE_TYPE: CurveType;
 }
+
+/// Fails compilation if encodings of curve `E` don't fit fixed-size serialization of keys
+///
+/// Evaluate [`AssertEncodingSizes::OK`] in every serializer that assumes 33 bytes compressed
+/// points and 32 bytes scalars.
+pub(crate) struct AssertEncodingSizes<E>(core::marker::PhantomData<E>);
+
+impl<E: Curve> AssertEncodingSizes<E> {
+    pub(crate) const OK: () = assert!(
+        core::mem::size_of::<E::CompressedPointArray>() == 33
+            && core::mem::size_of::<E::ScalarArray>() == 32,
+        "curve must have 33 bytes compressed points and 32 bytes scalars"
+    );
+}
+
 #[cfg(feature = "curve-secp256k1")]
 impl SupportedCurve for supported_curves::Secp256k1 {
     const CURVE_TYPE: CurveType = CurveType::Secp256k1;
@@ -341,8 +1332,8 @@ impl SupportedCurve for supported_curves::Secp256r1 {
 
 /// Curves supported by SLIP-10 spec
 ///
-/// It's either secp256k1 or secp256r1. Note that SLIP-10 also supports ed25519 curve, but this library
-/// does not support it.
+/// It's either secp256k1 or secp256r1. Note that SLIP-10 also supports ed25519 and curve25519, but
+/// they are handled by dedicated [ed25519] and [curve25519] modules.
 ///
 /// `CurveType` is only needed for master key derivation.
 #[derive(Clone, Copy, Debug)]
@@ -351,54 +1342,264 @@ pub enum CurveType {
     Secp256k1,
     /// Secp256r1 curve
     Secp256r1,
+    /// Curve not covered by SLIP10 specs, with a custom curve tag
+    ///
+    /// Curve tag is used as an HMAC key in master key derivation, i.e. it's a domain separation
+    /// string like `"Bitcoin seed"`. Keys derived with custom curve tag are not compatible with
+    /// other SLIP10 implementations, unless they use the same tag.
+    ///
+    /// Implement [`SupportedCurve`] with `CURVE_TYPE = CurveType::Custom("MyCurve seed")` for a
+    /// curve not covered by SLIP10, and [`derive_master_key`] will work with it. Alternatively,
+    /// [`derive_master_key_with_curve_tag`] accepts a curve tag directly.
+    ///
+    /// Only curves with 33 bytes compressed points and 32 bytes scalars are supported, as keys
+    /// are serialized into fixed-size encodings. Serializing keys of other curves fails to
+    /// compile.
+    Custom(&'static str),
+}
+
+impl CurveType {
+    /// Returns curve tag used in master key derivation
+    ///
+    /// It's `"Bitcoin seed"` for secp256k1 and `"Nist256p1 seed"` for secp256r1 as defined
+    /// in SLIP10, or the custom tag for [`CurveType::Custom`].
+    pub const fn curve_tag(&self) -> &'static str {
+        match self {
+            Self::Secp256k1 => "Bitcoin seed",
+            Self::Secp256r1 => "Nist256p1 seed",
+            Self::Custom(tag) => tag,
+        }
+    }
+
+    /// Returns name of the curve as defined by [`Curve::CURVE_NAME`]
+    ///
+    /// Returns `None` for [`CurveType::Custom`] as its name is unknown.
+    pub const fn curve_name(&self) -> Option<&'static str> {
+        match self {
+            Self::Secp256k1 => Some("secp256k1"),
+            Self::Secp256r1 => Some("secp256r1"),
+            Self::Custom(_) => None,
+        }
+    }
 }
 
 /// Derives a master key from the seed
-///
-/// Seed must be 16-64 bytes long, otherwise an error is returned
 pub fn derive_master_key<E: Curve + SupportedCurve>(
-    seed: &[u8],
-) -> Result<ExtendedSecretKey<E>, errors::InvalidLength> {
-    let curve_tag = match E::CURVE_TYPE {
-        CurveType::Secp256k1 => "Bitcoin seed",
-        CurveType::Secp256r1 => "Nist256p1 seed",
-    };
-    derive_master_key_with_curve_tag(curve_tag.as_bytes(), seed)
+    seed: impl AsRef<Seed>,
+) -> ExtendedSecretKey<E> {
+    derive_master_key_with_curve_tag(E::CURVE_TYPE.curve_tag().as_bytes(), seed)
 }
 
-/// Derives a master key from the seed and the curve tag as defined in SLIP10
+/// Derives a master key from the seed for explicitly specified curve type
 ///
-/// It's preferred to use [derive_master_key] instead, as it automatically infers
-/// the curve tag for supported curves. The curve tag is not validated by the function,
-/// it's caller's responsibility to make sure that it complies with SLIP10.
+/// Prefer [`derive_master_key`] whenever possible, as it infers curve type from `E`. This
+/// function is useful when `E` doesn't implement [`SupportedCurve`], and curve type is only
+/// known at runtime. Curve type is checked against [`Curve::CURVE_NAME`], and an error is returned
+/// on mismatch, instead of silently deriving a key with a wrong curve tag. [`CurveType::Custom`]
+/// can't be checked, and is always accepted.
 ///
-/// Seed must be 16-64 bytes long, otherwise an error is returned
-pub fn derive_master_key_with_curve_tag<E: Curve>(
-    curve_tag: &[u8],
-    seed: &[u8],
-) -> Result<ExtendedSecretKey<E>, errors::InvalidLength> {
-    if !(16 <= seed.len() && seed.len() <= 64) {
-        return Err(errors::InvalidLength);
+/// ### Example
+/// ```rust
+/// use slip_10::{supported_curves::Secp256k1, CurveType};
+///
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// let master_key =
+///     slip_10::derive_master_key_with_curve_type::<Secp256k1>(CurveType::Secp256k1, &seed)?;
+///
+/// let mismatched =
+///     slip_10::derive_master_key_with_curve_type::<Secp256k1>(CurveType::Secp256r1, &seed);
+/// assert!(mismatched.is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn derive_master_key_with_curve_type<E: Curve>(
+    curve_type: CurveType,
+    seed: impl AsRef<Seed>,
+) -> Result<ExtendedSecretKey<E>, errors::DeriveMasterKeyError> {
+    if let Some(curve_name) = curve_type.curve_name() {
+        if curve_name != E::CURVE_NAME {
+            return Err(errors::DeriveMasterKeyError::CurveMismatch);
+        }
     }
+    Ok(derive_master_key_with_curve_tag(
+        curve_type.curve_tag().as_bytes(),
+        seed,
+    ))
+}
 
-    let hmac = HmacSha512::new_from_slice(curve_tag)
-        .expect("this never fails: hmac can handle keys of any size");
-    let mut i = hmac.clone().chain_update(seed).finalize().into_bytes();
+/// Derives a master key pair from the seed for explicitly specified curve type
+///
+/// Same as [`derive_master_key_with_curve_type`], but returns [`ExtendedKeyPair`] which is
+/// needed to derive child keys.
+///
+/// ### Example
+/// ```rust
+/// use slip_10::{supported_curves::Secp256k1, CurveType};
+///
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// let master_key_pair = slip_10::derive_master_key_pair::<Secp256k1>(CurveType::Secp256k1, &seed)?;
+/// let child_key_pair = slip_10::derive_child_key_pair_with_path(&master_key_pair, [1 + slip_10::H, 10]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn derive_master_key_pair<E: Curve>(
+    curve_type: CurveType,
+    seed: impl AsRef<Seed>,
+) -> Result<ExtendedKeyPair<E>, errors::DeriveMasterKeyError> {
+    derive_master_key_with_curve_type(curve_type, seed).map(ExtendedKeyPair::from)
+}
+
+/// Derives a key pair at the path from raw seed bytes
+///
+/// Validates the seed, parses the path (like `m/44'/0'/0'/0/1`), and derives the key pair,
+/// reporting any failure as [`DeriveError`](errors::DeriveError).
+///
+/// ### Example
+/// ```rust
+/// use slip_10::{errors::DeriveError, supported_curves::Secp256k1};
+///
+/// let key_pair = slip_10::derive_key_pair_from_seed::<Secp256k1>(
+///     b"16-64 bytes of high entropy",
+///     "m/44'/0'/0'/0/1",
+/// )?;
+///
+/// let result = slip_10::derive_key_pair_from_seed::<Secp256k1>(b"too short", "m/0");
+/// assert!(matches!(result, Err(DeriveError::InvalidSeedLength(_))));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn derive_key_pair_from_seed<E: Curve + SupportedCurve>(
+    seed: &[u8],
+    path: &str,
+) -> Result<ExtendedKeyPair<E>, errors::DeriveError> {
+    let seed = Seed::new(seed)?;
+    let path: DerivationPath = path.parse()?;
+    let master_key = ExtendedKeyPair::from(derive_master_key::<E>(&seed));
+    Ok(derive_child_key_pair_with_path(&master_key, &path))
+}
+
+/// Derives a master key from BIP39 mnemonic
+///
+/// Computes a 64 bytes seed from the mnemonic and the passphrase as defined in [BIP39][bip39-seed]
+/// (PBKDF2-HMAC-SHA512 with 2048 iterations), and derives a master key from it. Passphrase is
+/// expected to be in UTF-8 NFKD form, which is always the case for ASCII passphrases. Use an
+/// empty string if there's no passphrase.
+///
+/// ### Example
+/// ```rust
+/// use slip_10::{bip39::Mnemonic, supported_curves::Secp256k1};
+///
+/// let mnemonic: Mnemonic = "abandon abandon abandon abandon abandon abandon \
+///     abandon abandon abandon abandon abandon about".parse()?;
+/// let master_key = slip_10::derive_master_key_from_mnemonic::<Secp256k1>(&mnemonic, "TREZOR");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [bip39-seed]: https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki#from-mnemonic-to-seed
+#[cfg(feature = "bip39")]
+pub fn derive_master_key_from_mnemonic<E: Curve + SupportedCurve>(
+    mnemonic: &bip39::Mnemonic,
+    passphrase: &str,
+) -> ExtendedSecretKey<E> {
+    derive_master_key(Seed::from(mnemonic.to_seed_normalized(passphrase)))
+}
+
+/// Derives a master key from the seed and the curve tag as defined in SLIP10
+///
+/// It's preferred to use [derive_master_key] instead, as it automatically infers
+/// the curve tag for supported curves. The curve tag is not validated by the function,
+/// it's caller's responsibility to make sure that it complies with SLIP10.
+pub fn derive_master_key_with_curve_tag<E: Curve>(
+    curve_tag: &[u8],
+    seed: impl AsRef<Seed>,
+) -> ExtendedSecretKey<E> {
+    master_key_with_retries::<Slip10, _>(curve_tag, seed.as_ref(), None)
+        .expect("this never fails: number of retries is not limited")
+        .0
+}
+
+/// Derives a master key from the seed, giving up after `max_retries` retries
+///
+/// Same as [`derive_master_key`], but returns [`DeriveError`](errors::DeriveError)
+/// instead of retrying forever. See [`try_derive_child_key_pair`] for details.
+pub fn try_derive_master_key<E: Curve + SupportedCurve>(
+    seed: impl AsRef<Seed>,
+    max_retries: usize,
+) -> Result<ExtendedSecretKey<E>, errors::DeriveError> {
+    try_derive_master_key_with_curve_tag(E::CURVE_TYPE.curve_tag().as_bytes(), seed, max_retries)
+}
+
+/// Derives a master key from the seed and the curve tag, giving up after `max_retries` retries
+///
+/// Same as [`derive_master_key_with_curve_tag`], but returns [`DeriveError`](errors::DeriveError)
+/// instead of retrying forever.
+pub fn try_derive_master_key_with_curve_tag<E: Curve>(
+    curve_tag: &[u8],
+    seed: impl AsRef<Seed>,
+    max_retries: usize,
+) -> Result<ExtendedSecretKey<E>, errors::DeriveError> {
+    master_key_with_retries::<Slip10, _>(curve_tag, seed.as_ref(), Some(max_retries))
+        .map(|(key, _)| key)
+}
+
+/// Derives a master key from the seed, along with derivation stats
+///
+/// Same as [`derive_master_key`], but also returns how many retries occurred, see
+/// [`DerivationStats`].
+///
+/// ### Example
+/// ```rust
+/// use slip_10::supported_curves::Secp256k1;
+///
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// let (master_key, stats) = slip_10::derive_master_key_with_stats::<Secp256k1>(&seed);
+/// assert_eq!(stats.retries, 0);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn derive_master_key_with_stats<E: Curve + SupportedCurve>(
+    seed: impl AsRef<Seed>,
+) -> (ExtendedSecretKey<E>, DerivationStats) {
+    master_key_with_retries::<Slip10, _>(E::CURVE_TYPE.curve_tag().as_bytes(), seed.as_ref(), None)
+        .expect("this never fails: number of retries is not limited")
+}
+
+pub(crate) fn master_key_with_retries<S: DerivationScheme, E: Curve>(
+    curve_tag: &[u8],
+    seed: &Seed,
+    max_retries: Option<usize>,
+) -> Result<(ExtendedSecretKey<E>, DerivationStats), errors::DeriveError> {
+    let hmac = S::Mac::new_from_slice(curve_tag)
+        .expect("this never fails: hmac can handle keys of any size");
+    let mut i = hmac
+        .clone()
+        .chain_update(seed.as_bytes())
+        .finalize()
+        .into_bytes();
 
-    loop {
+    let mut retries = 0;
+    let result = loop {
         let (i_left, i_right) = split_into_two_halfes(&i);
 
         if let Ok(mut sk) = Scalar::<E>::from_be_bytes(i_left) {
             if !bool::from(subtle::ConstantTimeEq::ct_eq(&sk, &Scalar::zero())) {
-                return Ok(ExtendedSecretKey {
+                let key = ExtendedSecretKey {
                     secret_key: SecretScalar::new(&mut sk),
-                    chain_code: (*i_right).into(),
-                });
+                    chain_code: ChainCode::new((*i_right).into()),
+                };
+                break Ok((key, DerivationStats { retries }));
             }
         }
 
-        i = hmac.clone().chain_update(&i[..]).finalize().into_bytes()
-    }
+        if let Err(err) = check_retries(
+            &mut retries,
+            max_retries,
+            errors::DeriveError::TooManyRetriesForMasterKey,
+        ) {
+            break Err(err);
+        }
+        let next_i = hmac.clone().chain_update(&i[..]).finalize().into_bytes();
+        erase(&mut i);
+        i = next_i;
+    };
+    erase(&mut i);
+    result
 }
 
 /// Derives child key pair (extended secret key + public key) from parent key pair
@@ -408,8 +1609,8 @@ pub fn derive_master_key_with_curve_tag<E: Curve>(
 /// ```rust
 /// use slip_10::supported_curves::Secp256k1;
 ///
-/// # let seed = b"do not use this seed :)".as_slice();
-/// let master_key = slip_10::derive_master_key::<Secp256k1>(seed)?;
+/// # let seed = slip_10::Seed::new(b"do not use this seed :)")?;
+/// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
 /// let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
 ///
 /// let derived_key = slip_10::derive_child_key_pair(
@@ -427,6 +1628,14 @@ pub fn derive_child_key_pair<E: Curve>(
         ChildIndex::Hardened(i) => derive_hardened_shift(parent_key, i),
         ChildIndex::NonHardened(i) => derive_public_shift(&parent_key.public_key, i),
     };
+    apply_shift(parent_key, shift)
+}
+
+/// Applies the shift to the parent key pair, obtaining the child key pair
+pub(crate) fn apply_shift<E: Curve>(
+    parent_key: &ExtendedKeyPair<E>,
+    shift: DerivedShift<E>,
+) -> ExtendedKeyPair<E> {
     let mut child_sk = &parent_key.secret_key.secret_key + shift.shift;
     let child_sk = SecretScalar::new(&mut child_sk);
     ExtendedKeyPair {
@@ -448,8 +1657,8 @@ pub fn derive_child_key_pair<E: Curve>(
 /// Derive a child key with path m/1/10/1<sub>H</sub>
 /// ```rust
 /// use slip_10::supported_curves::Secp256k1;
-/// # let seed = b"16-64 bytes of high entropy".as_slice();
-/// let master_key = slip_10::derive_master_key::<Secp256k1>(seed)?;
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
 /// let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
 ///
 /// let child_key = slip_10::derive_child_key_pair_with_path(
@@ -481,8 +1690,8 @@ pub fn derive_child_key_pair_with_path<E: Curve>(
 /// Parse a path from the string and derive a child without extra allocations:
 /// ```rust
 /// use slip_10::supported_curves::Secp256k1;
-/// # let seed = b"16-64 bytes of high entropy".as_slice();
-/// let master_key = slip_10::derive_master_key::<Secp256k1>(seed)?;
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
 /// let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
 ///
 /// let path = "1/10/2";
@@ -504,6 +1713,225 @@ pub fn try_derive_child_key_pair_with_path<E: Curve, Err>(
     Ok(derived_key)
 }
 
+/// Derives a child key pair with specified derivation path, yielding key pairs at every level
+///
+/// Iterator yields a key pair at each level of the path, e.g. for path
+/// m/44<sub>H</sub>/0<sub>H</sub>/0 it yields keys at m/44<sub>H</sub>,
+/// m/44<sub>H</sub>/0<sub>H</sub>, and m/44<sub>H</sub>/0<sub>H</sub>/0. The last yielded key is the same as the one returned by
+/// [`derive_child_key_pair_with_path`]. Each level is derived only once.
+///
+/// If derivation path is empty, iterator yields nothing.
+///
+/// ### Example
+/// Export account-level extended public key while deriving the first address key
+/// ```rust
+/// use slip_10::{supported_curves::Secp256k1, H};
+///
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+/// let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+///
+/// let levels: Vec<_> =
+///     slip_10::derive_path_iter(&master_key_pair, [44 + H, H, H, 0, 0]).collect();
+/// let account_public_key = levels[2].public_key();
+/// let address_key = &levels[4];
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn derive_path_iter<E: Curve, P>(
+    parent_key: &ExtendedKeyPair<E>,
+    path: P,
+) -> PathIter<E, P::IntoIter>
+where
+    P: IntoIterator,
+    P::Item: Into<ChildIndex>,
+{
+    PathIter {
+        key_pair: parent_key.clone(),
+        path: path.into_iter(),
+    }
+}
+
+/// Iterator over key pairs at every level of the path, returned by [`derive_path_iter`]
+#[derive(Clone, Debug)]
+pub struct PathIter<E: Curve, I> {
+    key_pair: ExtendedKeyPair<E>,
+    path: I,
+}
+
+impl<E: Curve, I> Iterator for PathIter<E, I>
+where
+    I: Iterator,
+    I::Item: Into<ChildIndex>,
+{
+    type Item = ExtendedKeyPair<E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let child_index = self.path.next()?;
+        self.key_pair = derive_child_key_pair(&self.key_pair, child_index);
+        Some(self.key_pair.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.path.size_hint()
+    }
+}
+
+impl<E: Curve, I> ExactSizeIterator for PathIter<E, I>
+where
+    I: ExactSizeIterator,
+    I::Item: Into<ChildIndex>,
+{
+}
+
+impl<E: Curve, I> core::iter::FusedIterator for PathIter<E, I>
+where
+    I: core::iter::FusedIterator,
+    I::Item: Into<ChildIndex>,
+{
+}
+
+/// Derives hardened child extended secret key from parent extended secret key
+///
+/// Unlike [`derive_child_key_pair`], it never computes public keys, which makes it much faster,
+/// especially for long hardened-only paths. The derived key is the same as the one derived
+/// with [`derive_child_key_pair`].
+///
+/// ### Example
+/// Derive child key m/44<sub>H</sub>/0<sub>H</sub> from master key
+/// ```rust
+/// use slip_10::{supported_curves::Secp256k1, HardenedIndex};
+///
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+///
+/// let purpose_key = slip_10::derive_child_secret_key(&master_key, HardenedIndex::new(44));
+/// let coin_type_key = purpose_key.derive_child(HardenedIndex::new(0));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn derive_child_secret_key<E: Curve>(
+    parent_key: &ExtendedSecretKey<E>,
+    child_index: HardenedIndex,
+) -> ExtendedSecretKey<E> {
+    child_secret_key_with_retries::<Slip10, _>(parent_key, child_index, None)
+        .expect("this never fails: number of retries is not limited")
+}
+
+/// Derives hardened child extended secret key, giving up after `max_retries` retries
+///
+/// Same as [`derive_child_secret_key`], but returns an error instead of retrying forever. See
+/// [`try_derive_child_key_pair`] for details.
+pub fn try_derive_child_secret_key<E: Curve>(
+    parent_key: &ExtendedSecretKey<E>,
+    child_index: HardenedIndex,
+    max_retries: usize,
+) -> Result<ExtendedSecretKey<E>, errors::DeriveError> {
+    child_secret_key_with_retries::<Slip10, _>(parent_key, child_index, Some(max_retries))
+}
+
+pub(crate) fn child_secret_key_with_retries<S: DerivationScheme, E: Curve>(
+    parent_key: &ExtendedSecretKey<E>,
+    child_index: HardenedIndex,
+    max_retries: Option<usize>,
+) -> Result<ExtendedSecretKey<E>, errors::DeriveError> {
+    let hmac = S::Mac::new_from_slice(parent_key.chain_code.as_slice())
+        .expect("this never fails: hmac can handle keys of any size");
+    let mut parent_sk = parent_key.secret_key.as_ref().to_be_bytes();
+    let mut i = hmac
+        .clone()
+        .chain_update([0x00])
+        .chain_update(&parent_sk)
+        .chain_update(child_index.to_be_bytes())
+        .finalize()
+        .into_bytes();
+    erase(parent_sk.as_mut());
+    let mut retries = 0;
+    let result = loop {
+        let (i_left, i_right) = split_into_two_halfes(&i);
+
+        if let Ok(mut shift) = Scalar::<E>::from_be_bytes(i_left) {
+            let shift = SecretScalar::new(&mut shift);
+            let mut child_sk = parent_key.secret_key.as_ref() + shift.as_ref();
+            if !bool::from(child_sk.ct_eq(&Scalar::zero())) {
+                break Ok(ExtendedSecretKey {
+                    secret_key: SecretScalar::new(&mut child_sk),
+                    chain_code: ChainCode::new((*i_right).into()),
+                });
+            }
+        }
+
+        if let Err(err) = check_retries(
+            &mut retries,
+            max_retries,
+            errors::DeriveError::TooManyRetries {
+                level: 0,
+                child_index: child_index.into(),
+            },
+        ) {
+            break Err(err);
+        }
+        let next_i = hmac
+            .clone()
+            .chain_update([0x01])
+            .chain_update(i_right)
+            .chain_update(child_index.to_be_bytes())
+            .finalize()
+            .into_bytes();
+        erase(&mut i);
+        i = next_i;
+    };
+    erase(&mut i);
+    result
+}
+
+/// Derives a child extended secret key with specified hardened derivation path
+///
+/// Same as [`derive_child_secret_key`] applied to each index of the path. If derivation path is
+/// empty, a copy of `parent_key` is returned.
+pub fn derive_child_secret_key_with_path<E: Curve>(
+    parent_key: &ExtendedSecretKey<E>,
+    path: impl IntoIterator<Item = HardenedIndex>,
+) -> ExtendedSecretKey<E> {
+    let mut derived_key = parent_key.clone();
+    for child_index in path {
+        derived_key = derive_child_secret_key(&derived_key, child_index);
+    }
+    derived_key
+}
+
+/// Derives child key pair, giving up after `max_retries` retries
+///
+/// SLIP10 derivation retries with a different HMAC input if the derived key is invalid. It's
+/// astronomically unlikely to happen even once for supported curves, but the number of retries
+/// is unbounded in [`derive_child_key_pair`]. This function returns an error if a valid key is
+/// not found within `max_retries` retries, so derivation is guaranteed to terminate. Otherwise,
+/// the output is the same as [`derive_child_key_pair`] would return.
+///
+/// ### Example
+/// ```rust
+/// use slip_10::supported_curves::Secp256k1;
+///
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// let master_key = slip_10::try_derive_master_key::<Secp256k1>(&seed, 10)?;
+/// let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+///
+/// let child_key_pair = slip_10::try_derive_child_key_pair(&master_key_pair, 1 + slip_10::H, 10)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn try_derive_child_key_pair<E: Curve>(
+    parent_key: &ExtendedKeyPair<E>,
+    child_index: impl Into<ChildIndex>,
+    max_retries: usize,
+) -> Result<ExtendedKeyPair<E>, errors::DeriveError> {
+    let child_index = child_index.into();
+    let shift = match child_index {
+        ChildIndex::Hardened(i) => try_derive_hardened_shift(parent_key, i, max_retries)?,
+        ChildIndex::NonHardened(i) => {
+            try_derive_public_shift(&parent_key.public_key, i, max_retries)?
+        }
+    };
+    Ok(apply_shift(parent_key, shift))
+}
+
 /// Derives child extended public key from parent extended public key
 ///
 /// ### Example
@@ -511,8 +1939,8 @@ pub fn try_derive_child_key_pair_with_path<E: Curve, Err>(
 /// ```rust
 /// use slip_10::supported_curves::Secp256k1;
 ///
-/// # let seed = b"do not use this seed :)".as_slice();
-/// let master_key = slip_10::derive_master_key::<Secp256k1>(seed)?;
+/// # let seed = slip_10::Seed::new(b"do not use this seed :)")?;
+/// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
 /// let master_public_key = slip_10::ExtendedPublicKey::from(&master_key);
 ///
 /// let derived_key = slip_10::derive_child_public_key(
@@ -528,6 +1956,397 @@ pub fn derive_child_public_key<E: Curve>(
     derive_public_shift(parent_public_key, child_index).child_public_key
 }
 
+/// Checks that `claimed_child` is derived from `parent_public_key` at `child_index`
+///
+/// Recomputes non-hardened derivation and compares the result with `claimed_child` (both public
+/// key and chain code) in constant time. Can be used to validate derivations claimed by an
+/// untrusted party.
+///
+/// ### Example
+/// ```rust
+/// use slip_10::supported_curves::Secp256k1;
+///
+/// # let seed = slip_10::Seed::new(b"do not use this seed :)")?;
+/// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+/// let master_public_key = slip_10::ExtendedPublicKey::from(&master_key);
+///
+/// let child = slip_10::derive_child_public_key(&master_public_key, 1.try_into()?);
+/// assert!(slip_10::verify_child_public_key(&master_public_key, 1.try_into()?, &child));
+/// assert!(!slip_10::verify_child_public_key(&master_public_key, 2.try_into()?, &child));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn verify_child_public_key<E: Curve>(
+    parent_public_key: &ExtendedPublicKey<E>,
+    child_index: NonHardenedIndex,
+    claimed_child: &ExtendedPublicKey<E>,
+) -> bool {
+    derive_child_public_key(parent_public_key, child_index)
+        .ct_eq(claimed_child)
+        .into()
+}
+
+/// Checks many `(parent, child_index, claimed_child)` derivations at once
+///
+/// Returns positions of entries that failed verification, in ascending order. Empty list means
+/// that all derivations are valid. Each entry is checked as in [`verify_child_public_key`].
+///
+/// Consecutive entries that share the same parent reuse a [`ShiftDeriver`], so HMAC is keyed and
+/// parent public key is serialized only once per run of such entries. Group entries by parent to
+/// benefit from it.
+///
+/// ### Example
+/// ```rust
+/// use slip_10::{supported_curves::Secp256k1, NonHardenedIndex};
+///
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// # let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+/// # let account_public_key = slip_10::ExtendedPublicKey::from(&master_key);
+/// let addresses: Vec<_> = (0..20)
+///     .map(NonHardenedIndex::new)
+///     .map(|i| (i, slip_10::derive_child_public_key(&account_public_key, i)))
+///     .collect();
+///
+/// let failed = slip_10::verify_child_public_keys(
+///     addresses.iter().map(|(i, child)| (&account_public_key, *i, child)),
+/// );
+/// assert!(failed.is_empty());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "alloc")]
+pub fn verify_child_public_keys<'a, E: Curve>(
+    derivations: impl IntoIterator<
+        Item = (
+            &'a ExtendedPublicKey<E>,
+            NonHardenedIndex,
+            &'a ExtendedPublicKey<E>,
+        ),
+    >,
+) -> alloc::vec::Vec<usize> {
+    let mut deriver: Option<ShiftDeriver<E>> = None;
+    let mut failed = alloc::vec::Vec::new();
+    for (i, (parent_public_key, child_index, claimed_child)) in derivations.into_iter().enumerate()
+    {
+        let deriver = match &mut deriver {
+            Some(deriver) if deriver.parent_public_key() == parent_public_key => deriver,
+            deriver => deriver.insert(ShiftDeriver::new(parent_public_key)),
+        };
+        if !deriver.verify_child_public_key(child_index, claimed_child) {
+            failed.push(i)
+        }
+    }
+    failed
+}
+
+/// Derives child key pair, along with derivation stats
+///
+/// Same as [`derive_child_key_pair`], but also returns how many retries occurred, see
+/// [`DerivationStats`].
+pub fn derive_child_key_pair_with_stats<E: Curve>(
+    parent_key: &ExtendedKeyPair<E>,
+    child_index: impl Into<ChildIndex>,
+) -> (ExtendedKeyPair<E>, DerivationStats) {
+    let (shift, stats) = match child_index.into() {
+        ChildIndex::Hardened(i) => hardened_shift_with_retries::<Slip10, _>(parent_key, i, None),
+        ChildIndex::NonHardened(i) => {
+            ShiftDeriver::new(&parent_key.public_key).derive_with_retries(i, None)
+        }
+    }
+    .expect("this never fails: number of retries is not limited");
+    (apply_shift(parent_key, shift), stats)
+}
+
+/// Derives a child key pair with specified derivation path, along with stats of each level
+///
+/// Same as [`derive_child_key_pair_with_path`], but also returns how many retries occurred at
+/// each level of the path, see [`DerivationStats`].
+///
+/// ### Example
+/// ```rust
+/// use slip_10::{supported_curves::Secp256k1, DerivationPath};
+///
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+/// let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+///
+/// let path: DerivationPath = "m/44'/0'/0'/0/1".parse()?;
+/// let (child_key_pair, stats) =
+///     slip_10::derive_child_key_pair_with_path_and_stats(&master_key_pair, &path);
+/// assert_eq!(stats.len(), 5);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "alloc")]
+pub fn derive_child_key_pair_with_path_and_stats<E: Curve>(
+    parent_key: &ExtendedKeyPair<E>,
+    path: impl IntoIterator<Item = impl Into<ChildIndex>>,
+) -> (ExtendedKeyPair<E>, alloc::vec::Vec<DerivationStats>) {
+    let mut derived_key = parent_key.clone();
+    let mut stats = alloc::vec::Vec::new();
+    for child_index in path {
+        let (child_key, child_stats) = derive_child_key_pair_with_stats(&derived_key, child_index);
+        derived_key = child_key;
+        stats.push(child_stats);
+    }
+    (derived_key, stats)
+}
+
+/// Derives child extended public key, along with derivation stats
+///
+/// Same as [`derive_child_public_key`], but also returns how many retries occurred, see
+/// [`DerivationStats`].
+pub fn derive_child_public_key_with_stats<E: Curve>(
+    parent_public_key: &ExtendedPublicKey<E>,
+    child_index: NonHardenedIndex,
+) -> (ExtendedPublicKey<E>, DerivationStats) {
+    let (shift, stats) = ShiftDeriver::new(parent_public_key)
+        .derive_with_retries(child_index, None)
+        .expect("this never fails: number of retries is not limited");
+    (shift.child_public_key, stats)
+}
+
+/// Derives child extended public key, giving up after `max_retries` retries
+///
+/// Same as [`derive_child_public_key`], but returns an error instead of retrying forever. See
+/// [`try_derive_child_key_pair`] for details.
+pub fn try_derive_child_public_key<E: Curve>(
+    parent_public_key: &ExtendedPublicKey<E>,
+    child_index: NonHardenedIndex,
+    max_retries: usize,
+) -> Result<ExtendedPublicKey<E>, errors::DeriveError> {
+    try_derive_public_shift(parent_public_key, child_index, max_retries)
+        .map(|shift| shift.child_public_key)
+}
+
+/// Derives many child extended public keys of the same parent
+///
+/// Returns an iterator that yields child public keys in the same order as `child_indexes`. It
+/// uses [`ShiftDeriver`] under the hood, so HMAC is keyed and parent public key is serialized only
+/// once, which makes it faster than calling [`derive_child_public_key`] for each child index.
+///
+/// ### Example
+/// Generate first 20 receiving addresses of a watch-only account:
+/// ```rust
+/// use slip_10::{supported_curves::Secp256k1, NonHardenedIndex};
+///
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// # let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+/// # let account_public_key = slip_10::ExtendedPublicKey::from(&master_key);
+/// let child_public_keys = slip_10::derive_child_public_keys(
+///     &account_public_key,
+///     (0..20).map(NonHardenedIndex::new),
+/// );
+/// for child_public_key in child_public_keys {
+///     // compute an address from child public key
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn derive_child_public_keys<'a, E: Curve>(
+    parent_public_key: &ExtendedPublicKey<E>,
+    child_indexes: impl IntoIterator<Item = NonHardenedIndex> + 'a,
+) -> impl Iterator<Item = ExtendedPublicKey<E>> + 'a {
+    let deriver = ShiftDeriver::new(parent_public_key);
+    child_indexes
+        .into_iter()
+        .map(move |child_index| deriver.derive_child_public_key(child_index))
+}
+
+/// Derives child extended public keys for a slice of child indexes
+///
+/// Same as [`derive_child_public_keys`], but derives all children up front and returns them in
+/// a vector, i.e. `result[i]` is a child with index `child_indexes[i]`.
+///
+/// ### Example
+/// ```rust
+/// use slip_10::{supported_curves::Secp256k1, NonHardenedIndex};
+///
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// # let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+/// # let account_public_key = slip_10::ExtendedPublicKey::from(&master_key);
+/// let child_indexes = [0, 1, 5].map(NonHardenedIndex::new);
+/// let child_public_keys =
+///     slip_10::batch_derive_child_public_keys(&account_public_key, &child_indexes);
+/// assert_eq!(child_public_keys.len(), 3);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "alloc")]
+pub fn batch_derive_child_public_keys<E: Curve>(
+    parent_public_key: &ExtendedPublicKey<E>,
+    child_indexes: &[NonHardenedIndex],
+) -> alloc::vec::Vec<ExtendedPublicKey<E>> {
+    let deriver = ShiftDeriver::new(parent_public_key);
+    child_indexes
+        .iter()
+        .map(|child_index| deriver.derive_child_public_key(*child_index))
+        .collect()
+}
+
+/// Iterates over consecutive non-hardened children of the parent public key
+///
+/// Yields `(child_index, child_public_key)` pairs starting from `start_index`. Iterator ends after
+/// the last non-hardened index $2^{31} - 1$, so it's practically infinite: use [`Iterator::take`]
+/// or similar adapters to bound it.
+///
+/// ### Example
+/// Pre-generate 20 addresses starting from index 5:
+/// ```rust
+/// use slip_10::{supported_curves::Secp256k1, NonHardenedIndex};
+///
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// # let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+/// # let account_public_key = slip_10::ExtendedPublicKey::from(&master_key);
+/// let children = slip_10::derive_children_iter(&account_public_key, NonHardenedIndex::new(5));
+/// for (child_index, child_public_key) in children.take(20) {
+///     // compute an address from child public key
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn derive_children_iter<E: Curve>(
+    parent_public_key: &ExtendedPublicKey<E>,
+    start_index: NonHardenedIndex,
+) -> ChildrenIter<E> {
+    ChildrenIter {
+        deriver: ShiftDeriver::new(parent_public_key),
+        next_index: Some(start_index),
+    }
+}
+
+/// Iterator over consecutive non-hardened children, returned by [`derive_children_iter`]
+#[derive(Clone, Debug)]
+pub struct ChildrenIter<E: Curve> {
+    deriver: ShiftDeriver<E>,
+    next_index: Option<NonHardenedIndex>,
+}
+
+impl<E: Curve> Iterator for ChildrenIter<E> {
+    type Item = (NonHardenedIndex, ExtendedPublicKey<E>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let child_index = self.next_index?;
+        self.next_index = NonHardenedIndex::checked_new(*child_index + 1);
+        Some((
+            child_index,
+            self.deriver.derive_child_public_key(child_index),
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.next_index.map(|i| H - *i).unwrap_or(0);
+        match usize::try_from(remaining) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+impl<E: Curve> core::iter::FusedIterator for ChildrenIter<E> {}
+
+/// Scans non-hardened children of the parent public key until the gap limit is reached
+///
+/// Walks children starting from index 0 and calls `is_used` for each child public key (e.g. to
+/// check whether corresponding address has any transactions). Scanning stops once `gap_limit`
+/// consecutive children are unused.
+///
+/// Returns indexes of all used children and the next fresh index, which is the index right after
+/// the last used child (or 0 if no child is used).
+///
+/// ### Example
+/// ```rust
+/// use slip_10::supported_curves::Secp256k1;
+///
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// # let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+/// # let account_public_key = slip_10::ExtendedPublicKey::from(&master_key);
+/// # let used_keys = [
+/// #     slip_10::derive_child_public_key(&account_public_key, slip_10::NonHardenedIndex::new(0)),
+/// #     slip_10::derive_child_public_key(&account_public_key, slip_10::NonHardenedIndex::new(3)),
+/// # ];
+/// # let has_transactions = |key: &slip_10::ExtendedPublicKey<Secp256k1>| {
+/// #     used_keys.iter().any(|used| used.public_key == key.public_key)
+/// # };
+/// let result = slip_10::scan(&account_public_key, 20, |child_public_key| {
+///     has_transactions(child_public_key)
+/// });
+/// assert_eq!(result.used.iter().map(|i| **i).collect::<Vec<_>>(), [0, 3]);
+/// assert_eq!(result.next_fresh.map(|i| *i), Some(4));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "alloc")]
+pub fn scan<E: Curve>(
+    parent_public_key: &ExtendedPublicKey<E>,
+    gap_limit: u32,
+    mut is_used: impl FnMut(&ExtendedPublicKey<E>) -> bool,
+) -> ScanResult {
+    let mut used = alloc::vec::Vec::new();
+    let mut gap = 0;
+    for (child_index, child_public_key) in
+        derive_children_iter(parent_public_key, NonHardenedIndex::MIN)
+    {
+        if gap >= gap_limit {
+            break;
+        }
+        if is_used(&child_public_key) {
+            used.push(child_index);
+            gap = 0;
+        } else {
+            gap += 1;
+        }
+    }
+
+    let next_fresh = match used.last() {
+        Some(last_used) => NonHardenedIndex::checked_new(**last_used + 1),
+        None => Some(NonHardenedIndex::MIN),
+    };
+    ScanResult { used, next_fresh }
+}
+
+/// Result of [`scan`]
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct ScanResult {
+    /// Indexes of used children in ascending order
+    pub used: alloc::vec::Vec<NonHardenedIndex>,
+    /// Index right after the last used child, or 0 if no child is used
+    ///
+    /// `None` if the last used child has the largest non-hardened index
+    pub next_fresh: Option<NonHardenedIndex>,
+}
+
+/// Derives child extended public keys for a range of child indexes in parallel
+///
+/// Returns child public keys in the same order as `child_indexes`, i.e. `result[i]` is a child
+/// with index `child_indexes.start + i`. Returns an error if range contains a hardened index
+/// (i.e. `child_indexes.end` is greater than $2^{31}$).
+///
+/// Derivation is distributed across [`rayon`] global thread pool. Similarly to
+/// [`derive_child_public_keys`], HMAC is keyed and parent public key is serialized only once.
+///
+/// ### Example
+/// ```rust
+/// use slip_10::supported_curves::Secp256k1;
+///
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// # let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+/// # let account_public_key = slip_10::ExtendedPublicKey::from(&master_key);
+/// let child_public_keys = slip_10::par_derive_children(&account_public_key, 0..1000)?;
+/// assert_eq!(child_public_keys.len(), 1000);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "rayon")]
+pub fn par_derive_children<E: Curve>(
+    parent_public_key: &ExtendedPublicKey<E>,
+    child_indexes: ops::Range<u32>,
+) -> Result<std::vec::Vec<ExtendedPublicKey<E>>, errors::OutOfRange> {
+    use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+    if child_indexes.end > H {
+        return Err(errors::OutOfRange);
+    }
+    let deriver = ShiftDeriver::new(parent_public_key);
+    Ok(child_indexes
+        .into_par_iter()
+        .map(|child_index| deriver.derive_child_public_key(NonHardenedIndex(child_index)))
+        .collect())
+}
+
 /// Derives a child public key with specified derivation path
 ///
 /// Derivation path is an iterator that yields child indexes.
@@ -538,8 +2357,8 @@ pub fn derive_child_public_key<E: Curve>(
 /// Derive a child key with path m/1/10
 /// ```rust
 /// use slip_10::supported_curves::Secp256k1;
-/// # let seed = b"16-64 bytes of high entropy".as_slice();
-/// let master_key = slip_10::derive_master_key::<Secp256k1>(seed)?;
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
 /// let master_public_key = slip_10::ExtendedPublicKey::from(&master_key);
 ///
 /// let child_key = slip_10::derive_child_public_key_with_path(
@@ -571,8 +2390,8 @@ pub fn derive_child_public_key_with_path<E: Curve>(
 /// Parse a path from the string and derive a child without extra allocations:
 /// ```rust
 /// use slip_10::supported_curves::Secp256k1;
-/// # let seed = b"16-64 bytes of high entropy".as_slice();
-/// let master_key = slip_10::derive_master_key::<Secp256k1>(seed)?;
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
 /// let master_public_key = slip_10::ExtendedPublicKey::from(&master_key);
 ///
 /// let path = "1/10/2";
@@ -583,6 +2402,29 @@ pub fn derive_child_public_key_with_path<E: Curve>(
 /// )?;
 /// # Ok::<_, Box<dyn std::error::Error>>(())
 /// ```
+///
+/// Derive a child with [`DerivationPath`] that may contain hardened indexes. An error is
+/// returned if a hardened index is encountered, as it cannot be derived from public key:
+/// ```rust
+/// use slip_10::{supported_curves::Secp256k1, DerivationPath, NonHardenedIndex};
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// # let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+/// # let master_public_key = slip_10::ExtendedPublicKey::from(&master_key);
+///
+/// let path: DerivationPath = "m/1/10/2".parse()?;
+/// let child_key = slip_10::try_derive_child_public_key_with_path(
+///     &master_public_key,
+///     path.iter().map(NonHardenedIndex::try_from),
+/// )?;
+///
+/// let hardened_path: DerivationPath = "m/1/10'/2".parse()?;
+/// let result = slip_10::try_derive_child_public_key_with_path(
+///     &master_public_key,
+///     hardened_path.iter().map(NonHardenedIndex::try_from),
+/// );
+/// assert!(result.is_err());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
 pub fn try_derive_child_public_key_with_path<E: Curve, Err>(
     parent_public_key: &ExtendedPublicKey<E>,
     path: impl IntoIterator<Item = Result<NonHardenedIndex, Err>>,
@@ -599,16 +2441,53 @@ pub fn derive_hardened_shift<E: Curve>(
     parent_key: &ExtendedKeyPair<E>,
     child_index: HardenedIndex,
 ) -> DerivedShift<E> {
-    let hmac = HmacSha512::new_from_slice(parent_key.chain_code())
+    hardened_shift_with_retries::<Slip10, _>(parent_key, child_index, None)
+        .expect("this never fails: number of retries is not limited")
+        .0
+}
+
+/// Derives a shift for hardened child, giving up after `max_retries` retries
+///
+/// Same as [`derive_hardened_shift`], but returns an error instead of retrying forever. See
+/// [`try_derive_child_key_pair`] for details.
+pub fn try_derive_hardened_shift<E: Curve>(
+    parent_key: &ExtendedKeyPair<E>,
+    child_index: HardenedIndex,
+    max_retries: usize,
+) -> Result<DerivedShift<E>, errors::DeriveError> {
+    hardened_shift_with_retries::<Slip10, _>(parent_key, child_index, Some(max_retries))
+        .map(|(shift, _)| shift)
+}
+
+/// Derives a shift for non-hardened child, giving up after `max_retries` retries
+///
+/// Same as [`derive_public_shift`], but returns an error instead of retrying forever. See
+/// [`try_derive_child_key_pair`] for details.
+pub fn try_derive_public_shift<E: Curve>(
+    parent_public_key: &ExtendedPublicKey<E>,
+    child_index: NonHardenedIndex,
+    max_retries: usize,
+) -> Result<DerivedShift<E>, errors::DeriveError> {
+    ShiftDeriver::new(parent_public_key).try_derive(child_index, max_retries)
+}
+
+pub(crate) fn hardened_shift_with_retries<S: DerivationScheme, E: Curve>(
+    parent_key: &ExtendedKeyPair<E>,
+    child_index: HardenedIndex,
+    max_retries: Option<usize>,
+) -> Result<(DerivedShift<E>, DerivationStats), errors::DeriveError> {
+    let hmac = S::Mac::new_from_slice(parent_key.chain_code().as_slice())
         .expect("this never fails: hmac can handle keys of any size");
+    let mut parent_sk = parent_key.secret_key.secret_key.as_ref().to_be_bytes();
     let i = hmac
         .clone()
         .chain_update([0x00])
-        .chain_update(parent_key.secret_key.secret_key.as_ref().to_be_bytes())
+        .chain_update(&parent_sk)
         .chain_update(child_index.to_be_bytes())
         .finalize()
         .into_bytes();
-    calculate_shift(&hmac, &parent_key.public_key, *child_index, i)
+    erase(parent_sk.as_mut());
+    calculate_shift::<S, _>(&hmac, &parent_key.public_key, *child_index, i, max_retries)
 }
 
 /// Derives a shift for non-hardened child
@@ -616,47 +2495,285 @@ pub fn derive_public_shift<E: Curve>(
     parent_public_key: &ExtendedPublicKey<E>,
     child_index: NonHardenedIndex,
 ) -> DerivedShift<E> {
-    let hmac = HmacSha512::new_from_slice(&parent_public_key.chain_code)
-        .expect("this never fails: hmac can handle keys of any size");
-    let i = hmac
-        .clone()
-        .chain_update(&parent_public_key.public_key.to_bytes(true))
-        .chain_update(child_index.to_be_bytes())
-        .finalize()
-        .into_bytes();
-    calculate_shift(&hmac, parent_public_key, *child_index, i)
+    ShiftDeriver::new(parent_public_key).derive(child_index)
 }
 
-fn calculate_shift<E: Curve>(
-    hmac: &HmacSha512,
+/// Derives an aggregated shift for non-hardened derivation path
+///
+/// Child secret key at the end of the path equals to `parent_secret_key + shift`, where `shift`
+/// is a sum of shifts of each level of the path. It's useful in threshold and MPC signing, where
+/// parties hold additive shares of the parent secret key: they only need to apply the total
+/// shift to their shares, and never derive intermediate keys.
+///
+/// Returned [`DerivedShift`] contains the total shift and the child public key at the end of
+/// the path. If derivation path is empty, shift is zero and child public key is
+/// `parent_public_key`.
+///
+/// ### Example
+/// ```rust
+/// use slip_10::supported_curves::Secp256k1;
+///
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+/// let master_public_key = slip_10::ExtendedPublicKey::from(&master_key);
+///
+/// let path = [1.try_into()?, 10.try_into()?];
+/// let shift = slip_10::derive_path_shift(&master_public_key, path);
+///
+/// let child_secret_key = master_key.secret_key.as_ref() + shift.shift;
+/// assert_eq!(
+///     generic_ec::Point::generator() * child_secret_key,
+///     shift.child_public_key.public_key,
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn derive_path_shift<E: Curve>(
     parent_public_key: &ExtendedPublicKey<E>,
-    child_index: u32,
-    mut i: hmac::digest::Output<HmacSha512>,
+    path: impl IntoIterator<Item = NonHardenedIndex>,
 ) -> DerivedShift<E> {
-    loop {
+    let mut total_shift = DerivedShift {
+        shift: Scalar::zero(),
+        child_public_key: *parent_public_key,
+    };
+    for child_index in path {
+        let shift = derive_public_shift(&total_shift.child_public_key, child_index);
+        total_shift.shift += shift.shift;
+        total_shift.child_public_key = shift.child_public_key;
+    }
+    total_shift
+}
+
+/// Derives shifts for many non-hardened children of the same parent
+///
+/// [`derive_public_shift`] keys HMAC with the parent chain code and serializes the parent public
+/// key on every call. `ShiftDeriver` does it once at construction, so deriving many siblings
+/// (e.g. addresses `0..10_000`) only costs hashing the child index and a point multiplication
+/// per child.
+///
+/// ### Example
+/// ```rust
+/// use slip_10::{supported_curves::Secp256k1, NonHardenedIndex};
+///
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+/// let master_public_key = slip_10::ExtendedPublicKey::from(&master_key);
+///
+/// let deriver = slip_10::ShiftDeriver::new(&master_public_key);
+/// let child_public_keys = (0..10)
+///     .map(|i| deriver.derive_child_public_key(NonHardenedIndex::new(i)))
+///     .collect::<Vec<_>>();
+/// # assert_eq!(
+/// #     child_public_keys[1].public_key,
+/// #     slip_10::derive_child_public_key(&master_public_key, NonHardenedIndex::new(1)).public_key,
+/// # );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// Deriver is parametrized by [`DerivationScheme`], which is SLIP10 by default. Use
+/// [`with_scheme`](Self::with_scheme) to construct a deriver for a different scheme.
+pub struct ShiftDeriver<E: Curve, S: DerivationScheme = Slip10> {
+    parent_public_key: ExtendedPublicKey<E>,
+    /// HMAC keyed with parent chain code
+    hmac: S::Mac,
+    /// `hmac` that has already absorbed serialized parent public key
+    hmac_with_public_key: S::Mac,
+}
+
+impl<E: Curve> ShiftDeriver<E> {
+    /// Prepares derivation of children of `parent_public_key`
+    pub fn new(parent_public_key: &ExtendedPublicKey<E>) -> Self {
+        Self::with_scheme(parent_public_key)
+    }
+
+    /// Derives a shift for non-hardened child
+    ///
+    /// Output is the same as [`derive_public_shift`] would return
+    pub fn derive(&self, child_index: NonHardenedIndex) -> DerivedShift<E> {
+        self.derive_with_retries(child_index, None)
+            .expect("this never fails: number of retries is not limited")
+            .0
+    }
+
+    /// Derives child extended public key
+    ///
+    /// Output is the same as [`derive_child_public_key`] would return
+    pub fn derive_child_public_key(&self, child_index: NonHardenedIndex) -> ExtendedPublicKey<E> {
+        self.derive(child_index).child_public_key
+    }
+
+    /// Checks that `claimed_child` is derived from parent public key at `child_index`
+    ///
+    /// Output is the same as [`verify_child_public_key`] would return
+    pub fn verify_child_public_key(
+        &self,
+        child_index: NonHardenedIndex,
+        claimed_child: &ExtendedPublicKey<E>,
+    ) -> bool {
+        self.derive_child_public_key(child_index)
+            .ct_eq(claimed_child)
+            .into()
+    }
+}
+
+impl<E: Curve, S: DerivationScheme> ShiftDeriver<E, S> {
+    /// Prepares derivation of children of `parent_public_key` using derivation scheme `S`
+    pub fn with_scheme(parent_public_key: &ExtendedPublicKey<E>) -> Self {
+        let hmac = S::Mac::new_from_slice(parent_public_key.chain_code.as_slice())
+            .expect("this never fails: hmac can handle keys of any size");
+        let hmac_with_public_key = hmac
+            .clone()
+            .chain_update(parent_public_key.public_key.to_bytes(true));
+        Self {
+            parent_public_key: *parent_public_key,
+            hmac,
+            hmac_with_public_key,
+        }
+    }
+
+    /// Returns parent public key which children are derived
+    pub fn parent_public_key(&self) -> &ExtendedPublicKey<E> {
+        &self.parent_public_key
+    }
+
+    /// Derives a shift for non-hardened child, giving up after `max_retries` retries
+    ///
+    /// Output is the same as [`try_derive_public_shift`] would return if scheme is [`Slip10`]
+    pub fn try_derive(
+        &self,
+        child_index: NonHardenedIndex,
+        max_retries: usize,
+    ) -> Result<DerivedShift<E>, errors::DeriveError> {
+        self.derive_with_retries(child_index, Some(max_retries))
+            .map(|(shift, _)| shift)
+    }
+
+    pub(crate) fn derive_with_retries(
+        &self,
+        child_index: NonHardenedIndex,
+        max_retries: Option<usize>,
+    ) -> Result<(DerivedShift<E>, DerivationStats), errors::DeriveError> {
+        let i = self
+            .hmac_with_public_key
+            .clone()
+            .chain_update(child_index.to_be_bytes())
+            .finalize()
+            .into_bytes();
+        calculate_shift::<S, _>(
+            &self.hmac,
+            &self.parent_public_key,
+            *child_index,
+            i,
+            max_retries,
+        )
+    }
+}
+
+impl<E: Curve, S: DerivationScheme> Clone for ShiftDeriver<E, S> {
+    fn clone(&self) -> Self {
+        Self {
+            parent_public_key: self.parent_public_key,
+            hmac: self.hmac.clone(),
+            hmac_with_public_key: self.hmac_with_public_key.clone(),
+        }
+    }
+}
+
+impl<E: Curve, S: DerivationScheme> core::fmt::Debug for ShiftDeriver<E, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ShiftDeriver")
+            .field("parent_public_key", &self.parent_public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Calculates the shift from `I`, retrying as defined in SLIP10 if `I` doesn't produce a valid key
+///
+/// Returns an error if the key is not found within `max_retries` retries. `None` means that
+/// number of retries is not limited.
+fn calculate_shift<S: DerivationScheme, E: Curve>(
+    hmac: &S::Mac,
+    parent_public_key: &ExtendedPublicKey<E>,
+    child_index: u32,
+    mut i: hmac::digest::Output<S::Mac>,
+    max_retries: Option<usize>,
+) -> Result<(DerivedShift<E>, DerivationStats), errors::DeriveError> {
+    let mut retries = 0;
+    let result = loop {
         let (i_left, i_right) = split_into_two_halfes(&i);
 
         if let Ok(shift) = Scalar::<E>::from_be_bytes(i_left) {
             let child_pk = parent_public_key.public_key + Point::generator() * shift;
             if !child_pk.is_zero() {
-                return DerivedShift {
+                let shift = DerivedShift {
                     shift,
                     child_public_key: ExtendedPublicKey {
                         public_key: child_pk,
-                        chain_code: (*i_right).into(),
+                        chain_code: ChainCode::new((*i_right).into()),
                     },
                 };
+                break Ok((shift, DerivationStats { retries }));
             }
         }
 
-        i = hmac
+        if let Err(err) = check_retries(
+            &mut retries,
+            max_retries,
+            errors::DeriveError::TooManyRetries {
+                level: 0,
+                child_index: child_index.into(),
+            },
+        ) {
+            break Err(err);
+        }
+        let next_i = hmac
             .clone()
             .chain_update([0x01])
             .chain_update(i_right)
             .chain_update(child_index.to_be_bytes())
             .finalize()
-            .into_bytes()
+            .into_bytes();
+        erase(&mut i);
+        i = next_i;
+    };
+    erase(&mut i);
+    result
+}
+
+/// Erases a secret temporary, e.g. HMAC output `I`, if `zeroize` feature is enabled
+#[cfg_attr(not(feature = "zeroize"), allow(unused_variables))]
+pub(crate) fn erase(secret: &mut [u8]) {
+    #[cfg(feature = "zeroize")]
+    secret.zeroize();
+}
+
+/// Erases a secret key that's no longer needed, e.g. a key at intermediate level of derivation
+/// path, if `zeroize` feature is enabled
+#[cfg(all(
+    feature = "zeroize",
+    any(feature = "curve-ed25519", feature = "curve-curve25519")
+))]
+pub(crate) fn erase_key(key: &mut impl Zeroize) {
+    key.zeroize();
+}
+
+/// Erases a secret key that's no longer needed, does nothing as `zeroize` feature is disabled
+#[cfg(all(
+    not(feature = "zeroize"),
+    any(feature = "curve-ed25519", feature = "curve-curve25519")
+))]
+pub(crate) fn erase_key<K>(_key: &mut K) {}
+
+/// Counts a retry, returns `err` if `max_retries` is exceeded
+fn check_retries(
+    retries: &mut usize,
+    max_retries: Option<usize>,
+    err: errors::DeriveError,
+) -> Result<(), errors::DeriveError> {
+    if matches!(max_retries, Some(max_retries) if *retries >= max_retries) {
+        return Err(err);
     }
+    *retries += 1;
+    Ok(())
 }
 
 /// Splits array `I` of 64 bytes into two arrays `I_L = I[..32]` and `I_R = I[32..]`
@@ -665,3 +2782,44 @@ fn split_into_two_halfes(
 ) -> (&GenericArray<u8, U32>, &GenericArray<u8, U32>) {
     generic_array::sequence::Split::split(i)
 }
+
+/// Formats a secret in `Debug` output of secret keys as `{name}(…)`
+///
+/// The secret itself is printed only if `insecure-debug` feature is enabled.
+struct DebugSecret<'a, T: ?Sized>(&'static str, &'a T);
+
+#[cfg(not(feature = "insecure-debug"))]
+impl<T: ?Sized> core::fmt::Debug for DebugSecret<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}(…)", self.0)
+    }
+}
+
+#[cfg(feature = "insecure-debug")]
+impl<T: ?Sized + InsecureDebug> core::fmt::Debug for DebugSecret<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}(", self.0)?;
+        self.1.fmt_hex(f)?;
+        f.write_str(")")
+    }
+}
+
+/// Secret that can be printed when `insecure-debug` feature is enabled
+#[cfg(feature = "insecure-debug")]
+trait InsecureDebug {
+    fn fmt_hex(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result;
+}
+
+#[cfg(feature = "insecure-debug")]
+impl<E: Curve> InsecureDebug for Scalar<E> {
+    fn fmt_hex(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.to_be_bytes().as_bytes().fmt_hex(f)
+    }
+}
+
+#[cfg(feature = "insecure-debug")]
+impl InsecureDebug for [u8] {
+    fn fmt_hex(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
@@ -0,0 +1,139 @@
+//! Non-hardened derivation from MuSig2 aggregate keys
+//!
+//! [BIP328][bip328] makes a [MuSig2][bip327] aggregate public key an extended public key: the
+//! aggregate key is paired with a fixed [`BIP328_CHAIN_CODE`], so child keys can be derived
+//! non-hardened way like from any other xpub. Nobody knows the secret key of the aggregate
+//! key, so instead of deriving child secret keys, signers sign for a child key by applying the
+//! derivation [shift](crate::DerivedShift) as a plain (non x-only) tweak of MuSig2 key
+//! aggregation context (`ApplyTweak(keygen_ctx, shift, false)` in BIP327 terms).
+//!
+//! Two roles are involved:
+//! * [`Coordinator`] knows the aggregate key, derives child keys (e.g. to generate addresses),
+//!   and asks signers to sign for a child key at given path
+//! * [`Participant`] is a MuSig2 signer. It recomputes the tweak from the path on its own,
+//!   checks that it matches the child key the coordinator asks to sign for, and applies it to its
+//!   signing session
+//!
+//! Secret keys of participants are not modified: the tweak is accounted for once during
+//! signature aggregation.
+//!
+//! ### Example
+//! ```rust
+//! use slip_10::{musig2, supported_curves::Secp256k1, NonHardenedIndex};
+//!
+//! # let aggregate_public_key = generic_ec::Point::<Secp256k1>::generator() * generic_ec::Scalar::from(42);
+//! // Aggregate key is computed by MuSig2 `KeyAgg` from public keys of the signers
+//! let aggregate_key = musig2::aggregate_extended_key(aggregate_public_key);
+//! let path = [NonHardenedIndex::new(0), NonHardenedIndex::new(7)];
+//!
+//! // Coordinator derives a child key
+//! let coordinator = musig2::Coordinator::new(aggregate_key);
+//! let child_public_key = coordinator.derive(path).child_public_key;
+//!
+//! // Each participant, when asked to sign for `child_public_key` at `path`, obtains the tweak
+//! let participant = musig2::Participant::new(aggregate_key);
+//! let tweak = participant.tweak(path, &child_public_key)?;
+//! # let _ = tweak;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [bip327]: https://github.com/bitcoin/bips/blob/master/bip-0327.mediawiki
+//! [bip328]: https://github.com/bitcoin/bips/blob/master/bip-0328.mediawiki
+
+use generic_ec::{Point, Scalar};
+
+use crate::{
+    errors, supported_curves::Secp256k1, ChainCode, DerivedShift, ExtendedPublicKey,
+    NonHardenedIndex,
+};
+
+/// Chain code of aggregate extended keys defined in BIP328
+///
+/// Equals to SHA256 of `MuSig2MuSig2MuSig2`
+pub const BIP328_CHAIN_CODE: ChainCode = ChainCode::new([
+    0x86, 0x80, 0x87, 0xca, 0x02, 0xa6, 0xf9, 0x74, 0xc4, 0x59, 0x89, 0x24, 0xc3, 0x6b, 0x57, 0x76,
+    0x2d, 0x32, 0xcb, 0x45, 0x71, 0x71, 0x67, 0xe3, 0x00, 0x62, 0x2c, 0x71, 0x67, 0xe3, 0x89, 0x65,
+]);
+
+/// Makes extended public key from the aggregate public key and [`BIP328_CHAIN_CODE`]
+///
+/// `aggregate_public_key` is the output of MuSig2 `KeyAgg` before any tweaks are applied.
+pub fn aggregate_extended_key(
+    aggregate_public_key: Point<Secp256k1>,
+) -> ExtendedPublicKey<Secp256k1> {
+    ExtendedPublicKey {
+        public_key: aggregate_public_key,
+        chain_code: BIP328_CHAIN_CODE,
+    }
+}
+
+/// Coordinator of MuSig2 signers
+///
+/// Knows the aggregate extended key, derives child keys from it, and requests signers to sign
+/// for them. Coordinator can be any party, including one of the signers: it doesn't need any
+/// secrets.
+#[derive(Clone, Copy, Debug)]
+pub struct Coordinator {
+    aggregate_key: ExtendedPublicKey<Secp256k1>,
+}
+
+impl Coordinator {
+    /// Constructs a coordinator of the aggregate extended key
+    pub fn new(aggregate_key: ExtendedPublicKey<Secp256k1>) -> Self {
+        Self { aggregate_key }
+    }
+
+    /// Returns the aggregate extended key
+    pub fn aggregate_key(&self) -> &ExtendedPublicKey<Secp256k1> {
+        &self.aggregate_key
+    }
+
+    /// Derives child key at the path
+    ///
+    /// Returns the child public key, and the tweak that signers need to apply to sign for it.
+    pub fn derive(
+        &self,
+        path: impl IntoIterator<Item = NonHardenedIndex>,
+    ) -> DerivedShift<Secp256k1> {
+        crate::derive_path_shift(&self.aggregate_key, path)
+    }
+}
+
+/// MuSig2 signer
+///
+/// Signs for child keys of the aggregate extended key. Participant never trusts the tweak
+/// provided by coordinator: it derives the tweak itself, and makes sure that it results into
+/// the child key it's asked to sign for.
+#[derive(Clone, Copy, Debug)]
+pub struct Participant {
+    aggregate_key: ExtendedPublicKey<Secp256k1>,
+}
+
+impl Participant {
+    /// Constructs a participant of the aggregate extended key
+    pub fn new(aggregate_key: ExtendedPublicKey<Secp256k1>) -> Self {
+        Self { aggregate_key }
+    }
+
+    /// Returns the aggregate extended key
+    pub fn aggregate_key(&self) -> &ExtendedPublicKey<Secp256k1> {
+        &self.aggregate_key
+    }
+
+    /// Returns the tweak to sign for the child key at the path
+    ///
+    /// Tweak must be applied as a plain tweak to the MuSig2 key aggregation context. Returns
+    /// error if child key at the path is not `child_public_key`.
+    pub fn tweak(
+        &self,
+        path: impl IntoIterator<Item = NonHardenedIndex>,
+        child_public_key: &ExtendedPublicKey<Secp256k1>,
+    ) -> Result<Scalar<Secp256k1>, errors::InvalidKey> {
+        let shift = crate::derive_path_shift(&self.aggregate_key, path);
+        if shift.child_public_key == *child_public_key {
+            Ok(shift.shift)
+        } else {
+            Err(errors::InvalidKey)
+        }
+    }
+}
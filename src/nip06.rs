@@ -0,0 +1,85 @@
+//! [NIP-06][nip06] Nostr key derivation
+//!
+//! Nostr identity key is a secp256k1 key derived at m/44<sub>H</sub>/1237<sub>H</sub>/account<sub>H</sub>/0/0.
+//! Nostr uses [BIP340][bip340] x-only public keys, i.e. only x coordinate of the public key point.
+//!
+//! ### Example
+//! ```rust
+//! use slip_10::{nip06, HardenedIndex};
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let keys = nip06::derive_keys(&seed, HardenedIndex::new(0));
+//! let npub_hex = hex::encode(keys.public_key);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [nip06]: https://github.com/nostr-protocol/nips/blob/master/06.md
+//! [bip340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+
+use generic_ec::SecretScalar;
+
+use crate::{supported_curves::Secp256k1, ChildIndex, HardenedIndex, NonHardenedIndex, Seed, H};
+
+/// Coin type of Nostr: 1237<sub>H</sub>
+pub const COIN_TYPE: HardenedIndex = HardenedIndex(H + 1237);
+
+/// Nostr identity keys
+#[derive(Clone)]
+pub struct Keys {
+    /// Secret key
+    pub secret_key: SecretScalar<Secp256k1>,
+    /// X-only public key
+    pub public_key: [u8; 32],
+}
+
+impl core::fmt::Debug for Keys {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Keys")
+            .field(
+                "secret_key",
+                &crate::DebugSecret("SecretScalar", self.secret_key.as_ref()),
+            )
+            .field("public_key", &self.public_key)
+            .finish()
+    }
+}
+
+/// Returns derivation path m/44<sub>H</sub>/1237<sub>H</sub>/account<sub>H</sub>/0/0
+pub fn path(account: HardenedIndex) -> [ChildIndex; 5] {
+    [
+        HardenedIndex(H + 44).into(),
+        COIN_TYPE.into(),
+        account.into(),
+        NonHardenedIndex(0).into(),
+        NonHardenedIndex(0).into(),
+    ]
+}
+
+/// Derives Nostr identity keys of the account from the seed
+pub fn derive_keys(seed: impl AsRef<Seed>, account: HardenedIndex) -> Keys {
+    let master_key = crate::derive_master_key::<Secp256k1>(seed);
+    let master_key = crate::ExtendedKeyPair::from(master_key);
+    let key = crate::derive_child_key_pair_with_path(&master_key, path(account));
+
+    let public_key = key.public_key().public_key.to_bytes(true);
+    Keys {
+        secret_key: key.secret_key().secret_key.clone(),
+        public_key: public_key[1..]
+            .try_into()
+            .expect("this never fails: compressed point is 33 bytes long"),
+    }
+}
+
+/// Derives Nostr identity keys of the account from BIP39 mnemonic
+///
+/// Seed is computed from the mnemonic and the passphrase as in
+/// [`derive_master_key_from_mnemonic`](crate::derive_master_key_from_mnemonic). Use an empty
+/// string if there's no passphrase.
+#[cfg(feature = "bip39")]
+pub fn derive_keys_from_mnemonic(
+    mnemonic: &bip39::Mnemonic,
+    passphrase: &str,
+    account: HardenedIndex,
+) -> Keys {
+    derive_keys(Seed::from(mnemonic.to_seed_normalized(passphrase)), account)
+}
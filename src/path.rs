@@ -0,0 +1,643 @@
+use crate::{errors, ChildIndex, HardenedIndex, NonHardenedIndex};
+
+/// Derivation path
+///
+/// A sequence of child indexes that can be fed directly into derivation functions like
+/// [`derive_child_key_pair_with_path`](crate::derive_child_key_pair_with_path).
+///
+/// With `alloc` feature enabled, path is stored in a `Vec` and can be of any length. Otherwise,
+/// path is stored inline (no allocations), and can contain at most [`DerivationPath::MAX_LEN`]
+/// indexes. Use [`StaticPath`] to pick a different capacity.
+///
+/// ## Parsing
+/// Path can be parsed from a string like `m/44'/0'/0'/0/1`. Hardened indexes are marked with
+/// `'`, `h` or `H` suffix, i.e. `44'` corresponds to child index $44 + 2^{31}$. `m/` prefix
+/// is optional, and `m` alone corresponds to an empty path.
+///
+/// Path is displayed in canonical form: with `m/` prefix and `'` suffix for hardened indexes.
+///
+/// ## Example
+/// ```rust
+/// use slip_10::{supported_curves::Secp256k1, DerivationPath};
+///
+/// # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+/// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+/// let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+///
+/// let path: DerivationPath = "m/44'/0'/0'/0/1".parse()?;
+/// let child_key_pair = slip_10::derive_child_key_pair_with_path(&master_key_pair, &path);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct DerivationPath {
+    #[cfg(feature = "alloc")]
+    indexes: alloc::vec::Vec<ChildIndex>,
+    #[cfg(not(feature = "alloc"))]
+    indexes: StaticPath<{ DerivationPath::MAX_LEN }>,
+}
+
+/// Derivation path with fixed capacity
+///
+/// Stores up to `N` child indexes inline, without any allocations, which makes it suitable for
+/// embedded targets. Apart from fixed capacity, it's the same as [`DerivationPath`]: it can be
+/// parsed from a string like `m/44'/0'/0'/0/1` and displayed in canonical form. Pushing an index
+/// or parsing a path that doesn't fit into `N` indexes results into an error.
+///
+/// ## Example
+/// ```rust
+/// use slip_10::StaticPath;
+///
+/// let path: StaticPath<5> = "m/44'/0'/0'/0/1".parse()?;
+/// assert_eq!(path.len(), 5);
+///
+/// assert!("m/44'/0'/0'/0/1/2".parse::<StaticPath<5>>().is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct StaticPath<const N: usize> {
+    indexes: [ChildIndex; N],
+    len: usize,
+}
+
+impl DerivationPath {
+    /// Maximum amount of child indexes the path can contain when `alloc` feature is disabled
+    pub const MAX_LEN: usize = 16;
+
+    /// Constructs an empty path
+    pub const fn new() -> Self {
+        Self {
+            #[cfg(feature = "alloc")]
+            indexes: alloc::vec::Vec::new(),
+            #[cfg(not(feature = "alloc"))]
+            indexes: StaticPath::new(),
+        }
+    }
+
+    /// Appends a child index to the end of the path
+    ///
+    /// Returns an error if `alloc` feature is disabled and path already contains
+    /// [`DerivationPath::MAX_LEN`] indexes
+    pub fn push(&mut self, child_index: impl Into<ChildIndex>) -> Result<(), errors::PathTooLong> {
+        #[cfg(feature = "alloc")]
+        {
+            self.indexes.push(child_index.into());
+            Ok(())
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            self.indexes.push(child_index)
+        }
+    }
+
+    /// Removes the last child index from the path and returns it, or `None` if path is empty
+    pub fn pop(&mut self) -> Option<ChildIndex> {
+        self.indexes.pop()
+    }
+
+    /// Appends child indexes to the end of the path
+    ///
+    /// Can be used to concatenate paths, e.g. `path.extend(&suffix)`. Returns an error if
+    /// `alloc` feature is disabled and the path doesn't fit into [`DerivationPath::MAX_LEN`]
+    /// indexes, in which case the path is left partially extended.
+    pub fn extend(
+        &mut self,
+        child_indexes: impl IntoIterator<Item = impl Into<ChildIndex>>,
+    ) -> Result<(), errors::PathTooLong> {
+        child_indexes
+            .into_iter()
+            .try_for_each(|child_index| self.push(child_index))
+    }
+
+    /// Returns amount of child indexes in the path
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Checks whether the path is empty
+    pub fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+
+    /// Returns child indexes of the path
+    pub fn as_slice(&self) -> &[ChildIndex] {
+        #[cfg(feature = "alloc")]
+        {
+            &self.indexes
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            self.indexes.as_slice()
+        }
+    }
+
+    /// Returns an iterator over child indexes of the path
+    pub fn iter(&self) -> impl Iterator<Item = ChildIndex> + '_ {
+        self.as_slice().iter().copied()
+    }
+}
+
+impl<const N: usize> StaticPath<N> {
+    /// Maximum amount of child indexes the path can contain
+    pub const CAPACITY: usize = N;
+
+    /// Constructs an empty path
+    pub const fn new() -> Self {
+        Self {
+            indexes: [ChildIndex::NonHardened(NonHardenedIndex::MIN); N],
+            len: 0,
+        }
+    }
+
+    /// Appends a child index to the end of the path
+    ///
+    /// Returns an error if path already contains `N` indexes
+    pub fn push(&mut self, child_index: impl Into<ChildIndex>) -> Result<(), errors::PathTooLong> {
+        let slot = self.indexes.get_mut(self.len).ok_or(errors::PathTooLong)?;
+        *slot = child_index.into();
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes the last child index from the path and returns it, or `None` if path is empty
+    pub fn pop(&mut self) -> Option<ChildIndex> {
+        self.len = self.len.checked_sub(1)?;
+        Some(self.indexes[self.len])
+    }
+
+    /// Appends child indexes to the end of the path
+    ///
+    /// Returns an error if the path doesn't fit into `N` indexes, in which case the path
+    /// is left partially extended.
+    pub fn extend(
+        &mut self,
+        child_indexes: impl IntoIterator<Item = impl Into<ChildIndex>>,
+    ) -> Result<(), errors::PathTooLong> {
+        child_indexes
+            .into_iter()
+            .try_for_each(|child_index| self.push(child_index))
+    }
+
+    /// Returns amount of child indexes in the path
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks whether the path is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns child indexes of the path
+    pub fn as_slice(&self) -> &[ChildIndex] {
+        &self.indexes[..self.len]
+    }
+
+    /// Returns an iterator over child indexes of the path
+    pub fn iter(&self) -> impl Iterator<Item = ChildIndex> + '_ {
+        self.as_slice().iter().copied()
+    }
+}
+
+impl Default for DerivationPath {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Default for StaticPath<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> IntoIterator for &'a DerivationPath {
+    type Item = ChildIndex;
+    type IntoIter = core::iter::Copied<core::slice::Iter<'a, ChildIndex>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter().copied()
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a StaticPath<N> {
+    type Item = ChildIndex;
+    type IntoIter = core::iter::Copied<core::slice::Iter<'a, ChildIndex>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter().copied()
+    }
+}
+
+impl PartialEq for DerivationPath {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl Eq for DerivationPath {}
+
+impl core::hash::Hash for DerivationPath {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl PartialOrd for DerivationPath {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders paths lexicographically by their child indexes
+///
+/// A path goes before all paths it's a prefix of, e.g. `m/44'` < `m/44'/0'` < `m/44'/1'`.
+impl Ord for DerivationPath {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<const N: usize> PartialEq for StaticPath<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<const N: usize> Eq for StaticPath<N> {}
+
+impl<const N: usize> core::hash::Hash for StaticPath<N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<const N: usize> PartialOrd for StaticPath<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders paths lexicographically by their child indexes, same as [`DerivationPath`]
+impl<const N: usize> Ord for StaticPath<N> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+/// Displays the path in canonical form like `m/44'/0'/0'/0/1`
+///
+/// Empty path is displayed as `m`.
+impl core::fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        display_path(f, self.as_slice())
+    }
+}
+
+/// Displays the path in canonical form like `m/44'/0'/0'/0/1`
+///
+/// Empty path is displayed as `m`.
+impl<const N: usize> core::fmt::Display for StaticPath<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        display_path(f, self.as_slice())
+    }
+}
+
+impl core::str::FromStr for DerivationPath {
+    type Err = errors::ParsePathError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut path = Self::new();
+        parse_path(s, |child_index| path.push(child_index))?;
+        Ok(path)
+    }
+}
+
+impl<const N: usize> core::str::FromStr for StaticPath<N> {
+    type Err = errors::ParsePathError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut path = Self::new();
+        parse_path(s, |child_index| path.push(child_index))?;
+        Ok(path)
+    }
+}
+
+/// Serializes the path as a string like `m/44'/0'/0'` in human-readable formats, and as
+/// a sequence of `u32` child indexes otherwise
+#[cfg(feature = "serde")]
+impl serde::Serialize for DerivationPath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serialize_path(serializer, self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DerivationPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize_path(deserializer, Self::new(), |path, child_index| {
+            path.push(child_index)
+        })
+    }
+}
+
+/// Serializes the path as a string like `m/44'/0'/0'` in human-readable formats, and as
+/// a sequence of `u32` child indexes otherwise
+#[cfg(feature = "serde")]
+impl<const N: usize> serde::Serialize for StaticPath<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serialize_path(serializer, self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const N: usize> serde::Deserialize<'de> for StaticPath<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize_path(deserializer, Self::new(), |path, child_index| {
+            path.push(child_index)
+        })
+    }
+}
+
+/// Encoded as a list of child indexes tagged with `slip10.DerivationPath`
+#[cfg(feature = "udigest")]
+impl udigest::Digestable for DerivationPath {
+    fn unambiguously_encode<B: udigest::Buffer>(&self, encoder: udigest::encoding::EncodeValue<B>) {
+        encode_path(encoder, self.as_slice())
+    }
+}
+
+/// Encoded exactly as [`DerivationPath`] with the same child indexes
+#[cfg(feature = "udigest")]
+impl<const N: usize> udigest::Digestable for StaticPath<N> {
+    fn unambiguously_encode<B: udigest::Buffer>(&self, encoder: udigest::encoding::EncodeValue<B>) {
+        encode_path(encoder, self.as_slice())
+    }
+}
+
+#[cfg(feature = "udigest")]
+fn encode_path<B: udigest::Buffer>(
+    encoder: udigest::encoding::EncodeValue<B>,
+    path: &[ChildIndex],
+) {
+    let mut list = encoder.encode_list().with_tag(b"slip10.DerivationPath");
+    for child_index in path {
+        udigest::Digestable::unambiguously_encode(child_index, list.add_item());
+    }
+    list.finish();
+}
+
+/// Generates a path of any length
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for DerivationPath {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            indexes: arbitrary::Arbitrary::arbitrary(u)?,
+        })
+    }
+
+    fn arbitrary_take_rest(u: arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            indexes: arbitrary::Arbitrary::arbitrary_take_rest(u)?,
+        })
+    }
+}
+
+/// Generates a path of any length up to `N`
+#[cfg(feature = "arbitrary")]
+impl<'a, const N: usize> arbitrary::Arbitrary<'a> for StaticPath<N> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let len = u.int_in_range(0..=N)?;
+        let mut path = Self::new();
+        for _ in 0..len {
+            path.push(u.arbitrary::<ChildIndex>()?)
+                .expect("this never fails: length is within the capacity");
+        }
+        Ok(path)
+    }
+}
+
+fn display_path(f: &mut core::fmt::Formatter<'_>, path: &[ChildIndex]) -> core::fmt::Result {
+    f.write_str("m")?;
+    path.iter()
+        .try_for_each(|child_index| write!(f, "/{child_index}"))
+}
+
+/// Parses a path like `m/44'/0'`, feeding child indexes into `push`
+fn parse_path(
+    s: &str,
+    mut push: impl FnMut(ChildIndex) -> Result<(), errors::PathTooLong>,
+) -> Result<(), errors::ParsePathError> {
+    let s = match s.strip_prefix('m') {
+        Some("") => return Ok(()),
+        Some(s) => s
+            .strip_prefix('/')
+            .ok_or(errors::ParsePathError::InvalidPrefix)?,
+        None => s,
+    };
+
+    for child_index in s.split('/') {
+        let child_index =
+            parse_child_index(child_index).map_err(errors::ParsePathError::ParseIndex)?;
+        push(child_index).map_err(errors::ParsePathError::TooLong)?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+fn serialize_path<S, P>(serializer: S, path: &P) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+    P: core::fmt::Display,
+    for<'a> &'a P: IntoIterator<Item = ChildIndex>,
+{
+    if serializer.is_human_readable() {
+        serializer.collect_str(path)
+    } else {
+        serializer.collect_seq(path.into_iter().map(u32::from))
+    }
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_path<'de, D, P>(
+    deserializer: D,
+    empty: P,
+    push: fn(&mut P, ChildIndex) -> Result<(), errors::PathTooLong>,
+) -> Result<P, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    P: core::str::FromStr<Err = errors::ParsePathError>,
+{
+    use serde::de;
+
+    struct PathVisitor<P> {
+        empty: P,
+        push: fn(&mut P, ChildIndex) -> Result<(), errors::PathTooLong>,
+    }
+    impl<'de, P> de::Visitor<'de> for PathVisitor<P>
+    where
+        P: core::str::FromStr<Err = errors::ParsePathError>,
+    {
+        type Value = P;
+        fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            f.write_str("derivation path")
+        }
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            v.parse().map_err(E::custom)
+        }
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::SeqAccess<'de>,
+        {
+            let mut path = self.empty;
+            while let Some(child_index) = seq.next_element::<u32>()? {
+                (self.push)(&mut path, child_index.into()).map_err(de::Error::custom)?;
+            }
+            Ok(path)
+        }
+    }
+
+    let visitor = PathVisitor { empty, push };
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(visitor)
+    } else {
+        deserializer.deserialize_seq(visitor)
+    }
+}
+
+/// Parses a single path segment like `44'` or `0`
+///
+/// Same syntax as accepted by [`path!`](crate::path) macro: index without suffix must be less
+/// than $2^{31}$.
+pub(crate) fn parse_child_index(s: &str) -> Result<ChildIndex, errors::ParseChildIndexError> {
+    let (s, hardened) = match s.strip_suffix(['\'', 'h', 'H']) {
+        Some(s) => (s, true),
+        None => (s, false),
+    };
+    let index = NonHardenedIndex::try_from(parse_u32(s)?)
+        .map_err(errors::ParseChildIndexError::IndexNotInRange)?;
+    if hardened {
+        Ok(ChildIndex::Hardened(HardenedIndex(crate::H + *index)))
+    } else {
+        Ok(ChildIndex::NonHardened(index))
+    }
+}
+
+/// Parses `u32` integer
+///
+/// Unlike `u32::from_str`, leading `+` is rejected, as it is by [`path!`](crate::path) macro.
+pub(crate) fn parse_u32(s: &str) -> Result<u32, errors::ParseChildIndexError> {
+    if s.starts_with('+') {
+        // `+` alone is not a valid integer, which gives us the appropriate error
+        return Err(errors::ParseChildIndexError::ParseInt(
+            "+".parse::<u32>().unwrap_err(),
+        ));
+    }
+    s.parse::<u32>()
+        .map_err(errors::ParseChildIndexError::ParseInt)
+}
+
+/// Counts child indexes in the path, used by [`path!`](crate::path) macro
+///
+/// Panics if path is malformed, which is a compile-time error in const context
+#[doc(hidden)]
+pub const fn const_path_len(path: &str) -> usize {
+    let (bytes, mut i) = const_path_start(path);
+    if i == bytes.len() {
+        return 0;
+    }
+    let mut len = 1;
+    while i < bytes.len() {
+        if bytes[i] == b'/' {
+            len += 1;
+        }
+        i += 1;
+    }
+    len
+}
+
+/// Parses a path at compile time, used by [`path!`](crate::path) macro
+///
+/// Panics if path is malformed, which is a compile-time error in const context
+#[doc(hidden)]
+pub const fn const_parse_path<const N: usize>(path: &str) -> [ChildIndex; N] {
+    let (bytes, mut i) = const_path_start(path);
+    let mut indexes = [ChildIndex::NonHardened(NonHardenedIndex::MIN); N];
+    let mut n = 0;
+    while n < N {
+        let mut index: u32 = 0;
+        let mut digits = 0;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            index = match index.checked_mul(10) {
+                Some(index) => match index.checked_add((bytes[i] - b'0') as u32) {
+                    Some(index) => index,
+                    None => panic!("child index is out of range"),
+                },
+                None => panic!("child index is out of range"),
+            };
+            digits += 1;
+            i += 1;
+        }
+        if digits == 0 {
+            panic!("path contains empty or invalid child index");
+        }
+        if index >= crate::H {
+            panic!("child index is out of range");
+        }
+
+        let hardened = i < bytes.len() && matches!(bytes[i], b'\'' | b'h' | b'H');
+        if hardened {
+            i += 1;
+        }
+        indexes[n] = if hardened {
+            ChildIndex::Hardened(HardenedIndex(crate::H + index))
+        } else {
+            ChildIndex::NonHardened(NonHardenedIndex(index))
+        };
+        n += 1;
+
+        if i < bytes.len() {
+            if bytes[i] != b'/' {
+                panic!("path contains invalid child index");
+            }
+            i += 1;
+        }
+    }
+    if i != bytes.len() {
+        panic!("path contains invalid child index");
+    }
+    indexes
+}
+
+/// Skips optional `m/` prefix, returns path bytes and position of the first child index
+const fn const_path_start(path: &str) -> (&[u8], usize) {
+    let bytes = path.as_bytes();
+    if bytes.is_empty() {
+        panic!("path is empty");
+    }
+    if bytes[0] != b'm' {
+        return (bytes, 0);
+    }
+    if bytes.len() == 1 {
+        return (bytes, 1);
+    }
+    if bytes[1] != b'/' {
+        panic!("path must start with `m/`");
+    }
+    if bytes.len() == 2 {
+        panic!("path contains empty child index");
+    }
+    (bytes, 2)
+}
@@ -0,0 +1,107 @@
+//! Conversions between BIP32 extended keys and [`bitcoin::bip32`] types
+//!
+//! Keys from `bitcoin` crate convert into [`Xpub`](bip32::Xpub) and [`Xprv`](bip32::Xprv)
+//! infallibly, network is dropped. Backwards conversion takes the network explicitly, as it's
+//! not tracked by this crate.
+
+use bitcoin::{bip32 as btc, secp256k1, NetworkKind};
+use generic_ec::{Point, Scalar, SecretScalar};
+
+use crate::{bip32, errors, supported_curves::Secp256k1, ChainCode, ChildIndex};
+
+impl From<btc::ChainCode> for ChainCode {
+    fn from(chain_code: btc::ChainCode) -> Self {
+        ChainCode::new(chain_code.to_bytes())
+    }
+}
+
+impl From<ChainCode> for btc::ChainCode {
+    fn from(chain_code: ChainCode) -> Self {
+        chain_code.to_bytes().into()
+    }
+}
+
+impl From<btc::ChildNumber> for ChildIndex {
+    fn from(child_number: btc::ChildNumber) -> Self {
+        u32::from(child_number).into()
+    }
+}
+
+impl From<ChildIndex> for btc::ChildNumber {
+    fn from(child_index: ChildIndex) -> Self {
+        u32::from(child_index).into()
+    }
+}
+
+impl From<btc::Xpub> for bip32::Xpub<Secp256k1> {
+    fn from(xpub: btc::Xpub) -> Self {
+        let public_key = Point::from_bytes(xpub.public_key.serialize())
+            .expect("this never fails: secp256k1 public key is a valid point");
+        Self {
+            metadata: bip32::Metadata {
+                depth: bip32::Depth::new(xpub.depth),
+                parent_fingerprint: xpub.parent_fingerprint.to_bytes(),
+                child_number: xpub.child_number.into(),
+            },
+            key: crate::ExtendedPublicKey {
+                public_key,
+                chain_code: xpub.chain_code.into(),
+            },
+        }
+    }
+}
+
+impl From<btc::Xpriv> for bip32::Xprv<Secp256k1> {
+    fn from(xpriv: btc::Xpriv) -> Self {
+        let mut secret_key = Scalar::from_be_bytes(xpriv.private_key.secret_bytes())
+            .expect("this never fails: secp256k1 secret key is a valid scalar");
+        Self {
+            metadata: bip32::Metadata {
+                depth: bip32::Depth::new(xpriv.depth),
+                parent_fingerprint: xpriv.parent_fingerprint.to_bytes(),
+                child_number: xpriv.child_number.into(),
+            },
+            key: crate::ExtendedSecretKey {
+                secret_key: SecretScalar::new(&mut secret_key),
+                chain_code: xpriv.chain_code.into(),
+            },
+        }
+    }
+}
+
+impl bip32::Xpub<Secp256k1> {
+    /// Converts the key into [`bitcoin::bip32::Xpub`] for given network
+    ///
+    /// Returns an error if the public key is the identity point.
+    pub fn to_bitcoin(&self, network: NetworkKind) -> Result<btc::Xpub, errors::InvalidKey> {
+        let public_key = secp256k1::PublicKey::from_slice(&self.key.public_key.to_bytes(true))
+            .map_err(|_| errors::InvalidKey)?;
+        Ok(btc::Xpub {
+            network,
+            depth: *self.metadata.depth,
+            parent_fingerprint: self.metadata.parent_fingerprint.into(),
+            child_number: self.metadata.child_number.into(),
+            public_key,
+            chain_code: self.key.chain_code.into(),
+        })
+    }
+}
+
+impl bip32::Xprv<Secp256k1> {
+    /// Converts the key into [`bitcoin::bip32::Xpriv`] for given network
+    ///
+    /// Returns an error if the secret key is zero.
+    pub fn to_bitcoin(&self, network: NetworkKind) -> Result<btc::Xpriv, errors::InvalidKey> {
+        let private_key =
+            secp256k1::SecretKey::from_slice(&self.key.secret_key.as_ref().to_be_bytes())
+                .map_err(|_| errors::InvalidKey)?;
+        Ok(btc::Xpriv {
+            network,
+            depth: *self.metadata.depth,
+            parent_fingerprint: self.metadata.parent_fingerprint.into(),
+            child_number: self.metadata.child_number.into(),
+            private_key,
+            chain_code: self.key.chain_code.into(),
+        })
+    }
+}
@@ -0,0 +1,233 @@
+//! Interop with [RustCrypto] elliptic curves
+//!
+//! Most signing stacks are built on RustCrypto crates. Curves implementing [`RustCryptoCurve`]
+//! have a RustCrypto counterpart, so derived keys can be converted into
+//! [`elliptic_curve::SecretKey`] and [`elliptic_curve::PublicKey`] generically, which makes the
+//! wider trait ecosystem (e.g. `ecdsa` and `ecdh` crates) reachable. For secp256k1 and secp256r1,
+//! there are also shortcuts returning `k256` and `p256` signing and verifying keys, like
+//! `ExtendedSecretKey::to_k256_signing_key`.
+//!
+//! Secret keys can sign message digests right away with
+//! [`sign_prehash`](ExtendedSecretKey::sign_prehash), so going from seed to signature doesn't
+//! require any other crate:
+//! ```rust
+//! use sha2::Digest;
+//! use slip_10::supported_curves::Secp256k1;
+//!
+//! # let seed = b"16-64 bytes of high entropy";
+//! let key = slip_10::derive_key_pair_from_seed::<Secp256k1>(seed, "m/44'/0'/0'/0/0")?;
+//! let signature = key.secret_key().sign_prehash(&sha2::Sha256::digest(b"message").into())?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Derived secp256k1 and secp256r1 keys implement ECDSA `signature::Signer` and
+//! `signature::Verifier` traits directly, so they can be passed to any library parameterized over
+//! these traits. Keys are converted on every call, convert them once into signing or verifying
+//! keys when signing many messages.
+//!
+//! ### Example
+//! ```rust
+//! use slip_10::{rustcrypto::elliptic_curve, supported_curves::Secp256k1};
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//! let secret_key: elliptic_curve::SecretKey<k256::Secp256k1> =
+//!     master_key.to_rustcrypto_secret_key()?;
+//! let public_key = slip_10::ExtendedPublicKey::from(&master_key).to_rustcrypto_public_key()?;
+//! assert_eq!(secret_key.public_key(), public_key);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [RustCrypto]: https://github.com/RustCrypto/elliptic-curves
+
+pub use elliptic_curve;
+
+use elliptic_curve::{PublicKey, SecretKey};
+use generic_ec::{Curve, Point, Scalar};
+
+use crate::{errors, ExtendedKeyPair, ExtendedPublicKey, ExtendedSecretKey};
+
+/// Curve that has both generic-ec and RustCrypto implementations
+pub trait RustCryptoCurve: Curve {
+    /// RustCrypto implementation of the curve
+    type Curve: elliptic_curve::CurveArithmetic;
+
+    /// Converts a scalar into RustCrypto secret key
+    ///
+    /// Returns an error if the scalar is zero.
+    fn to_secret_key(scalar: &Scalar<Self>) -> Result<SecretKey<Self::Curve>, errors::InvalidKey>;
+
+    /// Converts a point into RustCrypto public key
+    ///
+    /// Returns an error if the point is the identity.
+    fn to_public_key(point: &Point<Self>) -> Result<PublicKey<Self::Curve>, errors::InvalidKey>;
+}
+
+impl<E: RustCryptoCurve> ExtendedSecretKey<E> {
+    /// Converts the secret key into RustCrypto [`SecretKey`]
+    ///
+    /// Chain code is dropped. Returns an error if the secret key is zero.
+    pub fn to_rustcrypto_secret_key(&self) -> Result<SecretKey<E::Curve>, errors::InvalidKey> {
+        E::to_secret_key(self.secret_key.as_ref())
+    }
+}
+
+impl<E: RustCryptoCurve> ExtendedPublicKey<E> {
+    /// Converts the public key into RustCrypto [`PublicKey`]
+    ///
+    /// Chain code is dropped. Returns an error if the public key is the identity point.
+    pub fn to_rustcrypto_public_key(&self) -> Result<PublicKey<E::Curve>, errors::InvalidKey> {
+        E::to_public_key(&self.public_key)
+    }
+}
+
+macro_rules! impl_conversions {
+    (
+        curve: $curve:ty,
+        crate: $krate:ident,
+        rustcrypto_curve: $rustcrypto_curve:ty,
+        to_secret_key: $to_secret_key:ident,
+        to_signing_key: $to_signing_key:ident,
+        to_public_key: $to_public_key:ident,
+        to_verifying_key: $to_verifying_key:ident $(,)?
+    ) => {
+        impl RustCryptoCurve for $curve {
+            type Curve = $rustcrypto_curve;
+
+            fn to_secret_key(
+                scalar: &Scalar<Self>,
+            ) -> Result<SecretKey<Self::Curve>, errors::InvalidKey> {
+                SecretKey::from_slice(&scalar.to_be_bytes()).map_err(|_| errors::InvalidKey)
+            }
+
+            fn to_public_key(
+                point: &Point<Self>,
+            ) -> Result<PublicKey<Self::Curve>, errors::InvalidKey> {
+                PublicKey::from_sec1_bytes(&point.to_bytes(true)).map_err(|_| errors::InvalidKey)
+            }
+        }
+
+        impl ExtendedSecretKey<$curve> {
+            #[doc = concat!("Converts the secret key into [`", stringify!($krate), "::SecretKey`]")]
+            ///
+            /// Chain code is dropped. Returns an error if the secret key is zero.
+            pub fn $to_secret_key(&self) -> Result<$krate::SecretKey, errors::InvalidKey> {
+                self.to_rustcrypto_secret_key()
+            }
+
+            #[doc = concat!("Converts the secret key into ECDSA [`", stringify!($krate), "::ecdsa::SigningKey`]")]
+            ///
+            /// Chain code is dropped. Returns an error if the secret key is zero.
+            pub fn $to_signing_key(
+                &self,
+            ) -> Result<$krate::ecdsa::SigningKey, errors::InvalidKey> {
+                self.$to_secret_key().map(Into::into)
+            }
+
+            /// Signs the message digest with deterministic ECDSA ([RFC 6979])
+            ///
+            /// `prehash` is a hash of the message, e.g. SHA256 digest. Returns an error if the
+            /// secret key is zero.
+            ///
+            /// [RFC 6979]: https://www.rfc-editor.org/rfc/rfc6979
+            pub fn sign_prehash(
+                &self,
+                prehash: &[u8; 32],
+            ) -> Result<$krate::ecdsa::Signature, errors::SignError> {
+                let signing_key = self.$to_signing_key().map_err(|_| errors::SignError)?;
+                $krate::ecdsa::signature::hazmat::PrehashSigner::sign_prehash(&signing_key, prehash)
+                    .map_err(|_| errors::SignError)
+            }
+        }
+
+        impl ExtendedPublicKey<$curve> {
+            #[doc = concat!("Converts the public key into [`", stringify!($krate), "::PublicKey`]")]
+            ///
+            /// Chain code is dropped. Returns an error if the public key is the identity point.
+            pub fn $to_public_key(&self) -> Result<$krate::PublicKey, errors::InvalidKey> {
+                self.to_rustcrypto_public_key()
+            }
+
+            #[doc = concat!("Converts the public key into ECDSA [`", stringify!($krate), "::ecdsa::VerifyingKey`]")]
+            ///
+            /// Chain code is dropped. Returns an error if the public key is the identity point.
+            pub fn $to_verifying_key(
+                &self,
+            ) -> Result<$krate::ecdsa::VerifyingKey, errors::InvalidKey> {
+                self.$to_public_key().map(Into::into)
+            }
+        }
+
+        impl $krate::ecdsa::signature::Signer<$krate::ecdsa::Signature>
+            for ExtendedSecretKey<$curve>
+        {
+            fn try_sign(
+                &self,
+                msg: &[u8],
+            ) -> Result<$krate::ecdsa::Signature, $krate::ecdsa::signature::Error> {
+                let signing_key = self
+                    .$to_signing_key()
+                    .map_err(|_| $krate::ecdsa::signature::Error::new())?;
+                $krate::ecdsa::signature::Signer::try_sign(&signing_key, msg)
+            }
+        }
+
+        impl $krate::ecdsa::signature::Verifier<$krate::ecdsa::Signature>
+            for ExtendedPublicKey<$curve>
+        {
+            fn verify(
+                &self,
+                msg: &[u8],
+                signature: &$krate::ecdsa::Signature,
+            ) -> Result<(), $krate::ecdsa::signature::Error> {
+                let verifying_key = self
+                    .$to_verifying_key()
+                    .map_err(|_| $krate::ecdsa::signature::Error::new())?;
+                $krate::ecdsa::signature::Verifier::verify(&verifying_key, msg, signature)
+            }
+        }
+
+        impl $krate::ecdsa::signature::Signer<$krate::ecdsa::Signature> for ExtendedKeyPair<$curve> {
+            fn try_sign(
+                &self,
+                msg: &[u8],
+            ) -> Result<$krate::ecdsa::Signature, $krate::ecdsa::signature::Error> {
+                $krate::ecdsa::signature::Signer::try_sign(self.secret_key(), msg)
+            }
+        }
+
+        impl $krate::ecdsa::signature::Verifier<$krate::ecdsa::Signature>
+            for ExtendedKeyPair<$curve>
+        {
+            fn verify(
+                &self,
+                msg: &[u8],
+                signature: &$krate::ecdsa::Signature,
+            ) -> Result<(), $krate::ecdsa::signature::Error> {
+                $krate::ecdsa::signature::Verifier::verify(self.public_key(), msg, signature)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "k256")]
+impl_conversions! {
+    curve: generic_ec::curves::Secp256k1,
+    crate: k256,
+    rustcrypto_curve: k256::Secp256k1,
+    to_secret_key: to_k256_secret_key,
+    to_signing_key: to_k256_signing_key,
+    to_public_key: to_k256_public_key,
+    to_verifying_key: to_k256_verifying_key,
+}
+
+#[cfg(feature = "p256")]
+impl_conversions! {
+    curve: generic_ec::curves::Secp256r1,
+    crate: p256,
+    rustcrypto_curve: p256::NistP256,
+    to_secret_key: to_p256_secret_key,
+    to_signing_key: to_p256_signing_key,
+    to_public_key: to_p256_public_key,
+    to_verifying_key: to_p256_verifying_key,
+}
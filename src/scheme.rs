@@ -0,0 +1,177 @@
+//! Derivation schemes structurally identical to SLIP10
+//!
+//! SLIP10 derives keys with HMAC-SHA512 and retries with a different HMAC input whenever the
+//! derived key is invalid. [`DerivationScheme`] makes both of these aspects pluggable, so
+//! non-standard schemes (e.g. based on a different 512-bit MAC, or rejecting invalid keys
+//! instead of retrying) can reuse the same index, path, and key machinery. [`Slip10`] is the
+//! standard scheme used by the rest of the crate.
+//!
+//! Functions of this module mirror top-level derivation functions, but are parametrized by the
+//! scheme. Derived keys differ from SLIP10 unless scheme is [`Slip10`].
+//!
+//! ### Example
+//! A scheme which never retries and returns an error instead:
+//! ```rust
+//! use slip_10::{scheme::DerivationScheme, supported_curves::Secp256k1};
+//!
+//! struct NoRetries;
+//! impl DerivationScheme for NoRetries {
+//!     type Mac = hmac::Hmac<sha2::Sha512>;
+//!     const MAX_RETRIES: Option<usize> = Some(0);
+//! }
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = slip_10::scheme::derive_master_key::<NoRetries, Secp256k1>(&seed)?;
+//! let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+//! let child_key_pair =
+//!     slip_10::scheme::derive_child_key_pair::<NoRetries, _>(&master_key_pair, 1 + slip_10::H)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use generic_array::typenum::U64;
+use generic_ec::Curve;
+
+use crate::{
+    errors, ChildIndex, DerivedShift, ExtendedKeyPair, ExtendedPublicKey, ExtendedSecretKey,
+    HardenedIndex, NonHardenedIndex, Seed, ShiftDeriver, SupportedCurve,
+};
+
+/// Derivation scheme: a MAC and retry policy
+pub trait DerivationScheme {
+    /// MAC which output is split into the key (left half) and the chain code (right half)
+    type Mac: hmac::Mac
+        + hmac::digest::KeyInit
+        + hmac::digest::OutputSizeUser<OutputSize = U64>
+        + Clone;
+
+    /// Max number of retries when derived key is invalid
+    ///
+    /// `None` means that number of retries is not limited, as defined in SLIP10. When limit is
+    /// exceeded, derivation returns [`DeriveError::TooManyRetries`](errors::DeriveError::TooManyRetries)
+    /// error.
+    const MAX_RETRIES: Option<usize> = None;
+}
+
+/// SLIP10 derivation scheme: HMAC-SHA512 with unlimited retries
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Slip10;
+
+impl DerivationScheme for Slip10 {
+    type Mac = crate::HmacSha512;
+}
+
+/// Derives a master key from the seed using derivation scheme `S`
+///
+/// Same as [`derive_master_key`](crate::derive_master_key), but parametrized by the scheme.
+pub fn derive_master_key<S: DerivationScheme, E: Curve + SupportedCurve>(
+    seed: impl AsRef<Seed>,
+) -> Result<ExtendedSecretKey<E>, errors::DeriveError> {
+    derive_master_key_with_curve_tag::<S, E>(E::CURVE_TYPE.curve_tag().as_bytes(), seed)
+}
+
+/// Derives a master key from the seed and the curve tag using derivation scheme `S`
+///
+/// Same as [`derive_master_key_with_curve_tag`](crate::derive_master_key_with_curve_tag), but
+/// parametrized by the scheme.
+pub fn derive_master_key_with_curve_tag<S: DerivationScheme, E: Curve>(
+    curve_tag: &[u8],
+    seed: impl AsRef<Seed>,
+) -> Result<ExtendedSecretKey<E>, errors::DeriveError> {
+    crate::master_key_with_retries::<S, E>(curve_tag, seed.as_ref(), S::MAX_RETRIES)
+        .map(|(key, _)| key)
+}
+
+/// Derives child key pair from parent key pair using derivation scheme `S`
+///
+/// Same as [`derive_child_key_pair`](crate::derive_child_key_pair), but parametrized by the
+/// scheme.
+pub fn derive_child_key_pair<S: DerivationScheme, E: Curve>(
+    parent_key: &ExtendedKeyPair<E>,
+    child_index: impl Into<ChildIndex>,
+) -> Result<ExtendedKeyPair<E>, errors::DeriveError> {
+    let shift = match child_index.into() {
+        ChildIndex::Hardened(i) => derive_hardened_shift::<S, E>(parent_key, i)?,
+        ChildIndex::NonHardened(i) => derive_public_shift::<S, E>(parent_key.public_key(), i)?,
+    };
+    Ok(crate::apply_shift(parent_key, shift))
+}
+
+/// Derives a child key pair with specified derivation path using derivation scheme `S`
+///
+/// Same as [`derive_child_key_pair_with_path`](crate::derive_child_key_pair_with_path), but
+/// parametrized by the scheme.
+pub fn derive_child_key_pair_with_path<S: DerivationScheme, E: Curve>(
+    parent_key: &ExtendedKeyPair<E>,
+    path: impl IntoIterator<Item = impl Into<ChildIndex>>,
+) -> Result<ExtendedKeyPair<E>, errors::DeriveError> {
+    let mut derived_key = parent_key.clone();
+    for (level, child_index) in path.into_iter().enumerate() {
+        let child_index = child_index.into();
+        derived_key = derive_child_key_pair::<S, E>(&derived_key, child_index)
+            .map_err(|err| err.at_level(level))?;
+    }
+    Ok(derived_key)
+}
+
+/// Derives child public key from parent public key using derivation scheme `S`
+///
+/// Same as [`derive_child_public_key`](crate::derive_child_public_key), but parametrized by the
+/// scheme.
+pub fn derive_child_public_key<S: DerivationScheme, E: Curve>(
+    parent_public_key: &ExtendedPublicKey<E>,
+    child_index: NonHardenedIndex,
+) -> Result<ExtendedPublicKey<E>, errors::DeriveError> {
+    derive_public_shift::<S, E>(parent_public_key, child_index).map(|shift| shift.child_public_key)
+}
+
+/// Derives a child public key with specified derivation path using derivation scheme `S`
+///
+/// Same as [`derive_child_public_key_with_path`](crate::derive_child_public_key_with_path), but
+/// parametrized by the scheme.
+pub fn derive_child_public_key_with_path<S: DerivationScheme, E: Curve>(
+    parent_public_key: &ExtendedPublicKey<E>,
+    path: impl IntoIterator<Item = NonHardenedIndex>,
+) -> Result<ExtendedPublicKey<E>, errors::DeriveError> {
+    let mut derived_key = *parent_public_key;
+    for (level, child_index) in path.into_iter().enumerate() {
+        derived_key = derive_child_public_key::<S, E>(&derived_key, child_index)
+            .map_err(|err| err.at_level(level))?;
+    }
+    Ok(derived_key)
+}
+
+/// Derives hardened child secret key using derivation scheme `S`
+///
+/// Same as [`derive_child_secret_key`](crate::derive_child_secret_key), but parametrized by the
+/// scheme.
+pub fn derive_child_secret_key<S: DerivationScheme, E: Curve>(
+    parent_key: &ExtendedSecretKey<E>,
+    child_index: HardenedIndex,
+) -> Result<ExtendedSecretKey<E>, errors::DeriveError> {
+    crate::child_secret_key_with_retries::<S, E>(parent_key, child_index, S::MAX_RETRIES)
+}
+
+/// Derives a shift for hardened child using derivation scheme `S`
+///
+/// Same as [`derive_hardened_shift`](crate::derive_hardened_shift), but parametrized by the
+/// scheme.
+pub fn derive_hardened_shift<S: DerivationScheme, E: Curve>(
+    parent_key: &ExtendedKeyPair<E>,
+    child_index: HardenedIndex,
+) -> Result<DerivedShift<E>, errors::DeriveError> {
+    crate::hardened_shift_with_retries::<S, E>(parent_key, child_index, S::MAX_RETRIES)
+        .map(|(shift, _)| shift)
+}
+
+/// Derives a shift for non-hardened child using derivation scheme `S`
+///
+/// Same as [`derive_public_shift`](crate::derive_public_shift), but parametrized by the scheme.
+/// Use [`ShiftDeriver::with_scheme`] to derive many children of the same parent.
+pub fn derive_public_shift<S: DerivationScheme, E: Curve>(
+    parent_public_key: &ExtendedPublicKey<E>,
+    child_index: NonHardenedIndex,
+) -> Result<DerivedShift<E>, errors::DeriveError> {
+    ShiftDeriver::<E, S>::with_scheme(parent_public_key)
+        .derive_with_retries(child_index, S::MAX_RETRIES)
+        .map(|(shift, _)| shift)
+}
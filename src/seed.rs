@@ -0,0 +1,169 @@
+use core::fmt;
+
+use rand_core::{CryptoRng, RngCore};
+use subtle::{Choice, ConstantTimeEq};
+
+use crate::errors;
+
+/// Seed which master key is derived from
+///
+/// Seed must be 16-64 bytes long as defined in SLIP-10. The length is checked once, when the
+/// seed is constructed, so master key derivation accepts any [`Seed`] and never fails on it.
+///
+/// Seeds are compared in constant time. `Debug` output doesn't include the seed unless
+/// `insecure-debug` feature is enabled. With `zeroize` feature, the seed is erased on drop.
+///
+/// ### Example
+/// ```rust
+/// use slip_10::{supported_curves::Secp256k1, Seed};
+///
+/// let seed = Seed::new(b"16-64 bytes of high entropy")?;
+/// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+///
+/// assert!(Seed::new(b"too short").is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone)]
+pub struct Seed {
+    bytes: [u8; Seed::MAX_LEN],
+    len: usize,
+}
+
+impl Seed {
+    /// Minimal length of the seed in bytes
+    pub const MIN_LEN: usize = 16;
+    /// Maximal length of the seed in bytes
+    pub const MAX_LEN: usize = 64;
+
+    /// Constructs a seed, returns an error if it's not 16-64 bytes long
+    pub fn new(seed: &[u8]) -> Result<Self, errors::InvalidLength> {
+        if !(Self::MIN_LEN..=Self::MAX_LEN).contains(&seed.len()) {
+            return Err(errors::InvalidLength);
+        }
+        let mut bytes = [0u8; Self::MAX_LEN];
+        bytes[..seed.len()].copy_from_slice(seed);
+        Ok(Self {
+            bytes,
+            len: seed.len(),
+        })
+    }
+
+    /// Generates a random seed of `len` bytes
+    ///
+    /// `len` must be within 16-64 bytes, otherwise an error is returned. 32 bytes (256 bits of
+    /// entropy) is a good default, 64 bytes is the length of BIP39 seeds.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use slip_10::{supported_curves::Secp256k1, Seed};
+    ///
+    /// let mut rng = rand_core::OsRng;
+    /// let seed = Seed::generate(&mut rng, 32)?;
+    /// let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn generate(
+        rng: &mut (impl CryptoRng + RngCore),
+        len: usize,
+    ) -> Result<Self, errors::InvalidLength> {
+        if !(Self::MIN_LEN..=Self::MAX_LEN).contains(&len) {
+            return Err(errors::InvalidLength);
+        }
+        let mut bytes = [0u8; Self::MAX_LEN];
+        rng.fill_bytes(&mut bytes[..len]);
+        Ok(Self { bytes, len })
+    }
+
+    /// Returns seed bytes
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl TryFrom<&[u8]> for Seed {
+    type Error = errors::InvalidLength;
+    fn try_from(seed: &[u8]) -> Result<Self, Self::Error> {
+        Self::new(seed)
+    }
+}
+
+macro_rules! impl_from_array {
+    ($($len:literal),+) => {$(
+        impl From<[u8; $len]> for Seed {
+            fn from(seed: [u8; $len]) -> Self {
+                Self::new(&seed).expect("this never fails: length is within the bounds")
+            }
+        }
+    )+};
+}
+
+impl_from_array!(16, 32, 64);
+
+impl AsRef<Seed> for Seed {
+    fn as_ref(&self) -> &Seed {
+        self
+    }
+}
+
+impl AsRef<[u8]> for Seed {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl ConstantTimeEq for Seed {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // Bytes beyond the length are always zero
+        self.len.ct_eq(&other.len) & self.bytes.ct_eq(&other.bytes)
+    }
+}
+
+impl PartialEq for Seed {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for Seed {}
+
+impl fmt::Debug for Seed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::DebugSecret("Seed", self.as_bytes()).fmt(f)
+    }
+}
+
+/// Generates a seed of 16-64 bytes
+///
+/// Seed is taken from the fuzzer input, so it's predictable by design. Only use it in tests and
+/// fuzzing, never derive real keys from it.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Seed {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let len = u.int_in_range(Self::MIN_LEN..=Self::MAX_LEN)?;
+        Self::new(u.bytes(len)?).map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (
+            Self::MIN_LEN,
+            Some(core::mem::size_of::<usize>() + Self::MAX_LEN),
+        )
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Seed {
+    fn zeroize(&mut self) {
+        self.bytes.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Seed {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Seed {}
@@ -0,0 +1,142 @@
+use core::fmt;
+
+use serde::de::{self, Visitor};
+use serde_with::{DeserializeAs, SerializeAs};
+
+/// Serializes byte arrays compactly
+///
+/// Byte array is serialized as a hex string in human-readable formats, and as bytes
+/// otherwise. At deserialization, it also accepts a sequence of bytes, which is how
+/// byte arrays are serialized by default.
+pub struct Bytes;
+
+impl<const N: usize> SerializeAs<[u8; N]> for Bytes {
+    fn serialize_as<S>(source: &[u8; N], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&Hex(source))
+        } else {
+            serializer.serialize_bytes(source)
+        }
+    }
+}
+
+impl<'de, const N: usize> DeserializeAs<'de, [u8; N]> for Bytes {
+    fn deserialize_as<D>(deserializer: D) -> Result<[u8; N], D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct BytesVisitor<const N: usize>;
+        impl<'de, const N: usize> Visitor<'de> for BytesVisitor<N> {
+            type Value = [u8; N];
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{N} bytes")
+            }
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                let mut out = [0u8; N];
+                hex::decode_to_slice(v, &mut out).map_err(E::custom)?;
+                Ok(out)
+            }
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                v.try_into().map_err(|_| E::invalid_length(v.len(), &self))
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                let mut out = [0u8; N];
+                for (i, byte) in out.iter_mut().enumerate() {
+                    *byte = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                if seq.next_element::<de::IgnoredAny>()?.is_some() {
+                    return Err(de::Error::invalid_length(N + 1, &self));
+                }
+                Ok(out)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(BytesVisitor)
+        } else {
+            deserializer.deserialize_bytes(BytesVisitor)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl SerializeAs<alloc::vec::Vec<u8>> for Bytes {
+    fn serialize_as<S>(source: &alloc::vec::Vec<u8>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&Hex(source))
+        } else {
+            serializer.serialize_bytes(source)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de> DeserializeAs<'de, alloc::vec::Vec<u8>> for Bytes {
+    fn deserialize_as<D>(deserializer: D) -> Result<alloc::vec::Vec<u8>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct BytesVisitor;
+        impl<'de> Visitor<'de> for BytesVisitor {
+            type Value = alloc::vec::Vec<u8>;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("bytes")
+            }
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                let mut out = alloc::vec![0u8; v.len() / 2];
+                hex::decode_to_slice(v, &mut out).map_err(E::custom)?;
+                Ok(out)
+            }
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(v.to_vec())
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                let mut out = alloc::vec::Vec::with_capacity(seq.size_hint().unwrap_or(0).min(64));
+                while let Some(byte) = seq.next_element()? {
+                    out.push(byte);
+                }
+                Ok(out)
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(BytesVisitor)
+        } else {
+            deserializer.deserialize_byte_buf(BytesVisitor)
+        }
+    }
+}
+
+struct Hex<'a>(&'a [u8]);
+
+impl fmt::Display for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
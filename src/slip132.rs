@@ -0,0 +1,157 @@
+//! [SLIP-0132][slip132] registry of extended key version bytes
+//!
+//! Wallets encode the script type of an account into version bytes of its extended keys, so
+//! that `zpub...` is known to be a native SegWit account, `ypub...` is a nested SegWit one, and
+//! so on. [`Slip132`] lists the registered Bitcoin version bytes and implements
+//! [`KeyVersion`], so it can be used with [`Xpub::encode_with_version`] and
+//! [`Xpub::decode_with_version`] (and the same methods of [`Xprv`]).
+//!
+//! ### Example
+//! ```rust
+//! use slip_10::{
+//!     bip32::{Bip32ExtendedKey, Xpub},
+//!     slip132::{ScriptType, Slip132},
+//!     supported_curves::Secp256k1,
+//!     DerivationPath,
+//! };
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//! let path: DerivationPath = "m/49'/0'/0'".parse()?;
+//! let account = Bip32ExtendedKey::master(master_key).derive_child_with_path(&path)?;
+//!
+//! let ypub = account.to_xpub().encode_with_version(&Slip132::Ypub).to_string();
+//! assert!(ypub.starts_with("ypub"));
+//!
+//! let (xpub, version) = Xpub::<Secp256k1>::decode_with_version::<Slip132>(&ypub)?;
+//! assert_eq!(version.script_type(), ScriptType::P2shP2wpkh);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [slip132]: https://github.com/satoshilabs/slips/blob/master/slip-0132.md
+
+use crate::bip32::{KeyVersion, Network};
+#[cfg(doc)]
+use crate::bip32::{Xprv, Xpub};
+
+/// Script type of the account, as implied by version bytes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScriptType {
+    /// P2PKH or P2SH
+    P2pkh,
+    /// P2WPKH nested in P2SH
+    P2shP2wpkh,
+    /// P2WSH nested in P2SH
+    P2shP2wsh,
+    /// P2WPKH
+    P2wpkh,
+    /// P2WSH
+    P2wsh,
+}
+
+/// Bitcoin version bytes registered in SLIP-0132
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Slip132 {
+    /// `xpub`/`xprv`: mainnet P2PKH or P2SH
+    Xpub,
+    /// `ypub`/`yprv`: mainnet P2WPKH nested in P2SH
+    Ypub,
+    /// `Ypub`/`Yprv`: mainnet P2WSH nested in P2SH
+    YpubMultisig,
+    /// `zpub`/`zprv`: mainnet P2WPKH
+    Zpub,
+    /// `Zpub`/`Zprv`: mainnet P2WSH
+    ZpubMultisig,
+    /// `tpub`/`tprv`: testnet P2PKH or P2SH
+    Tpub,
+    /// `upub`/`uprv`: testnet P2WPKH nested in P2SH
+    Upub,
+    /// `Upub`/`Uprv`: testnet P2WSH nested in P2SH
+    UpubMultisig,
+    /// `vpub`/`vprv`: testnet P2WPKH
+    Vpub,
+    /// `Vpub`/`Vprv`: testnet P2WSH
+    VpubMultisig,
+}
+
+impl Slip132 {
+    /// All registered versions
+    pub const ALL: [Self; 10] = [
+        Self::Xpub,
+        Self::Ypub,
+        Self::YpubMultisig,
+        Self::Zpub,
+        Self::ZpubMultisig,
+        Self::Tpub,
+        Self::Upub,
+        Self::UpubMultisig,
+        Self::Vpub,
+        Self::VpubMultisig,
+    ];
+
+    /// Returns script type implied by the version
+    pub fn script_type(&self) -> ScriptType {
+        match self {
+            Self::Xpub | Self::Tpub => ScriptType::P2pkh,
+            Self::Ypub | Self::Upub => ScriptType::P2shP2wpkh,
+            Self::YpubMultisig | Self::UpubMultisig => ScriptType::P2shP2wsh,
+            Self::Zpub | Self::Vpub => ScriptType::P2wpkh,
+            Self::ZpubMultisig | Self::VpubMultisig => ScriptType::P2wsh,
+        }
+    }
+
+    /// Checks whether the version is used on testnet
+    pub fn is_testnet(&self) -> bool {
+        self.network() == Network::Testnet
+    }
+
+    /// Returns network which the version is used on
+    pub fn network(&self) -> Network {
+        match self {
+            Self::Xpub | Self::Ypub | Self::YpubMultisig | Self::Zpub | Self::ZpubMultisig => {
+                Network::Mainnet
+            }
+            Self::Tpub | Self::Upub | Self::UpubMultisig | Self::Vpub | Self::VpubMultisig => {
+                Network::Testnet
+            }
+        }
+    }
+
+    /// Returns `(public, secret)` version bytes
+    const fn versions(&self) -> ([u8; 4], [u8; 4]) {
+        match self {
+            Self::Xpub => ([0x04, 0x88, 0xb2, 0x1e], [0x04, 0x88, 0xad, 0xe4]),
+            Self::Ypub => ([0x04, 0x9d, 0x7c, 0xb2], [0x04, 0x9d, 0x78, 0x78]),
+            Self::YpubMultisig => ([0x02, 0x95, 0xb4, 0x3f], [0x02, 0x95, 0xb0, 0x05]),
+            Self::Zpub => ([0x04, 0xb2, 0x47, 0x46], [0x04, 0xb2, 0x43, 0x0c]),
+            Self::ZpubMultisig => ([0x02, 0xaa, 0x7e, 0xd3], [0x02, 0xaa, 0x7a, 0x99]),
+            Self::Tpub => ([0x04, 0x35, 0x87, 0xcf], [0x04, 0x35, 0x83, 0x94]),
+            Self::Upub => ([0x04, 0x4a, 0x52, 0x62], [0x04, 0x4a, 0x4e, 0x28]),
+            Self::UpubMultisig => ([0x02, 0x42, 0x89, 0xef], [0x02, 0x42, 0x85, 0xb5]),
+            Self::Vpub => ([0x04, 0x5f, 0x1c, 0xf6], [0x04, 0x5f, 0x18, 0xbc]),
+            Self::VpubMultisig => ([0x02, 0x57, 0x54, 0x83], [0x02, 0x57, 0x50, 0x48]),
+        }
+    }
+}
+
+impl KeyVersion for Slip132 {
+    fn public_version(&self) -> [u8; 4] {
+        self.versions().0
+    }
+
+    fn secret_version(&self) -> [u8; 4] {
+        self.versions().1
+    }
+
+    fn from_public_version(version: [u8; 4]) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|v| v.public_version() == version)
+    }
+
+    fn from_secret_version(version: [u8; 4]) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|v| v.secret_version() == version)
+    }
+}
@@ -0,0 +1,765 @@
+//! [SLIP-0039][slip39] Shamir backup of the master secret
+//!
+//! SLIP-39 splits the master secret (the seed which master key is derived from) into mnemonic
+//! shares: shares are organized into groups, the secret can be recovered from `group_threshold`
+//! groups, and each group is recovered from `member_threshold` of its shares. Shares are
+//! compatible with Trezor and other SLIP-39 wallets.
+//!
+//! [`split_master_secret`] generates the shares, each share is displayed as a mnemonic of 20
+//! (for 128 bits secret) or 33 (for 256 bits secret) words, and can be parsed back from it.
+//! [`combine_shares`] recovers the master secret from the shares, and [`seed_from_slip39_shares`]
+//! derives the master key from the mnemonics straight away.
+//!
+//! ### Example
+//! Split a master secret into 2-of-3 shares:
+//! ```rust
+//! use slip_10::slip39::{self, Group, Share};
+//!
+//! # let master_secret = [0xab; 16];
+//! let mut rng = rand_core::OsRng;
+//! let shares = slip39::split_master_secret(
+//!     &master_secret,
+//!     b"passphrase",
+//!     1,
+//!     &[Group { member_threshold: 2, member_count: 3 }],
+//!     Default::default(),
+//!     &mut rng,
+//! )?;
+//! assert_eq!(shares[0].len(), 3);
+//!
+//! let mnemonic = shares[0][0].to_string();
+//! assert_eq!(mnemonic.split(' ').count(), 20);
+//! let share: Share = mnemonic.parse()?;
+//! assert_eq!(share.member_threshold(), 2);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [slip39]: https://github.com/satoshilabs/slips/blob/master/slip-0039.md
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use hmac::Mac as _;
+use rand_core::{CryptoRng, RngCore};
+
+use crate::errors;
+
+mod wordlist;
+
+pub use wordlist::WORDLIST;
+
+/// Maximum number of groups, and of members in a group
+pub const MAX_SHARE_COUNT: u8 = 16;
+/// Minimum length of master secret in bytes
+pub const MIN_SECRET_LEN: usize = 16;
+
+/// Number of PBKDF2 iterations in each round of Feistel network when iteration exponent is 0
+const BASE_ITERATION_COUNT: u32 = 10000 / ROUND_COUNT as u32;
+/// Number of rounds of Feistel network
+const ROUND_COUNT: u8 = 4;
+/// Size of the digest of the secret, which is stored in one of the shares
+const DIGEST_LEN: usize = 4;
+/// x coordinate of the share which contains the digest
+const DIGEST_INDEX: u8 = 254;
+/// x coordinate of the shared secret
+const SECRET_INDEX: u8 = 255;
+/// Number of words in share header: identifier, extendable flag, iteration exponent, group
+/// index, group threshold, group count, member index, and member threshold
+const HEADER_WORDS: usize = 4;
+/// Number of checksum words
+const CHECKSUM_WORDS: usize = 3;
+
+/// Group of shares
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Group {
+    /// Number of shares required to recover the group secret
+    pub member_threshold: u8,
+    /// Number of shares in the group
+    pub member_count: u8,
+}
+
+/// Parameters of the backup
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    /// Iteration exponent: encryption of master secret takes `10000 * 2^iteration_exponent`
+    /// PBKDF2 iterations. Must be less than 16.
+    pub iteration_exponent: u8,
+    /// Whether the backup is extendable, i.e. more shares with the same identifier can be
+    /// generated later for the same master secret and passphrase
+    pub extendable: bool,
+}
+
+impl Default for Params {
+    /// Defaults are the same as in Trezor: iteration exponent is 1, backup is extendable
+    fn default() -> Self {
+        Self {
+            iteration_exponent: 1,
+            extendable: true,
+        }
+    }
+}
+
+/// SLIP-39 share
+///
+/// Displayed as mnemonic, and can be parsed from it via [`FromStr`](core::str::FromStr)
+#[derive(Clone, PartialEq, Eq)]
+pub struct Share {
+    identifier: u16,
+    extendable: bool,
+    iteration_exponent: u8,
+    group_index: u8,
+    group_threshold: u8,
+    group_count: u8,
+    member_index: u8,
+    member_threshold: u8,
+    value: Vec<u8>,
+}
+
+impl Share {
+    /// Random identifier of the backup, shared by all its shares
+    pub fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /// Whether the backup is extendable
+    pub fn extendable(&self) -> bool {
+        self.extendable
+    }
+
+    /// Iteration exponent of master secret encryption
+    pub fn iteration_exponent(&self) -> u8 {
+        self.iteration_exponent
+    }
+
+    /// Index of the group which the share belongs to
+    pub fn group_index(&self) -> u8 {
+        self.group_index
+    }
+
+    /// Number of groups required to recover the master secret
+    pub fn group_threshold(&self) -> u8 {
+        self.group_threshold
+    }
+
+    /// Total number of groups
+    pub fn group_count(&self) -> u8 {
+        self.group_count
+    }
+
+    /// Index of the share within its group
+    pub fn member_index(&self) -> u8 {
+        self.member_index
+    }
+
+    /// Number of shares required to recover the group secret
+    pub fn member_threshold(&self) -> u8 {
+        self.member_threshold
+    }
+
+    /// Returns words of the share as indexes in [`WORDLIST`]
+    fn words(&self) -> Vec<u16> {
+        let value_words = (self.value.len() * 8).div_ceil(10);
+        let mut words = Vec::with_capacity(HEADER_WORDS + value_words + CHECKSUM_WORDS);
+
+        let header = (u64::from(self.identifier) << 25)
+            | (u64::from(self.extendable) << 24)
+            | (u64::from(self.iteration_exponent) << 20)
+            | (u64::from(self.group_index) << 16)
+            | (u64::from(self.group_threshold - 1) << 12)
+            | (u64::from(self.group_count - 1) << 8)
+            | (u64::from(self.member_index) << 4)
+            | u64::from(self.member_threshold - 1);
+        words.extend(
+            (0..HEADER_WORDS)
+                .rev()
+                .map(|i| ((header >> (10 * i)) & 1023) as u16),
+        );
+
+        // Value is padded with zeroes on the left to the multiple of 10 bits
+        let mut acc = 0u32;
+        let mut bits = (value_words * 10 - self.value.len() * 8) as u32;
+        for byte in &self.value {
+            acc = (acc << 8) | u32::from(*byte);
+            bits += 8;
+            if bits >= 10 {
+                bits -= 10;
+                words.push(((acc >> bits) & 1023) as u16);
+                acc &= (1 << bits) - 1;
+            }
+        }
+
+        let checksum = rs1024_create_checksum(customization_string(self.extendable), &words);
+        words.extend_from_slice(&checksum);
+        words
+    }
+}
+
+impl fmt::Display for Share {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, word) in self.words().into_iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(WORDLIST[usize::from(word)])?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Share {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Share")
+            .field("identifier", &self.identifier)
+            .field("extendable", &self.extendable)
+            .field("iteration_exponent", &self.iteration_exponent)
+            .field("group_index", &self.group_index)
+            .field("group_threshold", &self.group_threshold)
+            .field("group_count", &self.group_count)
+            .field("member_index", &self.member_index)
+            .field("member_threshold", &self.member_threshold)
+            .field("value", &crate::DebugSecret("ShareValue", &self.value[..]))
+            .finish()
+    }
+}
+
+impl core::str::FromStr for Share {
+    type Err = errors::ParseSlip39ShareError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words = s
+            .split_whitespace()
+            .map(|word| {
+                WORDLIST
+                    .binary_search(&word.to_ascii_lowercase().as_str())
+                    .map(|i| i as u16)
+                    .map_err(|_| errors::ParseSlip39ShareError::UnknownWord)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // At least 128 bits of value
+        let min_words = HEADER_WORDS + (MIN_SECRET_LEN * 8).div_ceil(10) + CHECKSUM_WORDS;
+        if words.len() < min_words {
+            return Err(errors::ParseSlip39ShareError::InvalidLength);
+        }
+
+        let header = words[..HEADER_WORDS]
+            .iter()
+            .fold(0u64, |acc, word| (acc << 10) | u64::from(*word));
+        let extendable = (header >> 24) & 1 == 1;
+        if !rs1024_verify_checksum(customization_string(extendable), &words) {
+            return Err(errors::ParseSlip39ShareError::InvalidChecksum);
+        }
+
+        let value_words = &words[HEADER_WORDS..words.len() - CHECKSUM_WORDS];
+        let padding_bits = (value_words.len() * 10) % 16;
+        if padding_bits > 8 {
+            return Err(errors::ParseSlip39ShareError::InvalidLength);
+        }
+        // First `padding_bits` bits are padding, they must be zero
+        let value = strip_padding(value_words, padding_bits)
+            .ok_or(errors::ParseSlip39ShareError::InvalidPadding)?;
+
+        let group_threshold = ((header >> 12) & 15) as u8 + 1;
+        let group_count = ((header >> 8) & 15) as u8 + 1;
+        if group_threshold > group_count {
+            return Err(errors::ParseSlip39ShareError::InvalidThreshold);
+        }
+
+        Ok(Self {
+            identifier: (header >> 25) as u16,
+            extendable,
+            iteration_exponent: ((header >> 20) & 15) as u8,
+            group_index: ((header >> 16) & 15) as u8,
+            group_threshold,
+            group_count,
+            member_index: ((header >> 4) & 15) as u8,
+            member_threshold: (header & 15) as u8 + 1,
+            value,
+        })
+    }
+}
+
+/// Converts 10-bit words into bytes, checking that leading `padding_bits` bits are zero
+fn strip_padding(words: &[u16], padding_bits: usize) -> Option<Vec<u8>> {
+    let total_bits = words.len() * 10;
+    let mut value = Vec::with_capacity((total_bits - padding_bits) / 8);
+    let bit = |i: usize| (words[i / 10] >> (9 - i % 10)) & 1;
+    if (0..padding_bits).any(|i| bit(i) != 0) {
+        return None;
+    }
+    for byte_start in (padding_bits..total_bits).step_by(8) {
+        let byte = (byte_start..byte_start + 8).fold(0u8, |acc, i| (acc << 1) | bit(i) as u8);
+        value.push(byte);
+    }
+    Some(value)
+}
+
+/// Splits the master secret into shares
+///
+/// Master secret is encrypted with the passphrase, and then split into `groups.len()` groups,
+/// `group_threshold` of which are required to recover the secret. Returns shares of each group.
+///
+/// Master secret must be at least 16 bytes long, and its length must be even. Passphrase must
+/// consist of printable ASCII characters. Each group may have up to 16 shares, and there may be
+/// up to 16 groups. Groups with threshold 1 must consist of a single share.
+pub fn split_master_secret(
+    master_secret: &[u8],
+    passphrase: &[u8],
+    group_threshold: u8,
+    groups: &[Group],
+    params: Params,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<Vec<Vec<Share>>, errors::Slip39Error> {
+    if master_secret.len() < MIN_SECRET_LEN || !master_secret.len().is_multiple_of(2) {
+        return Err(errors::Slip39Error::InvalidSecretLength);
+    }
+    if !passphrase.iter().all(|c| (32..=126).contains(c)) {
+        return Err(errors::Slip39Error::InvalidPassphrase);
+    }
+    if params.iteration_exponent >= 16 {
+        return Err(errors::Slip39Error::InvalidIterationExponent);
+    }
+    let group_count =
+        u8::try_from(groups.len()).map_err(|_| errors::Slip39Error::InvalidThreshold)?;
+    if group_threshold == 0 || group_threshold > group_count || group_count > MAX_SHARE_COUNT {
+        return Err(errors::Slip39Error::InvalidThreshold);
+    }
+    if groups.iter().any(|group| {
+        group.member_threshold == 0
+            || group.member_threshold > group.member_count
+            || group.member_count > MAX_SHARE_COUNT
+            || (group.member_threshold == 1 && group.member_count > 1)
+    }) {
+        return Err(errors::Slip39Error::InvalidThreshold);
+    }
+
+    let identifier = (rng.next_u32() & 0x7fff) as u16;
+    let encrypted_secret = encrypt(
+        master_secret,
+        passphrase,
+        params.iteration_exponent,
+        identifier,
+        params.extendable,
+    );
+
+    let group_shares = split_secret(group_threshold, group_count, &encrypted_secret, rng);
+    Ok(group_shares
+        .into_iter()
+        .zip(groups)
+        .enumerate()
+        .map(|(group_index, (group_secret, group))| {
+            split_secret(
+                group.member_threshold,
+                group.member_count,
+                &group_secret,
+                rng,
+            )
+            .into_iter()
+            .enumerate()
+            .map(|(member_index, value)| Share {
+                identifier,
+                extendable: params.extendable,
+                iteration_exponent: params.iteration_exponent,
+                group_index: group_index as u8,
+                group_threshold,
+                group_count,
+                member_index: member_index as u8,
+                member_threshold: group.member_threshold,
+                value,
+            })
+            .collect()
+        })
+        .collect())
+}
+
+/// Recovers the master secret from the shares
+///
+/// Shares must belong to the same backup, and there must be at least `group_threshold` groups
+/// with at least `member_threshold` shares in each. Excess shares are ignored. Recovered secret
+/// is checked against the digest stored in the shares, however, wrong passphrase cannot be
+/// detected: it yields a different master secret.
+pub fn combine_shares(
+    shares: &[Share],
+    passphrase: &[u8],
+) -> Result<Vec<u8>, errors::CombineSlip39Error> {
+    if !passphrase.iter().all(|c| (32..=126).contains(c)) {
+        return Err(errors::CombineSlip39Error::InvalidPassphrase);
+    }
+    let first = shares
+        .first()
+        .ok_or(errors::CombineSlip39Error::InsufficientShares)?;
+    if shares.iter().any(|share| {
+        share.identifier != first.identifier
+            || share.extendable != first.extendable
+            || share.iteration_exponent != first.iteration_exponent
+            || share.group_threshold != first.group_threshold
+            || share.group_count != first.group_count
+            || share.group_index >= share.group_count
+            || share.value.len() != first.value.len()
+    }) {
+        return Err(errors::CombineSlip39Error::MismatchedShares);
+    }
+
+    let mut groups: Vec<(u8, Vec<u8>)> = Vec::new();
+    for group_index in 0..first.group_count {
+        let members: Vec<&Share> = shares
+            .iter()
+            .filter(|share| share.group_index == group_index)
+            .collect();
+        let Some(&member) = members.first() else {
+            continue;
+        };
+        if members
+            .iter()
+            .any(|share| share.member_threshold != member.member_threshold)
+        {
+            erase_points(&mut groups);
+            return Err(errors::CombineSlip39Error::MismatchedShares);
+        }
+        let mut points: Vec<(u8, &[u8])> = Vec::with_capacity(members.len());
+        for share in members {
+            if points.iter().any(|(x, _)| *x == share.member_index) {
+                erase_points(&mut groups);
+                return Err(errors::CombineSlip39Error::DuplicateShare);
+            }
+            points.push((share.member_index, &share.value));
+        }
+        if points.len() >= usize::from(member.member_threshold) {
+            points.truncate(usize::from(member.member_threshold));
+            match recover_secret(&points) {
+                Ok(group_secret) => groups.push((group_index, group_secret)),
+                Err(err) => {
+                    erase_points(&mut groups);
+                    return Err(err);
+                }
+            }
+        }
+    }
+    if groups.len() < usize::from(first.group_threshold) {
+        erase_points(&mut groups);
+        return Err(errors::CombineSlip39Error::InsufficientShares);
+    }
+
+    let encrypted_secret = recover_secret(&groups[..usize::from(first.group_threshold)]);
+    erase_points(&mut groups);
+    let mut encrypted_secret = encrypted_secret?;
+    let master_secret = decrypt(
+        &encrypted_secret,
+        passphrase,
+        first.iteration_exponent,
+        first.identifier,
+        first.extendable,
+    );
+    crate::erase(&mut encrypted_secret);
+    Ok(master_secret)
+}
+
+/// Parses SLIP-39 mnemonics, recovers the master secret, and derives master key from it
+///
+/// Each mnemonic is validated (including its checksum), and then shares are combined as
+/// described in [`combine_shares`].
+///
+/// ### Example
+/// ```rust
+/// use slip_10::{slip39, supported_curves::Secp256k1};
+///
+/// let master_key = slip39::seed_from_slip39_shares::<Secp256k1>(
+///     &["duckling enlarge academic academic agency result length solution fridge kidney \
+///         coal piece deal husband erode duke ajar critical decision keyboard"],
+///     b"TREZOR",
+/// )?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn seed_from_slip39_shares<E: generic_ec::Curve + crate::SupportedCurve>(
+    mnemonics: &[impl AsRef<str>],
+    passphrase: &[u8],
+) -> Result<crate::ExtendedSecretKey<E>, errors::CombineSlip39Error> {
+    let shares = mnemonics
+        .iter()
+        .map(|mnemonic| mnemonic.as_ref().parse())
+        .collect::<Result<Vec<Share>, _>>()
+        .map_err(errors::CombineSlip39Error::InvalidShare)?;
+    let mut master_secret = combine_shares(&shares, passphrase)?;
+    let seed = crate::Seed::new(&master_secret);
+    crate::erase(&mut master_secret);
+    let seed = seed.map_err(|_| errors::CombineSlip39Error::InvalidSecretLength)?;
+    Ok(crate::derive_master_key(seed))
+}
+
+/// Recovers the secret from `threshold` shares, checking its digest
+fn recover_secret(
+    points: &[(u8, impl AsRef<[u8]>)],
+) -> Result<Vec<u8>, errors::CombineSlip39Error> {
+    if let [(_, secret)] = points {
+        return Ok(secret.as_ref().to_vec());
+    }
+    let mut secret = interpolate(points, SECRET_INDEX);
+    let mut digest_share = interpolate(points, DIGEST_INDEX);
+    let (expected_digest, random_part) = digest_share.split_at(DIGEST_LEN);
+    let digest_matches = bool::from(subtle::ConstantTimeEq::ct_eq(
+        &digest(random_part, &secret)[..],
+        expected_digest,
+    ));
+    crate::erase(&mut digest_share);
+    if !digest_matches {
+        crate::erase(&mut secret);
+        return Err(errors::CombineSlip39Error::InvalidDigest);
+    }
+    Ok(secret)
+}
+
+/// Erases y coordinates of the points, e.g. recovered group secrets
+fn erase_points(points: &mut [(u8, Vec<u8>)]) {
+    points.iter_mut().for_each(|(_, y)| crate::erase(y));
+}
+
+/// Encrypts master secret with 4-round Feistel network
+fn encrypt(
+    master_secret: &[u8],
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+) -> Vec<u8> {
+    feistel(
+        master_secret,
+        passphrase,
+        iteration_exponent,
+        identifier,
+        extendable,
+        0..ROUND_COUNT,
+    )
+}
+
+/// Decrypts master secret encrypted by [`encrypt`]: same Feistel network with rounds in
+/// reverse order
+fn decrypt(
+    encrypted_secret: &[u8],
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+) -> Vec<u8> {
+    feistel(
+        encrypted_secret,
+        passphrase,
+        iteration_exponent,
+        identifier,
+        extendable,
+        (0..ROUND_COUNT).rev(),
+    )
+}
+
+fn feistel(
+    input: &[u8],
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+    rounds: impl Iterator<Item = u8>,
+) -> Vec<u8> {
+    let (left, right) = input.split_at(input.len() / 2);
+    let (mut left, mut right) = (left.to_vec(), right.to_vec());
+    for round in rounds {
+        let mut f = round_function(
+            round,
+            passphrase,
+            iteration_exponent,
+            identifier,
+            extendable,
+            &right,
+        );
+        left.iter_mut().zip(&f).for_each(|(l, f)| *l ^= f);
+        crate::erase(&mut f);
+        core::mem::swap(&mut left, &mut right);
+    }
+    let mut output = Vec::with_capacity(input.len());
+    output.extend_from_slice(&right);
+    output.extend_from_slice(&left);
+    crate::erase(&mut left);
+    crate::erase(&mut right);
+    output
+}
+
+/// Round function of Feistel network: PBKDF2-HMAC-SHA256 of the passphrase salted with
+/// half of the block
+fn round_function(
+    round: u8,
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+    block: &[u8],
+) -> Vec<u8> {
+    let prf = hmac::Hmac::<sha2::Sha256>::new_from_slice(&[&[round][..], passphrase].concat())
+        .expect("this never fails: hmac can handle keys of any size");
+    let iterations = BASE_ITERATION_COUNT << iteration_exponent;
+
+    let mut out = Vec::with_capacity(block.len());
+    for i in 1u32..=(block.len().div_ceil(32) as u32) {
+        let mut mac = prf.clone();
+        if !extendable {
+            mac.update(b"shamir");
+            mac.update(&identifier.to_be_bytes());
+        }
+        let mut u = mac
+            .chain_update(block)
+            .chain_update(i.to_be_bytes())
+            .finalize()
+            .into_bytes();
+        let mut t = u;
+        for _ in 1..iterations {
+            u = prf.clone().chain_update(u).finalize().into_bytes();
+            t.iter_mut().zip(&u).for_each(|(t, u)| *t ^= u);
+        }
+        out.extend_from_slice(&t);
+    }
+    out.truncate(block.len());
+    out
+}
+
+/// Splits the secret into `count` shares, `threshold` of which are required to recover it
+///
+/// Returns values of shares with x coordinates `0..count`
+fn split_secret(
+    threshold: u8,
+    count: u8,
+    secret: &[u8],
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Vec<Vec<u8>> {
+    if threshold == 1 {
+        return (0..count).map(|_| secret.to_vec()).collect();
+    }
+
+    // Shares 0..threshold-2 are random, and along with the digest share and the secret they
+    // define the polynomial
+    let mut points: Vec<(u8, Vec<u8>)> = (0..threshold - 2)
+        .map(|x| {
+            let mut value = alloc::vec![0u8; secret.len()];
+            rng.fill_bytes(&mut value);
+            (x, value)
+        })
+        .collect();
+
+    let mut random_part = alloc::vec![0u8; secret.len() - DIGEST_LEN];
+    rng.fill_bytes(&mut random_part);
+    let mut digest = digest(&random_part, secret).to_vec();
+    digest.extend_from_slice(&random_part);
+    points.push((DIGEST_INDEX, digest));
+    points.push((SECRET_INDEX, secret.to_vec()));
+
+    let mut shares: Vec<Vec<u8>> = points[..usize::from(threshold - 2)]
+        .iter()
+        .map(|(_, value)| value.clone())
+        .collect();
+    shares.extend((threshold - 2..count).map(|x| interpolate(&points, x)));
+    shares
+}
+
+/// Digest of the shared secret: `HMAC-SHA256(key = random_part, msg = secret)[..4]`
+fn digest(random_part: &[u8], secret: &[u8]) -> [u8; DIGEST_LEN] {
+    let mac = hmac::Hmac::<sha2::Sha256>::new_from_slice(random_part)
+        .expect("this never fails: hmac can handle keys of any size")
+        .chain_update(secret)
+        .finalize()
+        .into_bytes();
+    mac[..DIGEST_LEN]
+        .try_into()
+        .expect("this never fails: exactly 4 bytes")
+}
+
+/// Evaluates at `x` the polynomial over GF(256) passing through the `points`
+fn interpolate(points: &[(u8, impl AsRef<[u8]>)], x: u8) -> Vec<u8> {
+    if let Some((_, y)) = points.iter().find(|(xi, _)| *xi == x) {
+        return y.as_ref().to_vec();
+    }
+    let (log, exp) = gf256_tables();
+    let len = points[0].1.as_ref().len();
+    let mut result = alloc::vec![0u8; len];
+
+    // Lagrange basis polynomials are evaluated in log form:
+    // log(l_i(x)) = sum_{j != i} log(x - x_j) - log(x_i - x_j)
+    let log_prod = points
+        .iter()
+        .map(|(xi, _)| u32::from(log[usize::from(xi ^ x)]))
+        .sum::<u32>();
+    for (i, (xi, yi)) in points.iter().enumerate() {
+        let log_denominator = points
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .map(|(_, (xj, _))| u32::from(log[usize::from(xi ^ xj)]))
+            .sum::<u32>();
+        let log_basis =
+            (log_prod + 255 * 16 - u32::from(log[usize::from(xi ^ x)]) - log_denominator) % 255;
+        for (r, y) in result.iter_mut().zip(yi.as_ref()) {
+            if *y != 0 {
+                *r ^= exp[((u32::from(log[usize::from(*y)]) + log_basis) % 255) as usize];
+            }
+        }
+    }
+    result
+}
+
+/// Log and exp tables of GF(256) with Rijndael polynomial $x^8 + x^4 + x^3 + x + 1$ and
+/// generator $x + 1$
+fn gf256_tables() -> ([u8; 256], [u8; 255]) {
+    let mut log = [0u8; 256];
+    let mut exp = [0u8; 255];
+    let mut poly = 1u16;
+    for (i, exp) in exp.iter_mut().enumerate() {
+        *exp = poly as u8;
+        log[usize::from(poly)] = i as u8;
+        // Multiply by x + 1
+        poly = (poly << 1) ^ poly;
+        if poly & 0x100 != 0 {
+            poly ^= 0x11b;
+        }
+    }
+    (log, exp)
+}
+
+/// Customization string of the RS1024 checksum
+fn customization_string(extendable: bool) -> &'static [u8] {
+    if extendable {
+        b"shamir_extendable"
+    } else {
+        b"shamir"
+    }
+}
+
+fn rs1024_polymod(values: impl IntoIterator<Item = u16>) -> u32 {
+    const GEN: [u32; 10] = [
+        0xe0e040, 0x1c1c080, 0x3838100, 0x7070200, 0xe0e0009, 0x1c0c2412, 0x38086c24, 0x3090fc48,
+        0x21b1f890, 0x3f3f120,
+    ];
+    let mut chk = 1u32;
+    for v in values {
+        let b = chk >> 20;
+        chk = ((chk & 0xfffff) << 10) ^ u32::from(v);
+        for (i, gen) in GEN.iter().enumerate() {
+            if (b >> i) & 1 == 1 {
+                chk ^= gen;
+            }
+        }
+    }
+    chk
+}
+
+fn rs1024_create_checksum(customization: &[u8], data: &[u16]) -> [u16; CHECKSUM_WORDS] {
+    let values = customization
+        .iter()
+        .map(|c| u16::from(*c))
+        .chain(data.iter().copied())
+        .chain([0; CHECKSUM_WORDS]);
+    let polymod = rs1024_polymod(values) ^ 1;
+    [2, 1, 0].map(|i| ((polymod >> (10 * i)) & 1023) as u16)
+}
+
+fn rs1024_verify_checksum(customization: &[u8], data: &[u16]) -> bool {
+    let values = customization
+        .iter()
+        .map(|c| u16::from(*c))
+        .chain(data.iter().copied());
+    rs1024_polymod(values) == 1
+}
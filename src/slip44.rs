@@ -0,0 +1,117 @@
+//! Registered [SLIP44][slip44] coin types
+//!
+//! Coin type is the second level of [BIP44](crate::bip44) path. [`CoinType`] lists well-known
+//! registered coin types, and converts into a [`HardenedIndex`], so they don't need to be
+//! hardcoded as magic numbers.
+//!
+//! ### Example
+//! ```rust
+//! use slip_10::bip44::{Bip44Path, Change, Purpose};
+//! use slip_10::{HardenedIndex, NonHardenedIndex};
+//! use slip_10::slip44::CoinType;
+//!
+//! let path = Bip44Path::new(Purpose::Bip44, CoinType::Ethereum.into(), HardenedIndex::new(0))
+//!     .change(Change::External)
+//!     .index(NonHardenedIndex::new(0))
+//!     .to_path();
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [slip44]: https://github.com/satoshilabs/slips/blob/master/slip-0044.md
+
+use crate::{HardenedIndex, H};
+
+/// Coin type registered in SLIP44
+///
+/// Only covers a subset of the registry. Any other coin type can be used by constructing
+/// [`HardenedIndex`] directly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CoinType {
+    /// Bitcoin: 0
+    Bitcoin,
+    /// Testnet of all coins: 1
+    Testnet,
+    /// Litecoin: 2
+    Litecoin,
+    /// Dogecoin: 3
+    Dogecoin,
+    /// Dash: 5
+    Dash,
+    /// Ethereum: 60
+    Ethereum,
+    /// Ethereum Classic: 61
+    EthereumClassic,
+    /// Cosmos: 118
+    Cosmos,
+    /// Monero: 128
+    Monero,
+    /// Zcash: 133
+    Zcash,
+    /// XRP: 144
+    Ripple,
+    /// Bitcoin Cash: 145
+    BitcoinCash,
+    /// Stellar: 148
+    Stellar,
+    /// Tron: 195
+    Tron,
+    /// Algorand: 283
+    Algorand,
+    /// Polkadot: 354
+    Polkadot,
+    /// NEAR: 397
+    Near,
+    /// Kusama: 434
+    Kusama,
+    /// Solana: 501
+    Solana,
+    /// Aptos: 637
+    Aptos,
+    /// Sui: 784
+    Sui,
+    /// Tezos: 1729
+    Tezos,
+    /// Cardano: 1815
+    Cardano,
+    /// Avalanche: 9000
+    Avalanche,
+}
+
+impl CoinType {
+    /// Returns registered number of the coin type, without hardened offset
+    pub const fn number(self) -> u32 {
+        match self {
+            Self::Bitcoin => 0,
+            Self::Testnet => 1,
+            Self::Litecoin => 2,
+            Self::Dogecoin => 3,
+            Self::Dash => 5,
+            Self::Ethereum => 60,
+            Self::EthereumClassic => 61,
+            Self::Cosmos => 118,
+            Self::Monero => 128,
+            Self::Zcash => 133,
+            Self::Ripple => 144,
+            Self::BitcoinCash => 145,
+            Self::Stellar => 148,
+            Self::Tron => 195,
+            Self::Algorand => 283,
+            Self::Polkadot => 354,
+            Self::Near => 397,
+            Self::Kusama => 434,
+            Self::Solana => 501,
+            Self::Aptos => 637,
+            Self::Sui => 784,
+            Self::Tezos => 1729,
+            Self::Cardano => 1815,
+            Self::Avalanche => 9000,
+        }
+    }
+}
+
+impl From<CoinType> for HardenedIndex {
+    fn from(coin_type: CoinType) -> Self {
+        HardenedIndex(H + coin_type.number())
+    }
+}
@@ -0,0 +1,105 @@
+//! Solana key derivation
+//!
+//! Solana wallets (Phantom, Solflare, Solana CLI) derive ed25519 keys with SLIP10 at
+//! m/44<sub>H</sub>/501<sub>H</sub>/account<sub>H</sub>/0<sub>H</sub>. All levels are hardened,
+//! as only hardened derivation is defined for ed25519.
+//!
+//! ### Example
+//! ```rust
+//! use slip_10::{solana, HardenedIndex};
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let keypair = solana::derive_keypair(&seed, HardenedIndex::new(0));
+//! // 64 bytes `secret_key || public_key`, as stored by Solana CLI
+//! let bytes = keypair.to_bytes();
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+use crate::{ed25519, HardenedIndex, Seed, H};
+
+/// Coin type of Solana: 501<sub>H</sub>
+pub const COIN_TYPE: HardenedIndex = HardenedIndex(H + 501);
+
+/// Solana keypair
+///
+/// `Debug` output doesn't include the secret key unless `insecure-debug` feature is enabled.
+#[derive(Clone)]
+pub struct Keypair {
+    /// Ed25519 secret key
+    pub secret_key: [u8; 32],
+    /// Ed25519 public key, which is also the account address
+    pub public_key: [u8; 32],
+}
+
+impl Keypair {
+    /// Returns 64 bytes `secret_key || public_key`
+    ///
+    /// It's a keypair format used by Solana CLI and `solana-sdk`.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.secret_key);
+        bytes[32..].copy_from_slice(&self.public_key);
+        bytes
+    }
+}
+
+impl core::fmt::Debug for Keypair {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Keypair")
+            .field(
+                "secret_key",
+                &crate::DebugSecret("SecretKey", &self.secret_key[..]),
+            )
+            .field("public_key", &self.public_key)
+            .finish()
+    }
+}
+
+impl From<&ed25519::ExtendedKeyPair> for Keypair {
+    fn from(key_pair: &ed25519::ExtendedKeyPair) -> Self {
+        let public_key = key_pair.public_key().public_key.to_bytes(true);
+        Self {
+            secret_key: key_pair.secret_key().secret_key,
+            public_key: public_key
+                .as_bytes()
+                .try_into()
+                .expect("this never fails: ed25519 point is 32 bytes long"),
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for Keypair {
+    fn zeroize(&mut self) {
+        self.secret_key.zeroize()
+    }
+}
+
+/// Returns derivation path m/44<sub>H</sub>/501<sub>H</sub>/account<sub>H</sub>/0<sub>H</sub>
+pub fn path(account: HardenedIndex) -> [HardenedIndex; 4] {
+    [HardenedIndex(H + 44), COIN_TYPE, account, HardenedIndex(H)]
+}
+
+/// Derives Solana keypair of the account from the seed
+pub fn derive_keypair(seed: impl AsRef<Seed>, account: HardenedIndex) -> Keypair {
+    let master_key = ed25519::ExtendedKeyPair::from(ed25519::derive_master_key(seed));
+    let key_pair = ed25519::derive_child_key_pair_with_path(&master_key, path(account));
+    Keypair::from(&key_pair)
+}
+
+/// Derives Solana keypair of the account from BIP39 mnemonic
+///
+/// Seed is computed from the mnemonic and the passphrase as in
+/// [`derive_master_key_from_mnemonic`](crate::derive_master_key_from_mnemonic). Use an empty
+/// string if there's no passphrase.
+#[cfg(feature = "bip39")]
+pub fn derive_keypair_from_mnemonic(
+    mnemonic: &bip39::Mnemonic,
+    passphrase: &str,
+    account: HardenedIndex,
+) -> Keypair {
+    derive_keypair(Seed::from(mnemonic.to_seed_normalized(passphrase)), account)
+}
@@ -0,0 +1,120 @@
+//! [SEP-0005][sep5] Stellar key derivation
+//!
+//! Stellar accounts are ed25519 keys derived with SLIP10 at m/44<sub>H</sub>/148<sub>H</sub>/account<sub>H</sub>.
+//! Only hardened derivation is defined for ed25519, so the path is made of [`HardenedIndex`]es
+//! only. Derived keys can be encoded as [StrKey][strkey]: `G...` account IDs and `S...` secret
+//! seeds.
+//!
+//! ### Example
+//! ```rust
+//! use slip_10::{stellar, HardenedIndex};
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let key_pair = stellar::derive_key_pair(&seed, HardenedIndex::new(0));
+//! let account_id = stellar::encode_public_key(key_pair.public_key()).to_string();
+//! assert!(account_id.starts_with('G'));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [sep5]: https://github.com/stellar/stellar-protocol/blob/master/ecosystem/sep-0005.md
+//! [strkey]: https://github.com/stellar/stellar-protocol/blob/master/ecosystem/sep-0023.md
+
+use core::fmt;
+
+use crate::{ed25519, HardenedIndex, Seed, H};
+
+/// Coin type of Stellar: 148<sub>H</sub>
+pub const COIN_TYPE: HardenedIndex = HardenedIndex(H + 148);
+
+/// StrKey version byte of account IDs (`G...`)
+const VERSION_ACCOUNT_ID: u8 = 6 << 3;
+/// StrKey version byte of secret seeds (`S...`)
+const VERSION_SEED: u8 = 18 << 3;
+/// Size of StrKey payload: version byte, key, and 2 bytes checksum
+const STRKEY_LEN: usize = 1 + 32 + 2;
+
+/// Returns derivation path m/44<sub>H</sub>/148<sub>H</sub>/account<sub>H</sub>
+pub fn path(account: HardenedIndex) -> [HardenedIndex; 3] {
+    [HardenedIndex(H + 44), COIN_TYPE, account]
+}
+
+/// Derives Stellar key pair of the account from the seed
+pub fn derive_key_pair(seed: impl AsRef<Seed>, account: HardenedIndex) -> ed25519::ExtendedKeyPair {
+    let master_key = ed25519::ExtendedKeyPair::from(ed25519::derive_master_key(seed));
+    ed25519::derive_child_key_pair_with_path(&master_key, path(account))
+}
+
+/// Derives Stellar key pair of the account from BIP39 mnemonic
+///
+/// Seed is computed from the mnemonic and the passphrase as in
+/// [`derive_master_key_from_mnemonic`](crate::derive_master_key_from_mnemonic). Use an empty
+/// string if there's no passphrase.
+#[cfg(feature = "bip39")]
+pub fn derive_key_pair_from_mnemonic(
+    mnemonic: &bip39::Mnemonic,
+    passphrase: &str,
+    account: HardenedIndex,
+) -> ed25519::ExtendedKeyPair {
+    derive_key_pair(Seed::from(mnemonic.to_seed_normalized(passphrase)), account)
+}
+
+/// Encodes the public key as StrKey account ID `G...`
+pub fn encode_public_key(public_key: &ed25519::ExtendedPublicKey) -> impl fmt::Display {
+    let public_key = public_key.public_key.to_bytes(true);
+    StrKey::new(
+        VERSION_ACCOUNT_ID,
+        public_key
+            .as_bytes()
+            .try_into()
+            .expect("this never fails: ed25519 point is 32 bytes long"),
+    )
+}
+
+/// Encodes the secret key as StrKey secret seed `S...`
+pub fn encode_secret_key(secret_key: &ed25519::ExtendedSecretKey) -> impl fmt::Display {
+    StrKey::new(VERSION_SEED, &secret_key.secret_key)
+}
+
+/// StrKey payload displayed as base32 string
+struct StrKey([u8; STRKEY_LEN]);
+
+impl StrKey {
+    fn new(version: u8, key: &[u8; 32]) -> Self {
+        let mut payload = [0u8; STRKEY_LEN];
+        payload[0] = version;
+        payload[1..33].copy_from_slice(key);
+        let checksum = crc16_xmodem(&payload[..33]);
+        payload[33..].copy_from_slice(&checksum.to_le_bytes());
+        Self(payload)
+    }
+}
+
+impl fmt::Display for StrKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+        // 35 bytes are exactly 56 base32 characters, no padding is needed
+        self.0.chunks_exact(5).try_for_each(|chunk| {
+            let bits = chunk
+                .iter()
+                .fold(0u64, |acc, byte| (acc << 8) | u64::from(*byte));
+            (0..8).rev().try_for_each(|i| {
+                let c = ALPHABET[((bits >> (5 * i)) & 31) as usize];
+                fmt::Write::write_char(f, char::from(c))
+            })
+        })
+    }
+}
+
+fn crc16_xmodem(data: &[u8]) -> u16 {
+    data.iter().fold(0u16, |mut crc, byte| {
+        crc ^= u16::from(*byte) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+        crc
+    })
+}
@@ -0,0 +1,62 @@
+//! [proptest] strategies generating SLIP-10 types
+//!
+//! Ready-made strategies for property-testing code that consumes child indexes, derivation paths,
+//! and seeds. Generated values shrink towards the simplest ones: smaller indexes, shorter paths,
+//! and zero-filled seeds of minimal length.
+//!
+//! Generated seeds aren't random, only use them in tests.
+//!
+//! ### Example
+//! ```rust
+//! use proptest::prelude::*;
+//! use slip_10::{strategies, supported_curves::Secp256k1};
+//!
+//! proptest!(|(seed in strategies::any_seed(), path in strategies::any_path(5))| {
+//!     let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//!     let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+//!     let child_key = slip_10::derive_child_key_pair_with_path(&master_key_pair, &path);
+//!     // Empty path corresponds to the master key itself
+//!     prop_assume!(!path.is_empty());
+//!     prop_assert_ne!(child_key.public_key(), master_key_pair.public_key());
+//! });
+//! ```
+//!
+//! [proptest]: https://docs.rs/proptest
+
+use proptest::{collection, strategy::Strategy};
+
+use crate::{ChildIndex, DerivationPath, HardenedIndex, NonHardenedIndex, Seed, H};
+
+/// Generates any child index, hardened or non-hardened
+pub fn any_child_index() -> impl Strategy<Value = ChildIndex> {
+    proptest::prelude::any::<u32>().prop_map(ChildIndex::from)
+}
+
+/// Generates any hardened index
+pub fn any_hardened_index() -> impl Strategy<Value = HardenedIndex> {
+    (0..H).prop_map(HardenedIndex::new)
+}
+
+/// Generates any non-hardened index
+pub fn any_non_hardened_index() -> impl Strategy<Value = NonHardenedIndex> {
+    (0..H).prop_map(NonHardenedIndex::new)
+}
+
+/// Generates a derivation path containing at most `max_depth` child indexes
+pub fn any_path(max_depth: usize) -> impl Strategy<Value = DerivationPath> {
+    collection::vec(any_child_index(), 0..=max_depth).prop_map(|indexes| {
+        let mut path = DerivationPath::new();
+        path.extend(indexes)
+            .expect("this never fails: path is growable with `std` feature");
+        path
+    })
+}
+
+/// Generates a seed of 16-64 bytes
+pub fn any_seed() -> impl Strategy<Value = Seed> {
+    collection::vec(
+        proptest::prelude::any::<u8>(),
+        Seed::MIN_LEN..=Seed::MAX_LEN,
+    )
+    .prop_map(|bytes| Seed::new(&bytes).expect("this never fails: length is within the bounds"))
+}
@@ -0,0 +1,105 @@
+//! Taproot key-path tweak of secp256k1 keys
+//!
+//! [BIP341][bip341] never puts a derived key into an output directly: output key is the
+//! internal key $P$ tweaked as $Q = P + tG$, where $t = H_{TapTweak}(x(P))$ when there's no script
+//! tree, as recommended by [BIP86][bip86] for single-key wallets. [`taproot_output_key`] computes
+//! $Q$ from derived public key, and [`taproot_tweak_secret_key`] computes matching secret key
+//! which signs for $Q$ in key-path spends.
+//!
+//! ### Example
+//! ```rust
+//! use generic_ec::Point;
+//! use slip_10::{supported_curves::Secp256k1, taproot};
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//! let master_key = slip_10::ExtendedKeyPair::from(master_key);
+//! let key = slip_10::derive_child_key_pair_with_path(&master_key, slip_10::path!("m/86'/0'/0'/0/0"));
+//!
+//! let (output_key, parity) = taproot::taproot_output_key(key.public_key());
+//! let output_secret_key = taproot::taproot_tweak_secret_key(key.secret_key());
+//!
+//! let expected = Point::generator() * &output_secret_key;
+//! assert_eq!(expected.to_bytes(true)[1..], output_key);
+//! assert_eq!(expected.to_bytes(true)[0] == 0x03, parity == taproot::Parity::Odd);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [bip341]: https://github.com/bitcoin/bips/blob/master/bip-0341.mediawiki
+//! [bip86]: https://github.com/bitcoin/bips/blob/master/bip-0086.mediawiki
+
+use generic_ec::{Point, Scalar, SecretScalar};
+use sha2::Digest as _;
+
+use crate::{supported_curves::Secp256k1, ExtendedPublicKey, ExtendedSecretKey};
+
+/// Parity of y coordinate of the point
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Parity {
+    /// Even y coordinate
+    Even,
+    /// Odd y coordinate
+    Odd,
+}
+
+/// Returns x-only taproot output key and parity of its y coordinate
+///
+/// Derived public key is used as internal key, output key commits to an empty script tree.
+pub fn taproot_output_key(public_key: &ExtendedPublicKey<Secp256k1>) -> ([u8; 32], Parity) {
+    let (internal_key, _) = lift_x(&public_key.public_key);
+    let output_key = internal_key + Point::generator() * tweak(&internal_key);
+    let (output_key, parity) = x_only(&output_key);
+    (output_key, parity)
+}
+
+/// Returns secret key of taproot output key
+///
+/// Output public key of the returned secret key is [`taproot_output_key`]. Note that the output
+/// key may have odd y coordinate, in which case BIP340 signer negates the secret key as usual.
+pub fn taproot_tweak_secret_key(
+    secret_key: &ExtendedSecretKey<Secp256k1>,
+) -> SecretScalar<Secp256k1> {
+    let public_key = Point::generator() * &secret_key.secret_key;
+    let (internal_key, parity) = lift_x(&public_key);
+    let mut output_secret_key = match parity {
+        Parity::Even => secret_key.secret_key.as_ref() + tweak(&internal_key),
+        Parity::Odd => -secret_key.secret_key.as_ref() + tweak(&internal_key),
+    };
+    SecretScalar::new(&mut output_secret_key)
+}
+
+/// Negates the point if it has odd y coordinate
+///
+/// Returns the point with even y coordinate and parity of original point
+fn lift_x(point: &Point<Secp256k1>) -> (Point<Secp256k1>, Parity) {
+    match x_only(point).1 {
+        Parity::Even => (*point, Parity::Even),
+        Parity::Odd => (-point, Parity::Odd),
+    }
+}
+
+/// Returns x coordinate of the point and parity of its y coordinate
+fn x_only(point: &Point<Secp256k1>) -> ([u8; 32], Parity) {
+    let bytes = point.to_bytes(true);
+    let parity = if bytes[0] == 0x03 {
+        Parity::Odd
+    } else {
+        Parity::Even
+    };
+    let x = bytes[1..]
+        .try_into()
+        .expect("this never fails: compressed point is 33 bytes long");
+    (x, parity)
+}
+
+/// Computes $t = H_{TapTweak}(x(P))$
+fn tweak(internal_key: &Point<Secp256k1>) -> Scalar<Secp256k1> {
+    let tag = sha2::Sha256::digest(b"TapTweak");
+    let tweak = sha2::Sha256::new()
+        .chain_update(tag)
+        .chain_update(tag)
+        .chain_update(x_only(internal_key).0)
+        .finalize();
+    Scalar::from_be_bytes(tweak)
+        .expect("this never fails: tweak exceeds curve order with negligible probability")
+}
@@ -0,0 +1,103 @@
+//! Derivation of threshold key shares
+//!
+//! In threshold signing (FROST, GG18/CGGMP, etc.) no one holds the secret key: each signer holds
+//! a share of it, and the group public key is known to everyone. Pairing the group public key
+//! with a chain code makes it a group extended public key, and child keys are derived from it
+//! the non-hardened way. This module has the glue code which turns a share of the group secret
+//! key into a share of the child secret key ([`derive_share`]), and adjusts public shares of
+//! other signers ([`derive_public_share`]), which are needed to verify their partial
+//! signatures.
+//!
+//! The result depends on how the key is shared, see [`SecretSharing`].
+//!
+//! ### Example
+//! ```rust
+//! use generic_ec::{Point, Scalar, SecretScalar};
+//! use slip_10::{supported_curves::Secp256k1, threshold, ChainCode, SecretSharing};
+//!
+//! // 2-out-of-3 Shamir sharing of secret key: f(x) = 42 + 5x
+//! let f = |x: u64| Scalar::<Secp256k1>::from(42) + Scalar::from(5) * Scalar::from(x);
+//! let shares = [1, 2, 3].map(|i| SecretScalar::new(&mut f(i)));
+//! let public_shares = [1, 2, 3].map(|i| Point::generator() * f(i));
+//!
+//! let group_key = slip_10::ExtendedPublicKey {
+//!     public_key: Point::generator() * f(0),
+//!     chain_code: ChainCode::new([0xab; 32]),
+//! };
+//! let path = [1.try_into()?, 10.try_into()?];
+//!
+//! // Signer 1 derives its share of the child key
+//! let child = threshold::derive_share(&group_key, path, &shares[0], SecretSharing::Polynomial);
+//! assert_eq!(
+//!     child.group_key,
+//!     slip_10::derive_child_public_key_with_path(&group_key, path),
+//! );
+//! // and public share of signer 2
+//! let child_public_share2 =
+//!     threshold::derive_public_share(&group_key, path, &public_shares[1], SecretSharing::Polynomial);
+//! # let _ = child_public_share2;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use generic_ec::{Curve, Point, SecretScalar};
+
+use crate::{ExtendedPublicKey, NonHardenedIndex, SecretSharing};
+
+/// Share of the child secret key along with the child group key
+#[derive(Clone)]
+pub struct DerivedShare<E: Curve> {
+    /// Share of the child secret key
+    pub secret_share: SecretScalar<E>,
+    /// Child group extended public key
+    pub group_key: ExtendedPublicKey<E>,
+}
+
+impl<E: Curve> core::fmt::Debug for DerivedShare<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DerivedShare")
+            .field(
+                "secret_share",
+                &crate::DebugSecret("SecretScalar", self.secret_share.as_ref()),
+            )
+            .field("group_key", &self.group_key)
+            .finish()
+    }
+}
+
+/// Derives a share of the child secret key at the path
+///
+/// Takes group extended public key, non-hardened derivation path, and a share of the group
+/// secret key held by the signer. Returns the share of the child secret key and the child group
+/// extended public key.
+pub fn derive_share<E: Curve>(
+    group_key: &ExtendedPublicKey<E>,
+    path: impl IntoIterator<Item = NonHardenedIndex>,
+    secret_share: &SecretScalar<E>,
+    sharing: SecretSharing,
+) -> DerivedShare<E> {
+    let shift = crate::derive_path_shift(group_key, path);
+    let (secret_share, _) = shift.apply_to_share(secret_share, sharing);
+    DerivedShare {
+        secret_share,
+        group_key: shift.child_public_key,
+    }
+}
+
+/// Derives a public share of the child key at the path
+///
+/// Takes the public share of a signer, i.e. `G * secret_share`, and returns public share
+/// corresponding to the share returned by [`derive_share`] for the same signer.
+pub fn derive_public_share<E: Curve>(
+    group_key: &ExtendedPublicKey<E>,
+    path: impl IntoIterator<Item = NonHardenedIndex>,
+    public_share: &Point<E>,
+    sharing: SecretSharing,
+) -> Point<E> {
+    let shift = crate::derive_path_shift(group_key, path);
+    match sharing {
+        SecretSharing::Polynomial | SecretSharing::Additive { designated: true } => {
+            public_share + Point::generator() * shift.shift
+        }
+        SecretSharing::Additive { designated: false } => *public_share,
+    }
+}
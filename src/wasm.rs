@@ -0,0 +1,106 @@
+//! JavaScript bindings via [wasm-bindgen]
+//!
+//! Exposes a small API for browser wallets, so they derive keys with exactly the same code as
+//! Rust services. [`HdKey`] is an opaque handle to a secp256k1 BIP32 extended key: the secret key
+//! never leaves the WASM heap, only public data (xpub, public key, chain code) is exported to
+//! JavaScript.
+//!
+//! ### Example
+//! ```js
+//! import { HdKey } from "slip-10";
+//!
+//! const master = HdKey.fromSeed(seed);
+//! const account = master.derivePath("m/84'/0'/0'");
+//! console.log(account.xpub());
+//! // Secret key is erased from WASM memory when the handle is freed
+//! account.free();
+//! master.free();
+//! ```
+//!
+//! Functions that can fail throw a JavaScript `Error` with a message describing the failure.
+//!
+//! [wasm-bindgen]: https://github.com/rustwasm/wasm-bindgen
+
+use alloc::{string::String, vec::Vec};
+
+use wasm_bindgen::prelude::*;
+
+use crate::{bip32, supported_curves::Secp256k1, DerivationPath, Seed};
+
+/// Secp256k1 extended key pair along with its BIP32 metadata
+///
+/// Secret key is kept inside the WASM heap. It's stored as
+/// [`SecretScalar`](generic_ec::SecretScalar), which erases itself when the handle is freed
+/// regardless of `zeroize` feature. Chain code is not erased.
+#[wasm_bindgen]
+pub struct HdKey(bip32::Bip32ExtendedKey<Secp256k1>);
+
+#[wasm_bindgen]
+impl HdKey {
+    /// Derives a master key from the seed
+    ///
+    /// Throws if the seed is not 16-64 bytes long.
+    #[wasm_bindgen(js_name = fromSeed)]
+    pub fn from_seed(seed: &[u8]) -> Result<HdKey, JsError> {
+        let seed = Seed::new(seed)?;
+        let master_key = crate::derive_master_key::<Secp256k1>(&seed);
+        Ok(Self(bip32::Bip32ExtendedKey::master(master_key)))
+    }
+
+    /// Derives a child key with specified derivation path, e.g. `m/44'/0'/0'/0/0`
+    ///
+    /// Path is relative to this key. Throws if the path is malformed or the resulting key
+    /// exceeds maximum depth.
+    #[wasm_bindgen(js_name = derivePath)]
+    pub fn derive_path(&self, path: &str) -> Result<HdKey, JsError> {
+        let path: DerivationPath = path.parse()?;
+        Ok(Self(self.0.derive_child_with_path(&path)?))
+    }
+
+    /// Returns `xpub...` string
+    pub fn xpub(&self) -> String {
+        self.0.to_xpub().to_string()
+    }
+
+    /// Returns 78 bytes BIP32 serialization of the extended public key
+    #[wasm_bindgen(js_name = xpubBytes)]
+    pub fn xpub_bytes(&self) -> Vec<u8> {
+        self.0.to_xpub().to_bytes().to_vec()
+    }
+
+    /// Returns 33 bytes compressed public key
+    #[wasm_bindgen(js_name = publicKey)]
+    pub fn public_key(&self) -> Vec<u8> {
+        self.0.key().public_key().public_key.to_bytes(true).to_vec()
+    }
+
+    /// Returns 32 bytes chain code
+    #[wasm_bindgen(js_name = chainCode)]
+    pub fn chain_code(&self) -> Vec<u8> {
+        self.0.key().chain_code().to_bytes().to_vec()
+    }
+
+    /// Returns 4 bytes fingerprint of the key
+    pub fn fingerprint(&self) -> Vec<u8> {
+        self.0.key().public_key().fingerprint().to_vec()
+    }
+
+    /// Returns depth of the key, 0 for master key
+    #[wasm_bindgen(getter)]
+    pub fn depth(&self) -> u8 {
+        *self.0.metadata().depth
+    }
+}
+
+impl HdKey {
+    /// Returns the underlying extended key
+    pub fn as_extended_key(&self) -> &bip32::Bip32ExtendedKey<Secp256k1> {
+        &self.0
+    }
+}
+
+impl From<bip32::Bip32ExtendedKey<Secp256k1>> for HdKey {
+    fn from(key: bip32::Bip32ExtendedKey<Secp256k1>) -> Self {
+        Self(key)
+    }
+}
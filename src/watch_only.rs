@@ -0,0 +1,314 @@
+//! Watch-only accounts
+//!
+//! [`WatchOnlyAccount`] bundles an account-level extended public key with its [`KeyOrigin`]:
+//! fingerprint of the master key and hardened path from the master key to the account. That's
+//! everything a watch-only wallet or a signing coordinator needs to derive receive and change
+//! keys, and to tell a signer which key to sign with.
+//!
+//! Account is serialized in the key expression format of [output descriptors][descriptors],
+//! e.g. `[d34db33f/44'/0'/0']xpub6ERApfZwUNrhL...`. Arbitrary key expressions found in
+//! descriptors and PSBTs can be parsed into [`KeyExpression`].
+//!
+//! ### Example
+//! ```rust
+//! use slip_10::{supported_curves::Secp256k1, watch_only::WatchOnlyAccount, NonHardenedIndex};
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//! let master_key = slip_10::ExtendedKeyPair::from(master_key);
+//!
+//! let account = WatchOnlyAccount::from_master_key(&master_key, "m/84'/0'/0'".parse()?)?;
+//! let encoded = account.to_string();
+//!
+//! // `encoded` can be shared with the watch-only wallet
+//! let account: WatchOnlyAccount<Secp256k1> = encoded.parse()?;
+//! let receive_key = account.receive_public_key(NonHardenedIndex::new(0));
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [descriptors]: https://github.com/bitcoin/bips/blob/master/bip-0380.mediawiki#key-expressions
+
+use core::fmt;
+
+use generic_ec::Curve;
+
+use crate::{
+    bip32::{Bip32ExtendedKey, Xpub},
+    bip44::Change,
+    errors, ChildIndex, DerivationPath, ExtendedKeyPair, ExtendedPublicKey, NonHardenedIndex,
+    SupportedCurve,
+};
+
+/// Origin of the account key: master key fingerprint and path from master key to the account
+#[derive(Clone, Debug)]
+pub struct KeyOrigin {
+    /// Fingerprint of the master key
+    pub master_fingerprint: [u8; 4],
+    /// Derivation path from master key to the account key, must contain only hardened indexes
+    pub path: DerivationPath,
+}
+
+/// Account-level extended public key along with its origin
+///
+/// Consistency of the origin and the key is validated on construction: origin path must contain
+/// only hardened indexes and match depth, child number, and parent fingerprint of the key.
+#[derive(Clone, Debug)]
+pub struct WatchOnlyAccount<E: Curve> {
+    origin: KeyOrigin,
+    xpub: Xpub<E>,
+}
+
+impl<E: Curve> WatchOnlyAccount<E> {
+    /// Constructs a watch-only account, validating that `origin` is consistent with `xpub`
+    pub fn new(origin: KeyOrigin, xpub: Xpub<E>) -> Result<Self, errors::InvalidAccount> {
+        if origin
+            .path
+            .iter()
+            .any(|i| matches!(i, ChildIndex::NonHardened(_)))
+        {
+            return Err(errors::InvalidAccount::NonHardenedOrigin);
+        }
+        if usize::from(*xpub.metadata.depth) != origin.path.len() {
+            return Err(errors::InvalidAccount::DepthMismatch);
+        }
+        match origin.path.as_slice() {
+            [] => {}
+            [.., last] if u32::from(*last) != u32::from(xpub.metadata.child_number) => {
+                return Err(errors::InvalidAccount::ChildNumberMismatch)
+            }
+            [_] if xpub.metadata.parent_fingerprint != origin.master_fingerprint => {
+                return Err(errors::InvalidAccount::FingerprintMismatch)
+            }
+            _ => {}
+        }
+        Ok(Self { origin, xpub })
+    }
+
+    /// Derives account key from the master key and constructs a watch-only account
+    ///
+    /// Returns an error if `account_path` contains a non-hardened index
+    pub fn from_master_key(
+        master_key: &ExtendedKeyPair<E>,
+        account_path: DerivationPath,
+    ) -> Result<Self, errors::InvalidAccount> {
+        let account_key = Bip32ExtendedKey::master(master_key.clone())
+            .derive_child_with_path(&account_path)
+            .map_err(|_| errors::InvalidAccount::DepthMismatch)?;
+        let origin = KeyOrigin {
+            master_fingerprint: master_key.public_key().fingerprint(),
+            path: account_path,
+        };
+        Self::new(origin, account_key.to_xpub())
+    }
+
+    /// Returns origin of the account key
+    pub fn origin(&self) -> &KeyOrigin {
+        &self.origin
+    }
+
+    /// Returns account extended public key along with its BIP32 metadata
+    pub fn xpub(&self) -> &Xpub<E> {
+        &self.xpub
+    }
+
+    /// Returns account extended public key
+    pub fn public_key(&self) -> &ExtendedPublicKey<E> {
+        &self.xpub.key
+    }
+
+    /// Derives a key `account / change / index`
+    pub fn derive_public_key(
+        &self,
+        change: Change,
+        index: NonHardenedIndex,
+    ) -> ExtendedPublicKey<E> {
+        crate::derive_child_public_key_with_path(&self.xpub.key, [change.into(), index])
+    }
+
+    /// Derives a receive key `account / 0 / index`
+    pub fn receive_public_key(&self, index: NonHardenedIndex) -> ExtendedPublicKey<E> {
+        self.derive_public_key(Change::External, index)
+    }
+
+    /// Derives a change key `account / 1 / index`
+    pub fn change_public_key(&self, index: NonHardenedIndex) -> ExtendedPublicKey<E> {
+        self.derive_public_key(Change::Internal, index)
+    }
+
+    /// Returns full path from the master key to the key `account / change / index`
+    ///
+    /// Returns an error if `alloc` feature is disabled and the path doesn't fit into
+    /// [`DerivationPath::MAX_LEN`]
+    pub fn key_path(
+        &self,
+        change: Change,
+        index: NonHardenedIndex,
+    ) -> Result<DerivationPath, errors::PathTooLong> {
+        let mut path = self.origin.path.clone();
+        path.push(NonHardenedIndex::from(change))?;
+        path.push(index)?;
+        Ok(path)
+    }
+}
+
+/// Extended public key along with its origin, as used in descriptors and PSBTs
+///
+/// Parsed from and displayed as a key expression `[d34db33f/44'/0'/0']xpub...`. Unlike
+/// [`WatchOnlyAccount`], it's not required to be an account key: origin path may contain
+/// non-hardened indexes, and origin is not validated against the key metadata.
+///
+/// ### Example
+/// ```rust
+/// use slip_10::{supported_curves::Secp256k1, watch_only::KeyExpression};
+///
+/// let key: KeyExpression<Secp256k1> = "[3442193e/0h]xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw".parse()?;
+/// assert_eq!(key.origin.master_fingerprint, [0x34, 0x42, 0x19, 0x3e]);
+/// assert_eq!(key.origin.path.to_string(), "m/0'");
+/// let public_key = key.xpub.key;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct KeyExpression<E: Curve> {
+    /// Origin of the key
+    pub origin: KeyOrigin,
+    /// Extended public key along with its BIP32 metadata
+    pub xpub: Xpub<E>,
+}
+
+impl<E: Curve> From<WatchOnlyAccount<E>> for KeyExpression<E> {
+    fn from(account: WatchOnlyAccount<E>) -> Self {
+        Self {
+            origin: account.origin,
+            xpub: account.xpub,
+        }
+    }
+}
+
+impl<E: Curve> TryFrom<KeyExpression<E>> for WatchOnlyAccount<E> {
+    type Error = errors::InvalidAccount;
+    fn try_from(key: KeyExpression<E>) -> Result<Self, Self::Error> {
+        Self::new(key.origin, key.xpub)
+    }
+}
+
+/// Displays the origin as `d34db33f/44'/0'/0'`
+impl fmt::Display for KeyOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.master_fingerprint
+            .iter()
+            .try_for_each(|byte| write!(f, "{byte:02x}"))?;
+        self.path
+            .iter()
+            .try_for_each(|child_index| write!(f, "/{child_index}"))
+    }
+}
+
+/// Parses the origin like `d34db33f/44'/0'/0'`, i.e. key expression origin without brackets
+impl core::str::FromStr for KeyOrigin {
+    type Err = errors::ParseKeyExpressionError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (master_fingerprint, path) = match s.split_once('/') {
+            Some((master_fingerprint, path)) => (
+                master_fingerprint,
+                path.parse()
+                    .map_err(errors::ParseKeyExpressionError::ParsePath)?,
+            ),
+            None => (s, DerivationPath::new()),
+        };
+        Ok(Self {
+            master_fingerprint: parse_fingerprint(master_fingerprint)
+                .ok_or(errors::ParseKeyExpressionError::InvalidFingerprint)?,
+            path,
+        })
+    }
+}
+
+/// Displays the key as `[d34db33f/44'/0'/0']xpub...`
+impl<E: Curve + SupportedCurve> fmt::Display for KeyExpression<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}]{}", self.origin, self.xpub)
+    }
+}
+
+impl<E: Curve + SupportedCurve> core::str::FromStr for KeyExpression<E> {
+    type Err = errors::ParseKeyExpressionError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (origin, xpub) = s
+            .strip_prefix('[')
+            .and_then(|s| s.split_once(']'))
+            .ok_or(errors::ParseKeyExpressionError::InvalidFormat)?;
+        Ok(Self {
+            origin: origin.parse()?,
+            xpub: xpub
+                .parse()
+                .map_err(errors::ParseKeyExpressionError::ParseXpub)?,
+        })
+    }
+}
+
+/// Displays the account as `[d34db33f/44'/0'/0']xpub...`
+impl<E: Curve + SupportedCurve> fmt::Display for WatchOnlyAccount<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}]{}", self.origin, self.xpub)
+    }
+}
+
+impl<E: Curve + SupportedCurve> core::str::FromStr for WatchOnlyAccount<E> {
+    type Err = errors::ParseWatchOnlyAccountError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let key: KeyExpression<E> = s
+            .parse()
+            .map_err(errors::ParseWatchOnlyAccountError::ParseKeyExpression)?;
+        key.try_into()
+            .map_err(errors::ParseWatchOnlyAccountError::InvalidAccount)
+    }
+}
+
+/// Serializes the account as a string `[d34db33f/44'/0'/0']xpub...`
+#[cfg(feature = "serde")]
+impl<E: Curve + SupportedCurve> serde::Serialize for WatchOnlyAccount<E> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, E: Curve + SupportedCurve> serde::Deserialize<'de> for WatchOnlyAccount<E> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de;
+
+        struct AccountVisitor<E>(core::marker::PhantomData<E>);
+        impl<E: Curve + SupportedCurve> de::Visitor<'_> for AccountVisitor<E> {
+            type Value = WatchOnlyAccount<E>;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("watch-only account")
+            }
+            fn visit_str<Err>(self, v: &str) -> Result<Self::Value, Err>
+            where
+                Err: de::Error,
+            {
+                v.parse().map_err(Err::custom)
+            }
+        }
+
+        deserializer.deserialize_str(AccountVisitor(core::marker::PhantomData))
+    }
+}
+
+/// Parses fingerprint from 8 hex digits
+fn parse_fingerprint(s: &str) -> Option<[u8; 4]> {
+    if s.len() != 8 || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let mut fingerprint = [0u8; 4];
+    for (byte, i) in fingerprint.iter_mut().zip((0..8).step_by(2)) {
+        *byte = u8::from_str_radix(&s[i..i + 2], 16).ok()?;
+    }
+    Some(fingerprint)
+}
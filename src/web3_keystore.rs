@@ -0,0 +1,348 @@
+//! [Web3 Secret Storage][web3-secret-storage] (keystore JSON v3) of Ethereum keys
+//!
+//! Keystore JSON is the format of encrypted keys used by geth, MetaMask, MyEtherWallet, and most
+//! other Ethereum wallets. [`encrypt`] exports a derived secret key as a [`Keystore`], which is
+//! serialized into the standard JSON via serde, and [`Keystore::decrypt`] imports it back.
+//!
+//! Both scrypt and PBKDF2-HMAC-SHA256 key derivation functions are supported at import. Keys are
+//! always encrypted with AES-128-CTR, which is the only cipher defined by the standard. Note that
+//! scrypt parameters must satisfy $N < 2^{16r}$ as required by RFC7914: some keystores in the
+//! wild (including the scrypt test vector of the spec) don't, and can't be imported.
+//!
+//! KDF parameters come from untrusted JSON, so they're bounded at import: scrypt is limited to
+//! [`ScryptParams::MAX_N`], [`ScryptParams::MAX_R`], and [`ScryptParams::MAX_P`], and PBKDF2 to
+//! [`Pbkdf2Params::MAX_C`] iterations. Keystores exceeding these bounds are rejected with
+//! [`InvalidKdfParams`](errors::Web3KeystoreError::InvalidKdfParams).
+//!
+//! ### Example
+//! ```rust
+//! use slip_10::{supported_curves::Secp256k1, web3_keystore};
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//! let master_key = slip_10::ExtendedKeyPair::from(master_key);
+//! let key = slip_10::derive_child_key_pair_with_path(&master_key, slip_10::path!("m/44'/60'/0'/0/0"));
+//!
+//! let mut rng = rand_core::OsRng;
+//! let keystore = web3_keystore::encrypt(&key.secret_key().secret_key, b"password", &mut rng);
+//! let json = serde_json::to_string(&keystore)?;
+//!
+//! let keystore: web3_keystore::Keystore = serde_json::from_str(&json)?;
+//! let secret_key = keystore.decrypt(b"password")?;
+//! assert_eq!(secret_key.as_ref(), key.secret_key().secret_key.as_ref());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [web3-secret-storage]: https://ethereum.org/en/developers/docs/data-structures-and-encoding/web3-secret-storage/
+
+use alloc::{string::String, vec::Vec};
+
+use aes::cipher::{KeyIvInit, StreamCipher};
+use generic_ec::{Point, Scalar, SecretScalar};
+use hmac::Mac as _;
+use rand_core::{CryptoRng, RngCore};
+use sha3::Digest as _;
+use subtle::ConstantTimeEq;
+
+use crate::{errors, serde_utils::Bytes, supported_curves::Secp256k1};
+
+/// Version of the format
+pub const VERSION: u32 = 3;
+
+/// Encrypted key
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Keystore {
+    /// Encrypted key and parameters of its encryption
+    #[serde(alias = "Crypto")]
+    pub crypto: Crypto,
+    /// Random UUID of the key
+    pub id: String,
+    /// Version of the format, must be [`VERSION`]
+    pub version: u32,
+    /// Ethereum address of the key, as lower case hex without `0x` prefix
+    ///
+    /// Not defined in the standard, but written by most wallets
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "serde_with::As::<Option<Bytes>>"
+    )]
+    pub address: Option<[u8; 20]>,
+}
+
+/// Encrypted key and parameters of its encryption
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Crypto {
+    /// Cipher
+    pub cipher: Cipher,
+    /// Parameters of the cipher
+    pub cipherparams: CipherParams,
+    /// Encrypted secret key
+    #[serde(with = "serde_with::As::<Bytes>")]
+    pub ciphertext: [u8; 32],
+    /// Key derivation function and its parameters
+    #[serde(flatten)]
+    pub kdf: Kdf,
+    /// `KECCAK256(derived_key[16..32] || ciphertext)`
+    #[serde(with = "serde_with::As::<Bytes>")]
+    pub mac: [u8; 32],
+}
+
+/// Cipher
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum Cipher {
+    /// AES-128 in CTR mode
+    #[serde(rename = "aes-128-ctr")]
+    Aes128Ctr,
+}
+
+/// Parameters of the cipher
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct CipherParams {
+    /// Initialization vector
+    #[serde(with = "serde_with::As::<Bytes>")]
+    pub iv: [u8; 16],
+}
+
+/// Key derivation function
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kdf", content = "kdfparams", rename_all = "lowercase")]
+pub enum Kdf {
+    /// scrypt
+    Scrypt(ScryptParams),
+    /// PBKDF2
+    Pbkdf2(Pbkdf2Params),
+}
+
+/// Parameters of scrypt
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ScryptParams {
+    /// Length of derived key
+    pub dklen: u32,
+    /// CPU/memory cost, must be a power of 2
+    pub n: u32,
+    /// Block size
+    pub r: u32,
+    /// Parallelization
+    pub p: u32,
+    /// Salt, must not be empty
+    #[serde(with = "serde_with::As::<Bytes>")]
+    pub salt: Vec<u8>,
+}
+
+impl ScryptParams {
+    /// The largest CPU/memory cost accepted at import, the one used by geth
+    pub const MAX_N: u32 = 1 << 18;
+    /// The largest block size accepted at import
+    pub const MAX_R: u32 = 8;
+    /// The largest parallelization accepted at import
+    pub const MAX_P: u32 = 16;
+
+    /// Generates parameters with random 32 bytes salt
+    ///
+    /// Parameters are the ones used by geth: $N = 2^{18}, r = 8, p = 1$
+    pub fn generate(rng: &mut (impl RngCore + CryptoRng)) -> Self {
+        let mut salt = alloc::vec![0u8; 32];
+        rng.fill_bytes(&mut salt);
+        Self {
+            dklen: 32,
+            n: Self::MAX_N,
+            r: 8,
+            p: 1,
+            salt,
+        }
+    }
+}
+
+/// Parameters of PBKDF2
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Pbkdf2Params {
+    /// Number of iterations
+    pub c: u32,
+    /// Length of derived key
+    pub dklen: u32,
+    /// Pseudo-random function
+    pub prf: Prf,
+    /// Salt, must not be empty
+    #[serde(with = "serde_with::As::<Bytes>")]
+    pub salt: Vec<u8>,
+}
+
+impl Pbkdf2Params {
+    /// The largest number of iterations accepted at import
+    pub const MAX_C: u32 = 10_000_000;
+}
+
+/// Pseudo-random function of PBKDF2
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum Prf {
+    /// HMAC-SHA256
+    #[serde(rename = "hmac-sha256")]
+    HmacSha256,
+}
+
+impl Kdf {
+    /// Derives 32 bytes key from the password
+    fn derive_key(&self, password: &[u8]) -> Result<[u8; 32], errors::Web3KeystoreError> {
+        let mut key = [0u8; 32];
+        match self {
+            Self::Scrypt(params) => {
+                if params.dklen != 32
+                    || params.salt.is_empty()
+                    || !params.n.is_power_of_two()
+                    || params.n > ScryptParams::MAX_N
+                    || params.r > ScryptParams::MAX_R
+                    || params.p > ScryptParams::MAX_P
+                {
+                    return Err(errors::Web3KeystoreError::InvalidKdfParams);
+                }
+                let log_n = params.n.trailing_zeros() as u8;
+                let scrypt_params = scrypt::Params::new(log_n, params.r, params.p, 32)
+                    .map_err(|_| errors::Web3KeystoreError::InvalidKdfParams)?;
+                scrypt::scrypt(password, &params.salt, &scrypt_params, &mut key)
+                    .map_err(|_| errors::Web3KeystoreError::InvalidKdfParams)?;
+            }
+            Self::Pbkdf2(params) => {
+                if params.dklen != 32
+                    || params.salt.is_empty()
+                    || params.c == 0
+                    || params.c > Pbkdf2Params::MAX_C
+                {
+                    return Err(errors::Web3KeystoreError::InvalidKdfParams);
+                }
+                pbkdf2_hmac_sha256(password, &params.salt, params.c, &mut key);
+            }
+        }
+        Ok(key)
+    }
+}
+
+/// Encrypts the secret key under the password
+///
+/// Uses scrypt with the parameters [generated](ScryptParams::generate) by `rng`. It takes about
+/// a second on a modern CPU.
+pub fn encrypt(
+    secret_key: &SecretScalar<Secp256k1>,
+    password: &[u8],
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Keystore {
+    let kdf = Kdf::Scrypt(ScryptParams::generate(rng));
+    encrypt_with_kdf(secret_key, password, kdf, rng)
+}
+
+/// Encrypts the secret key under the password using given key derivation function
+///
+/// ### Panics
+/// Panics if KDF parameters are invalid
+pub fn encrypt_with_kdf(
+    secret_key: &SecretScalar<Secp256k1>,
+    password: &[u8],
+    kdf: Kdf,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Keystore {
+    let derived_key = kdf.derive_key(password).expect("kdf params must be valid");
+    let mut iv = [0u8; 16];
+    rng.fill_bytes(&mut iv);
+
+    let mut ciphertext = [0u8; 32];
+    ciphertext.copy_from_slice(&secret_key.as_ref().to_be_bytes());
+    aes128_ctr(&derived_key[..16], &iv, &mut ciphertext);
+
+    Keystore {
+        crypto: Crypto {
+            cipher: Cipher::Aes128Ctr,
+            cipherparams: CipherParams { iv },
+            ciphertext,
+            kdf,
+            mac: mac(&derived_key, &ciphertext),
+        },
+        id: uuid_v4(rng),
+        version: VERSION,
+        address: Some(crate::ethereum::address_of(
+            &(Point::generator() * secret_key),
+        )),
+    }
+}
+
+impl Keystore {
+    /// Decrypts the secret key
+    ///
+    /// Returns error if password is wrong, or keystore is not supported
+    pub fn decrypt(
+        &self,
+        password: &[u8],
+    ) -> Result<SecretScalar<Secp256k1>, errors::Web3KeystoreError> {
+        if self.version != VERSION {
+            return Err(errors::Web3KeystoreError::UnsupportedVersion);
+        }
+        let derived_key = self.crypto.kdf.derive_key(password)?;
+        let expected_mac = mac(&derived_key, &self.crypto.ciphertext);
+        if !bool::from(expected_mac.ct_eq(&self.crypto.mac)) {
+            return Err(errors::Web3KeystoreError::WrongPassword);
+        }
+
+        let mut secret_key = self.crypto.ciphertext;
+        aes128_ctr(
+            &derived_key[..16],
+            &self.crypto.cipherparams.iv,
+            &mut secret_key,
+        );
+        let mut secret_key = Scalar::<Secp256k1>::from_be_bytes(secret_key)
+            .ok()
+            .filter(|sk| !bool::from(sk.ct_eq(&Scalar::zero())))
+            .ok_or(errors::Web3KeystoreError::InvalidKey)?;
+        Ok(SecretScalar::new(&mut secret_key))
+    }
+}
+
+fn mac(derived_key: &[u8; 32], ciphertext: &[u8; 32]) -> [u8; 32] {
+    sha3::Keccak256::new()
+        .chain_update(&derived_key[16..])
+        .chain_update(ciphertext)
+        .finalize()
+        .into()
+}
+
+fn aes128_ctr(key: &[u8], iv: &[u8; 16], data: &mut [u8]) {
+    let mut cipher = ctr::Ctr128BE::<aes::Aes128>::new(key.into(), iv.into());
+    cipher.apply_keystream(data);
+}
+
+/// Generates random UUID v4
+fn uuid_v4(rng: &mut (impl RngCore + CryptoRng)) -> String {
+    use core::fmt::Write as _;
+
+    let mut bytes = [0u8; 16];
+    rng.fill_bytes(&mut bytes);
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    let mut uuid = String::with_capacity(36);
+    for (i, byte) in bytes.iter().enumerate() {
+        if matches!(i, 4 | 6 | 8 | 10) {
+            uuid.push('-');
+        }
+        write!(uuid, "{byte:02x}").expect("this never fails: writing to string");
+    }
+    uuid
+}
+
+/// PBKDF2-HMAC-SHA256 as defined in [RFC8018](https://datatracker.ietf.org/doc/html/rfc8018)
+fn pbkdf2_hmac_sha256(password: &[u8], salt: &[u8], rounds: u32, out: &mut [u8]) {
+    let prf = hmac::Hmac::<sha2::Sha256>::new_from_slice(password)
+        .expect("this never fails: hmac can handle keys of any size");
+    for (i, chunk) in (1u32..).zip(out.chunks_mut(32)) {
+        let mut u = prf
+            .clone()
+            .chain_update(salt)
+            .chain_update(i.to_be_bytes())
+            .finalize()
+            .into_bytes();
+        let mut t = u;
+        for _ in 1..rounds {
+            u = prf.clone().chain_update(u).finalize().into_bytes();
+            t.iter_mut().zip(&u).for_each(|(t, u)| *t ^= u);
+        }
+        chunk.copy_from_slice(&t[..chunk.len()]);
+    }
+}
@@ -0,0 +1,124 @@
+//! [Wallet Import Format][wif] of secp256k1 secret keys
+//!
+//! WIF is used to export individual keys, e.g. to sweep funds of a derived key into other wallet
+//! software. Keys are always exported in compressed form, which is what derived keys use.
+//!
+//! ### Example
+//! ```rust
+//! use slip_10::{supported_curves::Secp256k1, wif};
+//!
+//! # let seed = slip_10::Seed::new(b"16-64 bytes of high entropy")?;
+//! let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+//! let master_key = slip_10::ExtendedKeyPair::from(master_key);
+//! let child_key = slip_10::derive_child_key_pair_with_path(&master_key, [1, 10]);
+//!
+//! let encoded = wif::encode(&child_key.secret_key().secret_key, wif::MAINNET).to_string();
+//! assert!(encoded.starts_with('K') || encoded.starts_with('L'));
+//!
+//! let decoded = wif::decode(&encoded)?;
+//! assert_eq!(decoded.version, wif::MAINNET);
+//! assert!(decoded.compressed);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [wif]: https://en.bitcoin.it/wiki/Wallet_import_format
+
+use core::fmt;
+
+use generic_ec::{Scalar, SecretScalar};
+use sha2::Digest as _;
+
+use crate::{errors, supported_curves::Secp256k1};
+
+/// Version byte of mainnet keys
+pub const MAINNET: u8 = 0x80;
+/// Version byte of testnet keys
+pub const TESTNET: u8 = 0xef;
+
+/// Size of WIF payload of compressed key: version, secret key, and compression flag
+const PAYLOAD_LEN: usize = 1 + 32 + 1;
+/// Size of Base58Check payload: payload followed by 4 bytes checksum
+const CHECKED_LEN: usize = PAYLOAD_LEN + 4;
+/// Upper bound of Base58 encoding length of [`CHECKED_LEN`] bytes
+const ENCODED_MAX_LEN: usize = 53;
+
+/// Secret key decoded from WIF
+pub struct Wif {
+    /// Secret key
+    pub secret_key: SecretScalar<Secp256k1>,
+    /// Version byte, e.g. [`MAINNET`] or [`TESTNET`]
+    pub version: u8,
+    /// Whether the key corresponds to compressed public key
+    pub compressed: bool,
+}
+
+/// Encodes the secret key in WIF with given version byte
+///
+/// Key is encoded in compressed form.
+pub fn encode(secret_key: &SecretScalar<Secp256k1>, version: u8) -> impl fmt::Display {
+    let mut payload = [0u8; PAYLOAD_LEN];
+    payload[0] = version;
+    payload[1..33].copy_from_slice(&secret_key.as_ref().to_be_bytes());
+    payload[33] = 0x01;
+    EncodedWif(payload)
+}
+
+/// Decodes the secret key from WIF
+///
+/// Both compressed and uncompressed forms are accepted.
+pub fn decode(s: &str) -> Result<Wif, errors::ParseWifError> {
+    let mut checked = [0u8; CHECKED_LEN];
+    let len = bs58::decode(s)
+        .onto(&mut checked)
+        .map_err(|err| match err {
+            bs58::decode::Error::BufferTooSmall => {
+                errors::ParseWifError::InvalidLength(errors::InvalidLength)
+            }
+            _ => errors::ParseWifError::Base58,
+        })?;
+    let (payload, expected_checksum) = match len.checked_sub(4) {
+        Some(payload_len @ (33 | 34)) => checked[..len].split_at(payload_len),
+        _ => return Err(errors::ParseWifError::InvalidLength(errors::InvalidLength)),
+    };
+    if checksum(payload) != expected_checksum {
+        return Err(errors::ParseWifError::InvalidChecksum);
+    }
+
+    let compressed = match payload.get(33) {
+        None => false,
+        Some(0x01) => true,
+        Some(_) => return Err(errors::ParseWifError::InvalidKey),
+    };
+    let mut secret_key = Scalar::<Secp256k1>::from_be_bytes(&payload[1..33])
+        .ok()
+        .filter(|sk| !bool::from(subtle::ConstantTimeEq::ct_eq(sk, &Scalar::zero())))
+        .ok_or(errors::ParseWifError::InvalidKey)?;
+    Ok(Wif {
+        secret_key: SecretScalar::new(&mut secret_key),
+        version: payload[0],
+        compressed,
+    })
+}
+
+/// Payload displayed as Base58Check string
+struct EncodedWif([u8; PAYLOAD_LEN]);
+
+impl fmt::Display for EncodedWif {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut checked = [0u8; CHECKED_LEN];
+        checked[..PAYLOAD_LEN].copy_from_slice(&self.0);
+        checked[PAYLOAD_LEN..].copy_from_slice(&checksum(&self.0));
+
+        let mut encoded = [0u8; ENCODED_MAX_LEN];
+        let len = bs58::encode(checked)
+            .onto(&mut encoded[..])
+            .map_err(|_| fmt::Error)?;
+        let encoded = core::str::from_utf8(&encoded[..len]).map_err(|_| fmt::Error)?;
+        f.write_str(encoded)
+    }
+}
+
+fn checksum(payload: &[u8]) -> [u8; 4] {
+    let hash = sha2::Sha256::digest(sha2::Sha256::digest(payload));
+    hash[..4].try_into().expect("exactly 4 bytes")
+}
@@ -0,0 +1,48 @@
+use slip_10::{address, bip39::Mnemonic, supported_curves::Secp256k1};
+
+fn master_key() -> slip_10::ExtendedKeyPair<Secp256k1> {
+    let mnemonic: Mnemonic = "abandon abandon abandon abandon abandon abandon \
+        abandon abandon abandon abandon abandon about"
+        .parse()
+        .unwrap();
+    slip_10::derive_master_key_from_mnemonic::<Secp256k1>(&mnemonic, "").into()
+}
+
+/// Test vectors defined in BIP44, BIP84, and BIP86
+#[test]
+fn addresses() {
+    let master_key = master_key();
+
+    let key =
+        slip_10::derive_child_key_pair_with_path(&master_key, slip_10::path!("m/44'/0'/0'/0/0"));
+    assert_eq!(
+        address::p2pkh(key.public_key(), address::Network::Bitcoin).to_string(),
+        "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA"
+    );
+
+    let key =
+        slip_10::derive_child_key_pair_with_path(&master_key, slip_10::path!("m/84'/0'/0'/0/0"));
+    assert_eq!(
+        address::p2wpkh(key.public_key(), address::Network::Bitcoin).to_string(),
+        "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"
+    );
+
+    let key =
+        slip_10::derive_child_key_pair_with_path(&master_key, slip_10::path!("m/86'/0'/0'/0/0"));
+    assert_eq!(
+        address::p2tr(key.public_key(), address::Network::Bitcoin).to_string(),
+        "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr"
+    );
+}
+
+#[test]
+fn testnet_addresses() {
+    let key =
+        slip_10::derive_child_key_pair_with_path(&master_key(), slip_10::path!("m/84'/1'/0'/0/0"));
+    let address = address::p2wpkh(key.public_key(), address::Network::Testnet).to_string();
+    assert!(address.starts_with("tb1q"));
+    let address = address::p2wpkh(key.public_key(), address::Network::Regtest).to_string();
+    assert!(address.starts_with("bcrt1q"));
+    let address = address::p2pkh(key.public_key(), address::Network::Testnet).to_string();
+    assert!(address.starts_with('m') || address.starts_with('n'));
+}
@@ -0,0 +1,64 @@
+use arbitrary::{Arbitrary, Unstructured};
+use slip_10::{ChainCode, ChildIndex, DerivationPath, HardenedIndex, NonHardenedIndex, Seed};
+
+/// Deterministic pseudo-random bytes standing in for fuzzer input
+fn fuzzer_input(len: usize) -> Vec<u8> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect()
+}
+
+#[test]
+fn indexes() {
+    let input = fuzzer_input(4096);
+    let mut u = Unstructured::new(&input);
+
+    let mut hardened = 0;
+    for _ in 0..100 {
+        let index = ChildIndex::arbitrary(&mut u).unwrap();
+        if matches!(index, ChildIndex::Hardened(_)) {
+            hardened += 1;
+        }
+        assert!(*HardenedIndex::arbitrary(&mut u).unwrap() >= slip_10::H);
+        assert!(*NonHardenedIndex::arbitrary(&mut u).unwrap() < slip_10::H);
+    }
+    assert!(0 < hardened && hardened < 100);
+}
+
+#[test]
+fn paths() {
+    let input = fuzzer_input(4096);
+    let mut u = Unstructured::new(&input);
+
+    for _ in 0..20 {
+        let path = DerivationPath::arbitrary(&mut u).unwrap();
+        let parsed: DerivationPath = path.to_string().parse().unwrap();
+        assert_eq!(parsed, path);
+
+        let path = slip_10::StaticPath::<5>::arbitrary(&mut u).unwrap();
+        assert!(path.len() <= 5);
+    }
+}
+
+#[test]
+fn chain_code_and_seed() {
+    let input = fuzzer_input(4096);
+    let mut u = Unstructured::new(&input);
+
+    let chain_code = ChainCode::arbitrary(&mut u).unwrap();
+    assert_ne!(chain_code, ChainCode::arbitrary(&mut u).unwrap());
+
+    for _ in 0..20 {
+        let seed = Seed::arbitrary(&mut u).unwrap();
+        assert!((Seed::MIN_LEN..=Seed::MAX_LEN).contains(&seed.as_bytes().len()));
+    }
+
+    // Not enough input for a seed
+    assert!(Seed::arbitrary(&mut Unstructured::new(&[0; 8])).is_err());
+}
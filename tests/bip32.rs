@@ -0,0 +1,438 @@
+use hex_literal::hex;
+use slip_10::{
+    bip32::{Bip32ExtendedKey, Depth, Metadata, Xprv, Xpub},
+    supported_curves::Secp256k1,
+    ChildIndex, H,
+};
+
+struct Bip32TestVector {
+    path: &'static [u32],
+    parent_fingerprint: [u8; 4],
+    xpub: &'static str,
+    xprv: &'static str,
+}
+
+/// Test vector 1 defined in
+/// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#test-vector-1
+const TEST_VECTOR_SEED: [u8; 16] = hex!("000102030405060708090a0b0c0d0e0f");
+const TEST_VECTOR: &[Bip32TestVector] = &[
+    Bip32TestVector {
+        path: &[],
+        parent_fingerprint: [0; 4],
+        xpub: "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8",
+        xprv: "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi",
+    },
+    Bip32TestVector {
+        path: &[H],
+        parent_fingerprint: hex!("3442193e"),
+        xpub: "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw",
+        xprv: "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7",
+    },
+    Bip32TestVector {
+        path: &[H, 1],
+        parent_fingerprint: hex!("5c1bd648"),
+        xpub: "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ",
+        xprv: "xprv9wTYmMFdV23N2TdNG573QoEsfRrWKQgWeibmLntzniatZvR9BmLnvSxqu53Kw1UmYPxLgboyZQaXwTCg8MSY3H2EU4pWcQDnRnrVA1xe8fs",
+    },
+];
+
+#[test]
+fn serialize_test_vector() {
+    let master_key =
+        slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(&TEST_VECTOR_SEED).unwrap());
+    let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+
+    for vector in TEST_VECTOR {
+        let key_pair =
+            slip_10::derive_child_key_pair_with_path(&master_key_pair, vector.path.iter().copied());
+        let metadata = Metadata {
+            depth: vector.path.len().try_into().unwrap(),
+            parent_fingerprint: vector.parent_fingerprint,
+            child_number: vector.path.last().copied().unwrap_or(0).into(),
+        };
+
+        let xpub = Xpub {
+            metadata,
+            key: *key_pair.public_key(),
+        };
+        let xprv = Xprv {
+            metadata,
+            key: key_pair.secret_key().clone(),
+        };
+        assert_eq!(xpub.to_string(), vector.xpub);
+        assert_eq!(xprv.to_string(), vector.xprv);
+
+        let parsed_xpub: Xpub<Secp256k1> = vector.xpub.parse().unwrap();
+        let parsed_xprv: Xprv<Secp256k1> = vector.xprv.parse().unwrap();
+        assert_eq!(parsed_xpub.to_bytes(), xpub.to_bytes());
+        assert_eq!(parsed_xprv.to_bytes(), xprv.to_bytes());
+        assert_eq!(parsed_xpub.key.public_key, key_pair.public_key().public_key);
+        assert_eq!(
+            u32::from(parsed_xprv.metadata.child_number),
+            u32::from(ChildIndex::from(vector.path.last().copied().unwrap_or(0)))
+        );
+    }
+}
+
+#[test]
+fn fingerprint_test_vector() {
+    let master_key =
+        slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(&TEST_VECTOR_SEED).unwrap());
+    let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+
+    for (parent, child) in TEST_VECTOR.iter().zip(&TEST_VECTOR[1..]) {
+        let parent_key =
+            slip_10::derive_child_key_pair_with_path(&master_key_pair, parent.path.iter().copied());
+        assert_eq!(
+            parent_key.public_key().fingerprint(),
+            child.parent_fingerprint
+        );
+    }
+}
+
+#[test]
+fn metadata_is_tracked_on_derivation() {
+    let master_key =
+        slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(&TEST_VECTOR_SEED).unwrap());
+    let master_key = Bip32ExtendedKey::master(master_key);
+
+    for vector in TEST_VECTOR {
+        let key = master_key
+            .derive_child_with_path(vector.path.iter().copied())
+            .unwrap();
+        assert_eq!(key.to_xpub().to_string(), vector.xpub);
+        assert_eq!(key.to_xprv().to_string(), vector.xprv);
+    }
+
+    let mut key = master_key;
+    for _ in 0..u8::MAX {
+        key = key.derive_child(0).unwrap();
+    }
+    assert_eq!(key.metadata().depth, Depth::MAX);
+    assert!(matches!(
+        key.derive_child(0),
+        Err(slip_10::errors::DeriveError::DepthOverflow { level: 0, .. })
+    ));
+    assert!(key.derive_child_with_path([0u32; 0]).is_ok());
+    let err = key.derive_child_with_path([0]).unwrap_err();
+    assert!(matches!(
+        err,
+        slip_10::errors::DeriveError::DepthOverflow { level: 0, .. }
+    ));
+}
+
+#[test]
+fn depth() {
+    assert_eq!(Depth::MASTER.child().unwrap(), 1);
+    assert!(Depth::MAX.child().is_err());
+    assert_eq!(Depth::try_from(255usize).unwrap(), Depth::MAX);
+    assert!(Depth::try_from(256usize).is_err());
+    assert_eq!(u8::from(Depth::new(3)), 3);
+}
+
+#[test]
+fn into_root() {
+    let master_key =
+        slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(&TEST_VECTOR_SEED).unwrap());
+    let master_key = Bip32ExtendedKey::master(master_key);
+
+    let account_key = master_key.derive_child_with_path([44 + H, H, H]).unwrap();
+    let root = account_key.clone().into_root();
+    assert_eq!(root.metadata().depth, 0);
+    assert_eq!(root.metadata().parent_fingerprint, [0; 4]);
+    assert_eq!(root.metadata().child_number, ChildIndex::from(0));
+    assert_eq!(root.key(), account_key.key());
+
+    // Children are the same, but depth starts from the new root
+    let child = root.derive_child(0).unwrap();
+    assert_eq!(child.key(), account_key.derive_child(0).unwrap().key());
+    assert_eq!(child.metadata().depth, 1);
+
+    let xpub = account_key.to_xpub().into_root();
+    assert_eq!(xpub.to_string(), root.to_xpub().to_string());
+    let xprv = account_key.to_xprv().into_root();
+    assert_eq!(xprv.to_string(), root.to_xprv().to_string());
+}
+
+#[test]
+fn reject_mismatched_key_type() {
+    let xpub = TEST_VECTOR[0].xpub;
+    let xprv = TEST_VECTOR[0].xprv;
+    assert!(matches!(
+        xpub.parse::<Xprv<Secp256k1>>(),
+        Err(slip_10::errors::ParseExtendedKeyError::UnknownVersion)
+    ));
+    assert!(matches!(
+        xprv.parse::<Xpub<Secp256k1>>(),
+        Err(slip_10::errors::ParseExtendedKeyError::UnknownVersion)
+    ));
+}
+
+/// Invalid public keys from test vector 5 defined in
+/// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#test-vector-5
+#[test]
+fn reject_invalid_xpubs() {
+    use slip_10::errors::ParseExtendedKeyError as Error;
+
+    let invalid_xpubs = [
+        // pubkey version / prvkey mismatch
+        "xpub661MyMwAqRbcEYS8w7XLSVeEsBXy79zSzH1J8vCdxAZningWLdN3zgtU6LBpB85b3D2yc8sfvZU521AAwdZafEz7mnzBBsz4wKY5fTtTQBm",
+        // invalid pubkey prefix 04
+        "xpub661MyMwAqRbcEYS8w7XLSVeEsBXy79zSzH1J8vCdxAZningWLdN3zgtU6Txnt3siSujt9RCVYsx4qHZGc62TG4McvMGcAUjeuwZdduYEvFn",
+        // invalid pubkey
+        "xpub661MyMwAqRbcEYS8w7XLSVeEsBXy79zSzH1J8vCdxAZningWLdN3zgtU6N8ZMMXctdiCjxTNq964yKkwrkBJJwpzZS4HS2fxvyYUA4q2Xe4",
+    ];
+    for xpub in invalid_xpubs {
+        assert!(
+            matches!(xpub.parse::<Xpub<Secp256k1>>(), Err(Error::InvalidKey)),
+            "xpub: {xpub}"
+        );
+    }
+
+    let invalid_metadata = [
+        // zero depth with non-zero parent fingerprint
+        "xpub661no6RGEX3uJkY4bNnPcw4URcQTrSibUZ4NqJEw5eBkv7ovTwgiT91XX27VbEXGENhYRCf7hyEbWrR3FewATdCEebj6znwMfQkhRYHRLpJ",
+        // zero depth with non-zero index
+        "xpub661MyMwAuDcm6CRQ5N4qiHKrJ39Xe1R1NyfouMKTTWcguwVcfrZJaNvhpebzGerh7gucBvzEQWRugZDuDXjNDRmXzSZe4c7mnTK97pTvGS8",
+    ];
+    for xpub in invalid_metadata {
+        assert!(
+            matches!(xpub.parse::<Xpub<Secp256k1>>(), Err(Error::InvalidMetadata)),
+            "xpub: {xpub}"
+        );
+    }
+
+    // unknown extended key version
+    let xpub = "DMwo58pR1QLEFihHiXPVykYB6fJmsTeHvyTp7hRThAtCX8CvYzgPcn8XnmdfHGMQzT7ayAmfo4z3gY5KfbrZWZ6St24UVf2Qgo6oujFktLHdHY4";
+    assert!(matches!(
+        xpub.parse::<Xpub<Secp256k1>>(),
+        Err(Error::UnknownVersion)
+    ));
+}
+
+#[test]
+fn reject_invalid_xprvs() {
+    use slip_10::errors::ParseExtendedKeyError as Error;
+
+    let valid: Xprv<Secp256k1> = TEST_VECTOR[0].xprv.parse().unwrap();
+    let valid = valid.to_bytes();
+    let modified = |f: fn(&mut [u8; 78])| {
+        let mut bytes = valid;
+        f(&mut bytes);
+        Xprv::<Secp256k1>::from_bytes(&bytes)
+    };
+
+    // invalid prvkey prefix
+    assert!(matches!(modified(|b| b[45] = 0x04), Err(Error::InvalidKey)));
+    // private key 0 not in 1..n-1
+    assert!(matches!(
+        modified(|b| b[46..].fill(0)),
+        Err(Error::InvalidKey)
+    ));
+    // private key n not in 1..n-1
+    assert!(matches!(
+        modified(|b| b[46..].copy_from_slice(&hex!(
+            "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"
+        ))),
+        Err(Error::InvalidKey)
+    ));
+    // zero depth with non-zero parent fingerprint
+    assert!(matches!(
+        modified(|b| b[5] = 1),
+        Err(Error::InvalidMetadata)
+    ));
+    // zero depth with non-zero index
+    assert!(matches!(
+        modified(|b| b[12] = 1),
+        Err(Error::InvalidMetadata)
+    ));
+    // pubkey version
+    assert!(matches!(
+        modified(|b| b[..4].copy_from_slice(&Xpub::<Secp256k1>::VERSION)),
+        Err(Error::UnknownVersion)
+    ));
+    // invalid length
+    assert!(matches!(
+        Xprv::<Secp256k1>::from_bytes(&valid[..77]),
+        Err(Error::InvalidLength(_))
+    ));
+
+    // invalid checksum
+    let xprv = "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHL";
+    assert!(matches!(
+        xprv.parse::<Xprv<Secp256k1>>(),
+        Err(Error::InvalidChecksum)
+    ));
+    // invalid base58
+    assert!(matches!(
+        "xprv0".parse::<Xprv<Secp256k1>>(),
+        Err(Error::Base58)
+    ));
+}
+
+#[test]
+fn watch_only_account() {
+    use slip_10::{
+        bip44::Change,
+        errors::{InvalidAccount, ParseKeyExpressionError, ParseWatchOnlyAccountError},
+        watch_only::WatchOnlyAccount,
+        NonHardenedIndex,
+    };
+
+    let master_key =
+        slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(&TEST_VECTOR_SEED).unwrap());
+    let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+
+    let account =
+        WatchOnlyAccount::from_master_key(&master_key_pair, "m/0'".parse().unwrap()).unwrap();
+    let encoded = format!("[3442193e/0']{}", TEST_VECTOR[1].xpub);
+    assert_eq!(account.to_string(), encoded);
+
+    let account: WatchOnlyAccount<Secp256k1> = encoded.parse().unwrap();
+    assert_eq!(account.origin().master_fingerprint, hex!("3442193e"));
+    assert_eq!(account.origin().path.to_string(), "m/0'");
+
+    let expected = slip_10::derive_child_key_pair_with_path(&master_key_pair, [H, 1, 5]);
+    let derived = account.change_public_key(NonHardenedIndex::new(5));
+    assert_eq!(derived.public_key, expected.public_key().public_key);
+    assert_eq!(
+        account
+            .key_path(Change::Internal, NonHardenedIndex::new(5))
+            .unwrap()
+            .to_string(),
+        "m/0'/1/5"
+    );
+
+    let json = serde_json::to_string(&account).unwrap();
+    assert_eq!(json, format!("\"{encoded}\""));
+    let restored: WatchOnlyAccount<Secp256k1> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.to_string(), encoded);
+
+    let master = format!("[3442193e]{}", TEST_VECTOR[0].xpub);
+    assert_eq!(
+        master
+            .parse::<WatchOnlyAccount<Secp256k1>>()
+            .unwrap()
+            .to_string(),
+        master
+    );
+
+    let invalid = [
+        (format!("3442193e/0'{}", TEST_VECTOR[1].xpub), "format"),
+        (
+            format!("[3442193/0']{}", TEST_VECTOR[1].xpub),
+            "fingerprint",
+        ),
+        (format!("[00000000/0']{}", TEST_VECTOR[1].xpub), "mismatch"),
+        (format!("[3442193e/1']{}", TEST_VECTOR[1].xpub), "mismatch"),
+        (
+            format!("[3442193e/0'/1']{}", TEST_VECTOR[1].xpub),
+            "mismatch",
+        ),
+        (
+            format!("[5c1bd648/0'/1]{}", TEST_VECTOR[2].xpub),
+            "mismatch",
+        ),
+    ];
+    for (s, reason) in invalid {
+        let err = s.parse::<WatchOnlyAccount<Secp256k1>>().unwrap_err();
+        match reason {
+            "format" => assert!(matches!(
+                err,
+                ParseWatchOnlyAccountError::ParseKeyExpression(
+                    ParseKeyExpressionError::InvalidFormat
+                )
+            )),
+            "fingerprint" => assert!(matches!(
+                err,
+                ParseWatchOnlyAccountError::ParseKeyExpression(
+                    ParseKeyExpressionError::InvalidFingerprint
+                )
+            )),
+            _ => assert!(matches!(err, ParseWatchOnlyAccountError::InvalidAccount(_))),
+        }
+    }
+
+    assert!(matches!(
+        WatchOnlyAccount::from_master_key(&master_key_pair, "m/0'/1".parse().unwrap()),
+        Err(InvalidAccount::NonHardenedOrigin)
+    ));
+}
+
+#[test]
+fn key_expression() {
+    use slip_10::watch_only::{KeyExpression, WatchOnlyAccount};
+
+    // Origin path doesn't have to be hardened, nor match the key metadata
+    let s = format!("[d34db33f/84h/0h/0h/1/5]{}", TEST_VECTOR[2].xpub);
+    let key: KeyExpression<Secp256k1> = s.parse().unwrap();
+    assert_eq!(key.origin.master_fingerprint, hex!("d34db33f"));
+    assert_eq!(key.origin.path.to_string(), "m/84'/0'/0'/1/5");
+    assert_eq!(key.xpub.to_string(), TEST_VECTOR[2].xpub);
+    assert_eq!(
+        key.to_string(),
+        format!("[d34db33f/84'/0'/0'/1/5]{}", TEST_VECTOR[2].xpub)
+    );
+    assert!(WatchOnlyAccount::try_from(key).is_err());
+
+    let key: KeyExpression<Secp256k1> = format!("[3442193e/0']{}", TEST_VECTOR[1].xpub)
+        .parse()
+        .unwrap();
+    let account = WatchOnlyAccount::try_from(key).unwrap();
+    let key = KeyExpression::from(account);
+    assert_eq!(key.origin.to_string(), "3442193e/0'");
+
+    for invalid in [
+        TEST_VECTOR[1].xpub.to_string(),
+        format!("[3442193e/0'{}", TEST_VECTOR[1].xpub),
+        format!("[3442193e/0'/]{}", TEST_VECTOR[1].xpub),
+        format!("[3442193e/0']{}", TEST_VECTOR[1].xprv),
+    ] {
+        assert!(invalid.parse::<KeyExpression<Secp256k1>>().is_err());
+    }
+}
+
+#[test]
+fn testnet_serialization() {
+    use slip_10::bip32::{KeyVersion, Network};
+
+    let master_key =
+        slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(&TEST_VECTOR_SEED).unwrap());
+    let master_key = Bip32ExtendedKey::master(master_key);
+
+    let tprv = master_key
+        .to_xprv()
+        .encode_with_version(&Network::Testnet)
+        .to_string();
+    assert_eq!(tprv, "tprv8ZgxMBicQKsPeDgjzdC36fs6bMjGApWDNLR9erAXMs5skhMv36j9MV5ecvfavji5khqjWaWSFhN3YcCUUdiKH6isR4Pwy3U5y5egddBr16m");
+    let (decoded, network) = Xprv::<Secp256k1>::decode_with_version::<Network>(&tprv).unwrap();
+    assert_eq!(network, Network::Testnet);
+    assert_eq!(decoded.to_string(), TEST_VECTOR[0].xprv);
+
+    let tpub = master_key
+        .to_xpub()
+        .encode_with_version(&Network::Testnet)
+        .to_string();
+    assert!(tpub.starts_with("tpub"));
+    assert!(tpub.parse::<Xpub<Secp256k1>>().is_err());
+    let (decoded, network) = Xpub::<Secp256k1>::decode_with_version::<Network>(&tpub).unwrap();
+    assert_eq!(network, Network::Testnet);
+    assert_eq!(decoded.to_string(), TEST_VECTOR[0].xpub);
+
+    let (_, network) =
+        Xpub::<Secp256k1>::decode_with_version::<Network>(TEST_VECTOR[0].xpub).unwrap();
+    assert_eq!(network, Network::Mainnet);
+
+    let custom = Network::Custom {
+        public: hex!("0488b21e"),
+        secret: hex!("0488ade4"),
+    };
+    assert_eq!(custom.public_version(), Xpub::<Secp256k1>::VERSION);
+    assert_eq!(
+        master_key
+            .to_xpub()
+            .encode_with_version(&custom)
+            .to_string(),
+        TEST_VECTOR[0].xpub
+    );
+}
@@ -0,0 +1,119 @@
+use hex_literal::hex;
+use slip_10::{bip32_ed25519, H};
+
+struct Derivation {
+    path: &'static [u32],
+
+    expected_secret_key: [u8; 64],
+    expected_chain_code: [u8; 32],
+    expected_public_key: [u8; 32],
+}
+
+/// Root key `D1` from https://github.com/typed-io/rust-ed25519-bip32/blob/master/src/tests.rs
+const ROOT_SECRET_KEY: [u8; 64] = hex!("f8a29231ee38d6c5bf715d5bac21c750577aa3798b22d79d65bf97d6fadea15adcd1ee1abdf78bd4be64731a12deb94d3671784112eb6f364b871851fd1c9a24");
+const ROOT_CHAIN_CODE: [u8; 32] =
+    hex!("7384db9ad6003bbd08b3b1ddc0d07a597293ff85e961bf252b331262eddfad0d");
+
+/// `D1_H0` is taken from the same source, the rest were generated with the `ed25519-bip32` crate
+const TEST_VECTORS: &[Derivation] = &[
+    Derivation {
+        path: &[],
+        expected_secret_key: ROOT_SECRET_KEY,
+        expected_chain_code: ROOT_CHAIN_CODE,
+        expected_public_key: hex!(
+            "86ae05cac74c490e097646814c76a9ac813c470afb6ce6429bf836b1bf517878"
+        ),
+    },
+    Derivation {
+        path: &[H],
+        expected_secret_key: hex!("60d399da83ef80d8d4f8d223239efdc2b8fef387e1b5219137ffb4e8fbdea15adc9366b7d003af37c11396de9a83734e30e05e851efa32745c9cd7b42712c890"),
+        expected_chain_code: hex!(
+            "608763770eddf77248ab652984b21b849760d1da74a6f5bd633ce41adceef07a"
+        ),
+        expected_public_key: hex!(
+            "9c99845ae0a60881decf7874b94ad02e7540628b588f8b4305d668cc53986978"
+        ),
+    },
+    Derivation {
+        path: &[0],
+        expected_secret_key: hex!("e86a12ba078cdbdf044b488624a50b9f681086c5e7c005222c6fb69e02dfa15a28630505d5878465269ecf096b7ec855780e6e4aed06852676e8ced5bd66d1da"),
+        expected_chain_code: hex!(
+            "d6324d15fe0641021a711f3ef93865b2e41c3cef61b155d57a988156074ce2a8"
+        ),
+        expected_public_key: hex!(
+            "10abaae2cf8f9c2d0cee0a880c0c3f6fcaaae9a1edff667fc567a117f6359c20"
+        ),
+    },
+    Derivation {
+        path: &[H, 1],
+        expected_secret_key: hex!("584a235f3a45e928a88d2f5482c3dfd29cfff1202016588501a24125fedea15a6243e84fe2c99c5d585cd8bd7516d25cb9bc8ffa97a3ec3df89563ca4dc2f17a"),
+        expected_chain_code: hex!(
+            "bc941e77ab7a05ba293fa338ea1c58ff26e5cccdc2b2b596b3484c0644282b81"
+        ),
+        expected_public_key: hex!(
+            "e91ddda61b0eb879d30beee12d3f591f272590f04a76c45e849a4f9c4fff18ca"
+        ),
+    },
+    Derivation {
+        path: &[1852 + H, 1815 + H, H, 0, 5],
+        expected_secret_key: hex!("70766f7123096c49e855143c4e5fd119baa3a7226176b4e090b87df312dfa15a137c3e17a3961a0c60c7a640baeb861016950250af41ff9e6bef2475b8b6836c"),
+        expected_chain_code: hex!(
+            "b8057ede0c65bef51f1213ca3b4b4490ba1dda827e4d94cc8e86d134189e6356"
+        ),
+        expected_public_key: hex!(
+            "16b2c8812520c9ba5158caf192169a8e3724d936dbee9baeca2f967dcb49f90a"
+        ),
+    },
+    Derivation {
+        path: &[1 + H, 2, 3],
+        expected_secret_key: hex!("60e27ec34b5e617780d7bb7da5b9571de00b47b556683f6c2dc4e55f0bdfa15a76189a1637f22cecf28727ea2618190788eac0f0ab88289fe2a7a1d3ae805c6f"),
+        expected_chain_code: hex!(
+            "93ae1c43eb7ef13f4d295cda87a144be98f93c83421bf88b832e991e6dce2da8"
+        ),
+        expected_public_key: hex!(
+            "c418d0c82f09cc9b188183d84e69d42178389c1f6a8d5972628c648f5988fa94"
+        ),
+    },
+];
+
+#[test]
+fn test_vectors() {
+    let root_key = bip32_ed25519::ExtendedKeyPair::from(bip32_ed25519::ExtendedSecretKey {
+        secret_key: ROOT_SECRET_KEY,
+        chain_code: ROOT_CHAIN_CODE.into(),
+    });
+
+    for vector in TEST_VECTORS {
+        let key =
+            bip32_ed25519::derive_child_key_pair_with_path(&root_key, vector.path.iter().copied());
+        assert_eq!(key.secret_key().secret_key, vector.expected_secret_key);
+        assert_eq!(key.chain_code(), &vector.expected_chain_code);
+        assert_eq!(
+            key.public_key().public_key.to_bytes(true).as_ref(),
+            vector.expected_public_key
+        );
+
+        // Non-hardened suffix of the path can be derived from the public key
+        let hardened_len = vector.path.iter().take_while(|i| **i >= H).count();
+        let (hardened, non_hardened) = vector.path.split_at(hardened_len);
+        let parent_key =
+            bip32_ed25519::derive_child_key_pair_with_path(&root_key, hardened.iter().copied());
+        let public_key = bip32_ed25519::derive_child_public_key_with_path(
+            parent_key.public_key(),
+            non_hardened.iter().map(|i| (*i).try_into().unwrap()),
+        );
+        assert_eq!(public_key.public_key, key.public_key().public_key);
+        assert_eq!(public_key.chain_code, vector.expected_chain_code);
+    }
+}
+
+#[test]
+fn master_key_is_clamped() {
+    for seed_len in 16..=64 {
+        let seed = vec![seed_len; usize::from(seed_len)];
+        let master_key = bip32_ed25519::derive_master_key(slip_10::Seed::new(&seed).unwrap());
+        let k_l = &master_key.secret_key[..32];
+        assert_eq!(k_l[0] & 0b0000_0111, 0);
+        assert_eq!(k_l[31] & 0b1110_0000, 0b0100_0000);
+    }
+}
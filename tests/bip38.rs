@@ -0,0 +1,64 @@
+use generic_ec::{Scalar, SecretScalar};
+use hex_literal::hex;
+use slip_10::{bip38, errors::Bip38Error, supported_curves::Secp256k1};
+
+/// Test vectors defined in https://github.com/bitcoin/bips/blob/master/bip-0038.mediawiki
+#[test]
+fn bip38_test_vectors() {
+    let vectors = [
+        (
+            "TestingOneTwoThree",
+            false,
+            "6PRVWUbkzzsbcVac2qwfssoUJAN1Xhrg6bNk8J7Nzm5H7kxEbn2Nh2ZoGg",
+            hex!("CBF4B9F70470856BB4F40F80B87EDB90865997FFEE6DF315AB166D713AF433A5"),
+        ),
+        (
+            "TestingOneTwoThree",
+            true,
+            "6PYNKZ1EAgYgmQfmNVamxyXVWHzK5s6DGhwP4J5o44cvXdoY7sRzhtpUeo",
+            hex!("CBF4B9F70470856BB4F40F80B87EDB90865997FFEE6DF315AB166D713AF433A5"),
+        ),
+        (
+            "Satoshi",
+            true,
+            "6PYLtMnXvfG3oJde97zRyLYFZCYizPU5T3LwgdYJz1fRhh16bU7u6PPmY7",
+            hex!("09C2686880095B1A4C249EE3AC4EEA8A014F11E6F986D0B5025AC1F39AFBD9AE"),
+        ),
+    ];
+
+    for (passphrase, compressed, encrypted, secret_key) in vectors {
+        let mut secret_key = Scalar::<Secp256k1>::from_be_bytes(secret_key).unwrap();
+        let secret_key = SecretScalar::new(&mut secret_key);
+
+        assert_eq!(
+            bip38::encrypt(&secret_key, passphrase, compressed).to_string(),
+            encrypted
+        );
+        let decrypted = bip38::decrypt(encrypted, passphrase).unwrap();
+        assert_eq!(decrypted.secret_key.as_ref(), secret_key.as_ref());
+        assert_eq!(decrypted.compressed, compressed);
+    }
+}
+
+#[test]
+fn extended_key() {
+    let master_key = slip_10::derive_master_key::<Secp256k1>(
+        slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap(),
+    );
+
+    let encrypted = bip38::encrypt_extended_key(&master_key, "passphrase").to_string();
+    let decrypted = bip38::decrypt_extended_key(&encrypted, "passphrase").unwrap();
+    assert_eq!(decrypted.to_bytes(), master_key.to_bytes());
+
+    assert!(matches!(
+        bip38::decrypt_extended_key(&encrypted, "wrong passphrase"),
+        Err(Bip38Error::WrongPassphrase)
+    ));
+    // Extended key is not a BIP38 key, and vice versa
+    assert!(bip38::decrypt(&encrypted, "passphrase").is_err());
+    assert!(bip38::decrypt_extended_key(
+        "6PYLtMnXvfG3oJde97zRyLYFZCYizPU5T3LwgdYJz1fRhh16bU7u6PPmY7",
+        "Satoshi"
+    )
+    .is_err());
+}
@@ -0,0 +1,79 @@
+use slip_10::{bip32, bip39::Mnemonic, supported_curves::Secp256k1};
+
+/// Test vectors from https://github.com/trezor/python-mnemonic/blob/master/vectors.json
+const TEST_VECTORS: &[(&str, &str)] = &[
+    (
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        "xprv9s21ZrQH143K3h3fDYiay8mocZ3afhfULfb5GX8kCBdno77K4HiA15Tg23wpbeF1pLfs1c5SPmYHrEpTuuRhxMwvKDwqdKiGJS9XFKzUsAF",
+    ),
+    (
+        "legal winner thank year wave sausage worth useful legal winner thank yellow",
+        "xprv9s21ZrQH143K2gA81bYFHqU68xz1cX2APaSq5tt6MFSLeXnCKV1RVUJt9FWNTbrrryem4ZckN8k4Ls1H6nwdvDTvnV7zEXs2HgPezuVccsq",
+    ),
+];
+
+#[test]
+fn mnemonic_test_vectors() {
+    for (mnemonic, expected_xprv) in TEST_VECTORS {
+        let mnemonic: Mnemonic = mnemonic.parse().unwrap();
+        let master_key = slip_10::derive_master_key_from_mnemonic::<Secp256k1>(&mnemonic, "TREZOR");
+        let xprv = bip32::Xprv {
+            metadata: bip32::Metadata::MASTER,
+            key: master_key,
+        };
+        assert_eq!(xprv.to_string(), *expected_xprv);
+    }
+}
+
+/// Account keys from BIP49 and BIP84 test vectors
+#[test]
+fn slip132_test_vectors() {
+    use slip_10::{
+        bip32::Bip32ExtendedKey,
+        slip132::{ScriptType, Slip132},
+        DerivationPath,
+    };
+
+    let mnemonic: Mnemonic = TEST_VECTORS[0].0.parse().unwrap();
+    let master_key = slip_10::derive_master_key_from_mnemonic::<Secp256k1>(&mnemonic, "");
+    let master_key = Bip32ExtendedKey::master(master_key);
+
+    let vectors = [
+        (
+            "m/84'/0'/0'",
+            Slip132::Zpub,
+            "zpub6rFR7y4Q2AijBEqTUquhVz398htDFrtymD9xYYfG1m4wAcvPhXNfE3EfH1r1ADqtfSdVCToUG868RvUUkgDKf31mGDtKsAYz2oz2AGutZYs",
+            "zprvAdG4iTXWBoARxkkzNpNh8r6Qag3irQB8PzEMkAFeTRXxHpbF9z4QgEvBRmfvqWvGp42t42nvgGpNgYSJA9iefm1yYNZKEm7z6qUWCroSQnE",
+        ),
+        (
+            "m/49'/1'/0'",
+            Slip132::Upub,
+            "upub5EFU65HtV5TeiSHmZZm7FUffBGy8UKeqp7vw43jYbvZPpoVsgU93oac7Wk3u6moKegAEWtGNF8DehrnHtv21XXEMYRUocHqguyjknFHYfgY",
+            "uprv91G7gZkzehuMVxDJTYE6tLivdF8e4rvzSu1LFfKw3b2Qx1Aj8vpoFnHdfUZ3hmi9jsvPifmZ24RTN2KhwB8BfMLTVqaBReibyaFFcTP1s9n",
+        ),
+    ];
+    for (path, version, expected_xpub, expected_xprv) in vectors {
+        let path: DerivationPath = path.parse().unwrap();
+        let account = master_key.derive_child_with_path(&path).unwrap();
+
+        let xpub = account.to_xpub().encode_with_version(&version).to_string();
+        let xprv = account.to_xprv().encode_with_version(&version).to_string();
+        assert_eq!(xpub, expected_xpub);
+        assert_eq!(xprv, expected_xprv);
+
+        let (decoded, decoded_version) =
+            bip32::Xpub::<Secp256k1>::decode_with_version::<Slip132>(&xpub).unwrap();
+        assert_eq!(decoded_version, version);
+        assert_eq!(decoded.to_bytes(), account.to_xpub().to_bytes());
+        let (_, decoded_version) =
+            bip32::Xprv::<Secp256k1>::decode_with_version::<Slip132>(&xprv).unwrap();
+        assert_eq!(decoded_version, version);
+    }
+    assert_eq!(Slip132::Zpub.script_type(), ScriptType::P2wpkh);
+    assert!(Slip132::Upub.is_testnet());
+
+    // Public version is not accepted for secret keys and vice versa
+    let zpub = vectors[0].2;
+    assert!(bip32::Xprv::<Secp256k1>::decode_with_version::<Slip132>(zpub).is_err());
+    assert!(zpub.parse::<bip32::Xpub<Secp256k1>>().is_err());
+}
@@ -0,0 +1,67 @@
+use hex_literal::hex;
+use slip_10::{
+    bip32::{Bip32ExtendedKey, Xprv},
+    bip85,
+    supported_curves::Secp256k1,
+    ExtendedSecretKey, HardenedIndex,
+};
+
+/// Test vectors defined in https://github.com/bitcoin/bips/blob/master/bip-0085.mediawiki
+const MASTER_KEY: &str = "xprv9s21ZrQH143K2LBWUUQRFXhucrQqBpKdRRxNVq2zBqsx8HVqFk2uYo8kmbaLLHRdqtQpUm98uKfu3vca1LqdGhUtyoFnCNkfmXRyPXLjbKb";
+
+fn master_key() -> ExtendedSecretKey<Secp256k1> {
+    MASTER_KEY.parse::<Xprv<Secp256k1>>().unwrap().key
+}
+
+#[test]
+fn entropy() {
+    let master_key = master_key();
+    let entropy = bip85::derive_entropy(
+        &master_key,
+        [bip85::PURPOSE, HardenedIndex::new(0), HardenedIndex::new(0)],
+    );
+    assert_eq!(entropy, hex!("efecfbccffea313214232d29e71563d941229afb4338c21f9517c41aaa0d16f00b83d2a09ef747e7a64e8e2bd5a14869e693da66ce94ac2da570ab7ee48618f7"));
+
+    let entropy = bip85::derive_entropy(
+        &master_key,
+        [bip85::PURPOSE, HardenedIndex::new(0), HardenedIndex::new(1)],
+    );
+    assert_eq!(entropy, hex!("70c6e3e8ebee8dc4c0dbba66076819bb8c09672527c4277ca8729532ad711872218f826919f6b67218adde99018a6df9095ab2b58d803b5b93ec9802085a690e"));
+}
+
+#[test]
+fn mnemonic() {
+    let mnemonic = bip85::derive_mnemonic(&master_key(), 12, HardenedIndex::new(0)).unwrap();
+    assert_eq!(
+        mnemonic.to_string(),
+        "girl mad pet galaxy egg matter matrix prison refuse sense ordinary nose"
+    );
+    assert!(bip85::derive_mnemonic(&master_key(), 13, HardenedIndex::new(0)).is_err());
+}
+
+#[test]
+fn wif() {
+    let secret_key = bip85::derive_wif_key(&master_key(), HardenedIndex::new(0)).unwrap();
+    assert_eq!(
+        slip_10::wif::encode(&secret_key, slip_10::wif::MAINNET).to_string(),
+        "Kzyv4uF39d4Jrw2W7UryTHwZr1zQVNk4dAFyqE6BuMrMh1Za7uhp"
+    );
+}
+
+#[test]
+fn xprv() {
+    let key = bip85::derive_xprv(&master_key(), HardenedIndex::new(0)).unwrap();
+    assert_eq!(
+        Bip32ExtendedKey::master(key).to_xprv().to_string(),
+        "xprv9s21ZrQH143K2srSbCSg4m4kLvPMzcWydgmKEnMmoZUurYuBuYG46c6P71UGXMzmriLzCCBvKQWBUv3vPB3m1SATMhp3uEjXHJ42jFg7myX"
+    );
+}
+
+#[test]
+fn hex() {
+    let mut entropy = [0u8; 64];
+    bip85::derive_hex(&master_key(), HardenedIndex::new(0), &mut entropy).unwrap();
+    assert_eq!(entropy, hex!("492db4698cf3b73a5a24998aa3e9d7fa96275d85724a91e71aa2d645442f878555d078fd1f1f67e368976f04137b1f7a0d19232136ca50c44614af72b5582a5c"));
+
+    assert!(bip85::derive_hex(&master_key(), HardenedIndex::new(0), &mut [0u8; 15]).is_err());
+}
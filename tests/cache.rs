@@ -0,0 +1,118 @@
+use slip_10::{
+    cache::{CacheStats, HdTreeCache, SharedHdTreeCache},
+    supported_curves::Secp256k1,
+    ChildIndex, ExtendedKeyPair, H,
+};
+
+fn root() -> ExtendedKeyPair<Secp256k1> {
+    let seed = slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap();
+    slip_10::derive_master_key::<Secp256k1>(&seed).into()
+}
+
+fn path(indexes: &[u32]) -> Vec<ChildIndex> {
+    indexes.iter().copied().map(ChildIndex::from).collect()
+}
+
+#[test]
+fn derives_same_keys() {
+    let root = root();
+    let mut cache = HdTreeCache::new(root.clone());
+
+    for i in 0..5 {
+        let path = path(&[44 + H, H, H, 0, i]);
+        assert_eq!(
+            cache.derive(path.iter().copied()),
+            root.derive_path(path.iter().copied())
+        );
+    }
+    assert_eq!(
+        cache.derive(path(&[44 + H, H])),
+        root.derive_path(path(&[44 + H, H]))
+    );
+    assert_eq!(cache.derive(path(&[1])), root.derive_path([1]));
+    assert_eq!(cache.derive(path(&[])), root);
+
+    assert_eq!(
+        cache.stats(),
+        CacheStats {
+            hits: 2,
+            misses: 6,
+            derivations: 4 + 5 + 1,
+            evictions: 0,
+        }
+    );
+    assert_eq!(cache.len(), 4 + 5 + 1);
+}
+
+#[test]
+fn eviction() {
+    let root = root();
+    let mut cache = HdTreeCache::with_max_entries(root.clone(), 3);
+
+    cache.derive(path(&[H, 0, 0]));
+    assert_eq!(cache.len(), 3);
+
+    // m/0'/0 is reused for the new key, so m/0' is the least recently used node
+    let key = cache.derive(path(&[H, 0, 1]));
+    assert_eq!(key, root.derive_path(path(&[H, 0, 1])));
+    assert_eq!(cache.len(), 3);
+    assert!(!cache.contains(&path(&[H])));
+    assert!(cache.contains(&path(&[H, 0])));
+    assert!(cache.contains(&path(&[H, 0, 0])));
+    assert!(cache.contains(&path(&[H, 0, 1])));
+    assert_eq!(cache.stats().evictions, 1);
+    assert_eq!(cache.stats().derivations, 4);
+
+    cache.set_max_entries(Some(1));
+    assert_eq!(cache.len(), 1);
+    assert!(cache.contains(&path(&[H, 0, 1])));
+
+    assert!(cache.remove(&path(&[H, 0, 1])));
+    assert!(!cache.remove(&path(&[H, 0, 1])));
+    assert!(cache.is_empty());
+
+    cache.set_max_entries(Some(0));
+    cache.derive(path(&[H, 0, 1]));
+    assert!(cache.is_empty());
+
+    cache.set_max_entries(None);
+    cache.derive(path(&[H, 0, 1]));
+    assert_eq!(cache.len(), 3);
+    cache.clear();
+    assert!(cache.is_empty());
+
+    cache.reset_stats();
+    assert_eq!(cache.stats(), CacheStats::default());
+}
+
+#[test]
+fn shared_cache() {
+    fn assert_sync<T: Send + Sync>() {}
+    assert_sync::<SharedHdTreeCache<Secp256k1>>();
+
+    let root = root();
+    let cache = SharedHdTreeCache::new(root.clone());
+
+    std::thread::scope(|s| {
+        for thread in 0..4 {
+            let (cache, root) = (&cache, &root);
+            s.spawn(move || {
+                for i in 0..10 {
+                    let path = path(&[44 + H, H, H, thread % 2, i]);
+                    assert_eq!(
+                        cache.derive(path.iter().copied()),
+                        root.derive_path(path.iter().copied())
+                    );
+                }
+            });
+        }
+    });
+
+    // m/44'/0'/0', m/44'/0'/0'/{0,1}, and 20 leaves
+    assert_eq!(cache.len(), 3 + 2 + 20);
+    assert_eq!(cache.stats().hits + cache.stats().misses, 40);
+
+    cache.set_max_entries(Some(5));
+    assert_eq!(cache.len(), 5);
+    assert_eq!(cache.into_inner().len(), 5);
+}
@@ -0,0 +1,49 @@
+use hex_literal::hex;
+use slip_10::{bip39::Mnemonic, cardano};
+
+/// Test vectors defined in https://cips.cardano.org/cip/CIP-0003 (Icarus)
+#[test]
+fn icarus_master_key() {
+    let mnemonic: Mnemonic =
+        "eight country switch draw meat scout mystery blade tip drift useless good keep usage title"
+            .parse()
+            .unwrap();
+
+    let master_key = cardano::derive_master_key_from_mnemonic(&mnemonic, "");
+    assert_eq!(
+        master_key.secret_key,
+        hex!("c065afd2832cd8b087c4d9ab7011f481ee1e0721e78ea5dd609f3ab3f156d245d176bd8fd4ec60b4731c3918a2a72a0226c0cd119ec35b47e4d55884667f552a")
+    );
+    assert_eq!(
+        master_key.chain_code,
+        hex!("23f7fdcd4a10c6cd2c7393ac61d877873e248f417634aa3d812af327ffe9d620")
+    );
+
+    let master_key = cardano::derive_master_key_from_mnemonic(&mnemonic, "foo");
+    assert_eq!(
+        master_key.secret_key,
+        hex!("70531039904019351e1afb361cd1b312a4d0565d4ff9f8062d38acf4b15cce41d7b5738d9c893feea55512a3004acb0d222c35d3e3d5cde943a15a9824cbac59")
+    );
+    assert_eq!(
+        master_key.chain_code,
+        hex!("443cf67e589614076ba01e354b1a432e0e6db3b59e37fc56b5fb0222970a010e")
+    );
+}
+
+#[test]
+fn cip1852_paths() {
+    use slip_10::{HardenedIndex, NonHardenedIndex};
+
+    assert_eq!(
+        cardano::account_path(HardenedIndex::new(1)),
+        slip_10::path!("m/1852'/1815'/1'")
+    );
+    assert_eq!(
+        cardano::address_path(
+            HardenedIndex::new(0),
+            cardano::Role::Staking,
+            NonHardenedIndex::new(0)
+        ),
+        slip_10::path!("m/1852'/1815'/0'/2/0")
+    );
+}
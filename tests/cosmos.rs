@@ -0,0 +1,47 @@
+use slip_10::{bip39::Mnemonic, cosmos, supported_curves::Secp256k1};
+
+#[test]
+fn address_of_derived_key() {
+    let mnemonic: Mnemonic = "abandon abandon abandon abandon abandon abandon \
+        abandon abandon abandon abandon abandon about"
+        .parse()
+        .unwrap();
+    let master_key = slip_10::derive_master_key_from_mnemonic::<Secp256k1>(&mnemonic, "");
+    let master_key = slip_10::ExtendedKeyPair::from(master_key);
+    let key =
+        slip_10::derive_child_key_pair_with_path(&master_key, slip_10::path!("m/44'/118'/0'/0/0"));
+
+    assert_eq!(
+        cosmos::cosmos_address(key.public_key(), "cosmos")
+            .unwrap()
+            .to_string(),
+        "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4"
+    );
+    assert!(cosmos::cosmos_address(key.public_key(), "osmo")
+        .unwrap()
+        .to_string()
+        .starts_with("osmo1"));
+}
+
+#[test]
+fn invalid_hrp() {
+    let key = slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(&[0u8; 32]).unwrap());
+    let key = slip_10::ExtendedKeyPair::from(key);
+
+    assert!(cosmos::cosmos_address(key.public_key(), "").is_err());
+    assert!(cosmos::cosmos_address(key.public_key(), "cosmos hub").is_err());
+    assert!(cosmos::cosmos_address(key.public_key(), &"a".repeat(84)).is_err());
+}
+
+#[test]
+fn hrp_too_long_for_address() {
+    let key = slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(&[0u8; 32]).unwrap());
+    let key = slip_10::ExtendedKeyPair::from(key);
+
+    // hrp, separator, 32 characters of data and 6 characters of checksum fit into 90 characters
+    let address = cosmos::cosmos_address(key.public_key(), &"a".repeat(51))
+        .unwrap()
+        .to_string();
+    assert_eq!(address.len(), 90);
+    assert!(cosmos::cosmos_address(key.public_key(), &"a".repeat(52)).is_err());
+}
@@ -0,0 +1,48 @@
+use slip_10::{
+    bip44::Change,
+    descriptor::{Descriptor, ScriptType},
+    supported_curves::Secp256k1,
+    watch_only::WatchOnlyAccount,
+};
+
+#[test]
+fn checksum_test_vectors() {
+    let vectors = [
+        ("raw(deadbeef)", "89f8spxm"),
+        (
+            "sh(multi(2,[00000000/111'/222]xprvA1RpRA33e1JQ7ifknakTFpgNXPmW2YvmhqLQYMmrj4xJXXWYpDPS3xz7iAxn8L39njGVyuoseXzU6rcxFLJ8HFsTjSyQbLYnMpCqE2VbFWc,xprv9uPDJpEQgRQfDcW7BkF7eTya6RPxXeJCqCJGHuCJ4GiRVLzkTXBAJMu2qaMWPrS7AANYqdq6vcBcBUdJCVVFceUvJFjaPdGZ2y9WACViL4L/0))",
+            "ggrsrxfy",
+        ),
+    ];
+    for (descriptor, expected) in vectors {
+        let checksum = slip_10::descriptor::checksum(descriptor).unwrap();
+        assert_eq!(core::str::from_utf8(&checksum).unwrap(), expected);
+    }
+
+    assert!(slip_10::descriptor::checksum("raw(deadbeef)\u{e9}").is_none());
+}
+
+/// BIP86 test vector: `abandon abandon ... about` mnemonic, account `m/86'/0'/0'`
+#[test]
+fn bip86_descriptor() {
+    let mnemonic: slip_10::bip39::Mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about".parse().unwrap();
+    let master_key = slip_10::derive_master_key_from_mnemonic::<Secp256k1>(&mnemonic, "");
+    let master_key = slip_10::ExtendedKeyPair::from(master_key);
+
+    let account =
+        WatchOnlyAccount::from_master_key(&master_key, "m/86'/0'/0'".parse().unwrap()).unwrap();
+    let receive = Descriptor::new(ScriptType::Tr, &account, Change::External);
+    assert_eq!(
+        receive.to_string(),
+        "tr([73c5da0a/86'/0'/0']xpub6BgBgsespWvERF3LHQu6CnqdvfEvtMcQjYrcRzx53QJjSxarj2afYWcLteoGVky7D3UKDP9QyrLprQ3VCECoY49yfdDEHGCtMMj92pReUsQ/0/*)#rg247h69"
+    );
+
+    let change = Descriptor::new(ScriptType::ShWpkh, &account, Change::Internal).to_string();
+    let (descriptor, checksum) = change.split_once('#').unwrap();
+    assert!(descriptor.starts_with("sh(wpkh([73c5da0a/86'/0'/0']xpub"));
+    assert!(descriptor.ends_with("/1/*))"));
+    assert_eq!(
+        slip_10::descriptor::checksum(descriptor).unwrap(),
+        checksum.as_bytes()
+    );
+}
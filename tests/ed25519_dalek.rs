@@ -0,0 +1,67 @@
+use hex_literal::hex;
+use slip_10::{ed25519, H};
+
+fn key_pair() -> ed25519::ExtendedKeyPair {
+    let seed = slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap();
+    let master_key = ed25519::ExtendedKeyPair::from(ed25519::derive_master_key(&seed));
+    master_key.derive_path([(44 + H).try_into().unwrap(), (501 + H).try_into().unwrap()])
+}
+
+#[test]
+fn keys_match() {
+    let key_pair = key_pair();
+    assert_eq!(
+        key_pair.secret_key().to_signing_key().verifying_key(),
+        key_pair.public_key().to_verifying_key()
+    );
+    assert_eq!(
+        key_pair.public_key().to_verifying_key().to_bytes()[..],
+        key_pair.public_key().public_key.to_bytes(true)[..]
+    );
+}
+
+#[test]
+fn sign_and_verify() {
+    let key_pair = key_pair();
+
+    let signature = key_pair.sign(b"message");
+    assert_eq!(signature, key_pair.secret_key().sign(b"message"));
+    key_pair.verify(b"message", &signature).unwrap();
+    key_pair
+        .public_key()
+        .verify(b"message", &signature)
+        .unwrap();
+    assert!(key_pair.verify(b"another message", &signature).is_err());
+
+    let another_key = key_pair.derive_child(H.try_into().unwrap());
+    assert!(another_key.verify(b"message", &signature).is_err());
+
+    // Keys implement `signature` traits
+    use ed25519::ed25519_dalek::{Signer, Verifier};
+    let signature: ed25519::ed25519_dalek::Signature = Signer::sign(&key_pair, b"message");
+    Verifier::verify(key_pair.public_key(), b"message", &signature).unwrap();
+    Verifier::verify(&key_pair, b"message", &signature).unwrap();
+}
+
+/// Test vector 1 from RFC 8032
+#[test]
+fn rfc8032_test_vector() {
+    let secret_key = ed25519::ExtendedSecretKey {
+        secret_key: hex!("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60"),
+        chain_code: slip_10::ChainCode::new([0u8; 32]),
+    };
+    let key_pair = ed25519::ExtendedKeyPair::from(secret_key);
+    assert_eq!(
+        key_pair.public_key().to_verifying_key().to_bytes(),
+        hex!("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a")
+    );
+
+    let signature = key_pair.sign(b"");
+    assert_eq!(
+        signature.to_bytes(),
+        hex!(
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+        )
+    );
+    key_pair.verify(b"", &signature).unwrap();
+}
@@ -0,0 +1,57 @@
+use hex_literal::hex;
+use slip_10::eip2333;
+
+struct TestVector {
+    seed: &'static [u8],
+    master_sk: [u8; 32],
+    child_index: u32,
+    child_sk: [u8; 32],
+}
+
+/// Test vectors defined in https://eips.ethereum.org/EIPS/eip-2333#test-cases
+const TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        seed: &hex!("c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"),
+        master_sk: hex!("0d7359d57963ab8fbbde1852dcf553fedbc31f464d80ee7d40ae683122b45070"),
+        child_index: 0,
+        child_sk: hex!("2d18bd6c14e6d15bf8b5085c9b74f3daae3b03cc2014770a599d8c1539e50f8e"),
+    },
+    TestVector {
+        seed: &hex!("3141592653589793238462643383279502884197169399375105820974944592"),
+        master_sk: hex!("41c9e07822b092a93fd6797396338c3ada4170cc81829fdfce6b5d34bd5e7ec7"),
+        child_index: 3141592653,
+        child_sk: hex!("384843fad5f3d777ea39de3e47a8f999ae91f89e42bffa993d91d9782d152a0f"),
+    },
+    TestVector {
+        seed: &hex!("0099FF991111002299DD7744EE3355BBDD8844115566CC55663355668888CC00"),
+        master_sk: hex!("3cfa341ab3910a7d00d933d8f7c4fe87c91798a0397421d6b19fd5b815132e80"),
+        child_index: 4294967295,
+        child_sk: hex!("40e86285582f35b28821340f6a53b448588efa575bc4d88c32ef8567b8d9479b"),
+    },
+    TestVector {
+        seed: &hex!("d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3"),
+        master_sk: hex!("2a0e28ffa5fbbe2f8e7aad4ed94f745d6bf755c51182e119bb1694fe61d3afca"),
+        child_index: 42,
+        child_sk: hex!("455c0dc9fccb3395825d92a60d2672d69416be1c2578a87a7a3d3ced11ebb88d"),
+    },
+];
+
+#[test]
+fn test_vectors() {
+    for vector in TEST_VECTORS {
+        let master_sk = eip2333::derive_master_sk(vector.seed).unwrap();
+        assert_eq!(master_sk.as_be_bytes(), &vector.master_sk);
+
+        let child_sk = master_sk.derive_child(vector.child_index);
+        assert_eq!(child_sk.as_be_bytes(), &vector.child_sk);
+        assert_eq!(
+            eip2333::derive_sk_with_path(&master_sk, [vector.child_index]),
+            child_sk
+        );
+    }
+}
+
+#[test]
+fn short_seed_is_rejected() {
+    assert!(eip2333::derive_master_sk(&[0u8; 31]).is_err());
+}
@@ -0,0 +1,124 @@
+use hex_literal::hex;
+use slip_10::{
+    electrum::{self, SeedType},
+    errors::ElectrumSeedError,
+    supported_curves::Secp256k1,
+    ExtendedKeyPair, HardenedIndex, NonHardenedIndex,
+};
+
+/// Test vectors defined in https://github.com/spesmilo/electrum/blob/master/tests/test_mnemonic.py
+#[test]
+fn test_vectors() {
+    let vectors = [
+        (
+            "wild father tree among universe such mobile favorite target dynamic credit identify",
+            "",
+            SeedType::Segwit,
+            hex!("aac2a6302e48577ab4b46f23dbae0774e2e62c796f797d0a1b5faeb528301e3064342dafb79069e7c4c6b8c38ae11d7a973bec0d4f70626f8cc5184a8d0b0756"),
+        ),
+        (
+            "wild father tree among universe such mobile favorite target dynamic credit identify",
+            "Did you ever hear the tragedy of Darth Plagueis the Wise?",
+            SeedType::Segwit,
+            hex!("4aa29f2aeb0127efb55138ab9e7be83b36750358751906f86c662b21a1ea1370f949e6d1a12fa56d3d93cadda93038c76ac8118597364e46f5156fde6183c82f"),
+        ),
+        (
+            "なのか ひろい しなん まなぶ つぶす さがす おしゃれ かわく おいかける けさき かいとう さたん",
+            "",
+            SeedType::Standard,
+            hex!("d3eaf0e44ddae3a5769cb08a26918e8b308258bcb057bb704c6f69713245c0b35cb92c03df9c9ece5eff826091b4e74041e010b701d44d610976ce8bfb66a8ad"),
+        ),
+        (
+            "なのか ひろい しなん まなぶ つぶす さがす おしゃれ かわく おいかける けさき かいとう さたん",
+            "Ｗｈｙ　ｄｏ　ｗｅ　ｆａｌｌ？",
+            SeedType::Standard,
+            hex!("b03c1f6eccf7104e64157cf37f77b37f1c0cff9b880a470d1c144edb17bd573ffb28ef516fec46de04cfc248605e9cee63e4012f8f925958f170328fd6c60ad4"),
+        ),
+        (
+            "眼 悲 叛 改 节 跃 衡 响 疆 股 遂 冬",
+            "",
+            SeedType::Segwit,
+            hex!("0b9077db7b5a50dbb6f61821e2d35e255068a5847e221138048a20e12d80b673ce306b6fe7ac174ebc6751e11b7037be6ee9f17db8040bb44f8466d519ce2abf"),
+        ),
+    ];
+
+    for (mnemonic, passphrase, expected_type, expected_seed) in vectors {
+        let (seed_type, seed) = electrum::to_seed(mnemonic, passphrase).unwrap();
+        assert_eq!(seed_type, expected_type);
+        assert_eq!(seed.as_bytes(), expected_seed);
+
+        let (_, master_key) = electrum::derive_master_key(mnemonic, passphrase).unwrap();
+        assert_eq!(
+            master_key,
+            slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(&expected_seed).unwrap())
+        );
+    }
+}
+
+#[test]
+fn normalize() {
+    assert_eq!(
+        electrum::normalize("  Wild FATHER\ttree  among "),
+        "wild father tree among"
+    );
+    assert_eq!(electrum::normalize("café"), "cafe");
+    assert_eq!(electrum::normalize("眼 悲 叛 abc 冬"), "眼悲叛 abc 冬");
+    assert_eq!(
+        electrum::normalize("Ｗｈｙ　ｄｏ　ｗｅ　ｆａｌｌ？"),
+        "why do we fall?"
+    );
+}
+
+#[test]
+fn seed_type() {
+    assert_eq!(
+        electrum::seed_type(
+            "actress park venue ensure cloth winter welcome assist park peace crane toward"
+        ),
+        Some(SeedType::TwoFactor)
+    );
+
+    // BIP39 mnemonic is not an Electrum seed
+    let bip39 = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+        abandon about";
+    assert_eq!(electrum::seed_type(bip39), None);
+    assert!(matches!(
+        electrum::to_seed(bip39, ""),
+        Err(ElectrumSeedError::InvalidVersion)
+    ));
+}
+
+#[test]
+fn address_keys() {
+    let segwit =
+        "wild father tree among universe such mobile favorite target dynamic credit identify";
+    let (_, master_key) = electrum::derive_master_key(segwit, "").unwrap();
+    let master_key = ExtendedKeyPair::from(master_key);
+    let key = electrum::derive_address_key(segwit, "", true, NonHardenedIndex::new(5)).unwrap();
+    let expected = slip_10::derive_child_key_pair_with_path(
+        &master_key,
+        [
+            HardenedIndex::new(0).into(),
+            NonHardenedIndex::new(1).into(),
+            slip_10::ChildIndex::from(NonHardenedIndex::new(5)),
+        ],
+    );
+    assert_eq!(key, expected);
+
+    let standard = "なのか ひろい しなん まなぶ つぶす さがす おしゃれ かわく おいかける けさき かいとう さたん";
+    let (_, master_key) = electrum::derive_master_key(standard, "").unwrap();
+    let master_key = ExtendedKeyPair::from(master_key);
+    let key = electrum::derive_address_key(standard, "", false, NonHardenedIndex::new(0)).unwrap();
+    let expected = slip_10::derive_child_key_pair_with_path(
+        &master_key,
+        [NonHardenedIndex::new(0), NonHardenedIndex::new(0)],
+    );
+    assert_eq!(key, expected);
+
+    let two_factor =
+        "actress park venue ensure cloth winter welcome assist park peace crane toward";
+    assert!(matches!(
+        electrum::derive_address_key(two_factor, "", false, NonHardenedIndex::new(0)),
+        Err(ElectrumSeedError::TwoFactor)
+    ));
+}
@@ -0,0 +1,39 @@
+use hex_literal::hex;
+use slip_10::{bip39::Mnemonic, ethereum, supported_curves::Secp256k1};
+
+#[test]
+fn address_of_derived_key() {
+    let mnemonic: Mnemonic = "abandon abandon abandon abandon abandon abandon \
+        abandon abandon abandon abandon abandon about"
+        .parse()
+        .unwrap();
+    let master_key = slip_10::derive_master_key_from_mnemonic::<Secp256k1>(&mnemonic, "");
+    let master_key = slip_10::ExtendedKeyPair::from(master_key);
+    let key =
+        slip_10::derive_child_key_pair_with_path(&master_key, slip_10::path!("m/44'/60'/0'/0/0"));
+
+    let address = ethereum::ethereum_address(key.public_key());
+    assert_eq!(address, hex!("9858EfFD232B4033E47d90003D41EC34EcaEda94"));
+    assert_eq!(
+        ethereum::to_checksum_address(&address).to_string(),
+        "0x9858EfFD232B4033E47d90003D41EC34EcaEda94"
+    );
+}
+
+/// Test vectors defined in https://eips.ethereum.org/EIPS/eip-55
+#[test]
+fn eip55_checksum() {
+    let addresses = [
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+        "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+        "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+    ];
+    for expected in addresses {
+        let address: [u8; 20] = hex::decode(&expected[2..]).unwrap().try_into().unwrap();
+        assert_eq!(
+            ethereum::to_checksum_address(&address).to_string(),
+            expected
+        );
+    }
+}
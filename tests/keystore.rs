@@ -0,0 +1,119 @@
+use slip_10::{
+    errors::UnsealError,
+    keystore::{KdfParams, SEALED_LEN},
+    supported_curves::{Secp256k1, Secp256r1},
+    ExtendedSecretKey,
+};
+
+/// Cheap parameters so tests run fast
+const PARAMS: KdfParams = KdfParams {
+    memory_kib: 64,
+    iterations: 1,
+    parallelism: 1,
+};
+
+#[test]
+fn seal_unseal() {
+    let mut rng = rand_core::OsRng;
+    let master_key = slip_10::derive_master_key::<Secp256k1>(
+        slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap(),
+    );
+
+    let sealed = master_key
+        .seal_with_params(b"password", PARAMS, &mut rng)
+        .unwrap();
+    assert_eq!(sealed.len(), SEALED_LEN);
+    let unsealed = ExtendedSecretKey::<Secp256k1>::unseal(&sealed, b"password").unwrap();
+    assert_eq!(unsealed.to_bytes(), master_key.to_bytes());
+
+    // Sealing is randomized
+    let sealed2 = master_key
+        .seal_with_params(b"password", PARAMS, &mut rng)
+        .unwrap();
+    assert_ne!(sealed, sealed2);
+}
+
+#[test]
+fn unseal_errors() {
+    let mut rng = rand_core::OsRng;
+    let master_key = slip_10::derive_master_key::<Secp256r1>(
+        slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap(),
+    );
+    let sealed = master_key
+        .seal_with_params(b"password", PARAMS, &mut rng)
+        .unwrap();
+
+    assert!(matches!(
+        ExtendedSecretKey::<Secp256r1>::unseal(&sealed, b"wrong password"),
+        Err(UnsealError::Decryption)
+    ));
+    // Sealed for another curve
+    assert!(matches!(
+        ExtendedSecretKey::<Secp256k1>::unseal(&sealed, b"password"),
+        Err(UnsealError::Decryption)
+    ));
+    assert!(matches!(
+        ExtendedSecretKey::<Secp256r1>::unseal(&sealed[1..], b"password"),
+        Err(UnsealError::InvalidLength)
+    ));
+
+    let mut tampered = sealed;
+    tampered[0] = 2;
+    assert!(matches!(
+        ExtendedSecretKey::<Secp256r1>::unseal(&tampered, b"password"),
+        Err(UnsealError::UnsupportedVersion)
+    ));
+    let mut tampered = sealed;
+    tampered[SEALED_LEN - 20] ^= 1;
+    assert!(matches!(
+        ExtendedSecretKey::<Secp256r1>::unseal(&tampered, b"password"),
+        Err(UnsealError::Decryption)
+    ));
+    // KDF params are authenticated
+    let mut tampered = sealed;
+    tampered[8] += 1;
+    assert!(matches!(
+        ExtendedSecretKey::<Secp256r1>::unseal(&tampered, b"password"),
+        Err(UnsealError::Decryption)
+    ));
+    let mut tampered = sealed;
+    tampered[1..5].copy_from_slice(&[0; 4]);
+    assert!(matches!(
+        ExtendedSecretKey::<Secp256r1>::unseal(&tampered, b"password"),
+        Err(UnsealError::InvalidKdfParams)
+    ));
+}
+
+#[test]
+fn unseal_rejects_excessive_kdf_params() {
+    let mut rng = rand_core::OsRng;
+    let master_key = slip_10::derive_master_key::<Secp256k1>(
+        slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap(),
+    );
+    let sealed = master_key
+        .seal_with_params(b"password", PARAMS, &mut rng)
+        .unwrap();
+
+    // Rejected before running the KDF, i.e. without allocating the memory
+    for (range, value) in [(1..5, u32::MAX), (5..9, u32::MAX), (9..13, 1 << 20)] {
+        let mut tampered = sealed;
+        tampered[range].copy_from_slice(&value.to_be_bytes());
+        assert!(matches!(
+            ExtendedSecretKey::<Secp256k1>::unseal(&tampered, b"password"),
+            Err(UnsealError::InvalidKdfParams)
+        ));
+    }
+
+    let max = KdfParams {
+        memory_kib: 32,
+        ..PARAMS
+    };
+    assert!(matches!(
+        ExtendedSecretKey::<Secp256k1>::unseal_with_max_params(&sealed, b"password", max),
+        Err(UnsealError::InvalidKdfParams)
+    ));
+    let unsealed =
+        ExtendedSecretKey::<Secp256k1>::unseal_with_max_params(&sealed, b"password", PARAMS)
+            .unwrap();
+    assert_eq!(unsealed.to_bytes(), master_key.to_bytes());
+}
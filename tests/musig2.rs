@@ -0,0 +1,70 @@
+use generic_ec::{Point, Scalar};
+use slip_10::{musig2, supported_curves::Secp256k1, NonHardenedIndex};
+
+#[test]
+fn bip328_chain_code() {
+    let key = musig2::aggregate_extended_key(Point::generator().to_point());
+    assert_eq!(
+        key.chain_code,
+        hex_literal::hex!("868087ca02a6f974c4598924c36b57762d32cb45717167e300622c7167e38965")
+    );
+}
+
+/// Signature produced by signers with the tweak applied during aggregation verifies against the
+/// derived child key
+#[test]
+fn signing_for_child_key() {
+    // Secret keys of signers, and KeyAgg coefficients
+    let secret_keys = [Scalar::<Secp256k1>::from(3), Scalar::from(5)];
+    let coefficients = [Scalar::<Secp256k1>::from(7), Scalar::from(11)];
+    let aggregate_public_key: Point<Secp256k1> = secret_keys
+        .iter()
+        .zip(&coefficients)
+        .map(|(x, a)| Point::generator() * (a * x))
+        .sum();
+
+    let aggregate_key = musig2::aggregate_extended_key(aggregate_public_key);
+    let path = [NonHardenedIndex::new(0), NonHardenedIndex::new(5)];
+
+    let coordinator = musig2::Coordinator::new(aggregate_key);
+    let child_public_key = coordinator.derive(path).child_public_key;
+    assert_eq!(
+        child_public_key,
+        slip_10::derive_child_public_key_with_path(&aggregate_key, path)
+    );
+
+    let participant = musig2::Participant::new(aggregate_key);
+    let tweak = participant.tweak(path, &child_public_key).unwrap();
+
+    // Simplified Schnorr signing: s = sum(k_i + e a_i x_i) + e t
+    let nonces = [Scalar::<Secp256k1>::from(13), Scalar::from(17)];
+    let challenge = Scalar::<Secp256k1>::from(19);
+    let partial_sigs = secret_keys
+        .iter()
+        .zip(&coefficients)
+        .zip(&nonces)
+        .map(|((x, a), k)| k + challenge * a * x);
+    let s = partial_sigs.sum::<Scalar<Secp256k1>>() + challenge * tweak;
+    let r: Point<Secp256k1> = nonces.iter().map(|k| Point::generator() * k).sum();
+
+    assert_eq!(
+        Point::generator() * s,
+        r + child_public_key.public_key * challenge
+    );
+}
+
+#[test]
+fn participant_rejects_wrong_child_key() {
+    let aggregate_key =
+        musig2::aggregate_extended_key(Point::generator() * Scalar::<Secp256k1>::from(42));
+    let participant = musig2::Participant::new(aggregate_key);
+
+    let child_public_key =
+        slip_10::derive_child_public_key(&aggregate_key, NonHardenedIndex::new(1));
+    assert!(participant
+        .tweak([NonHardenedIndex::new(2)], &child_public_key)
+        .is_err());
+    assert!(participant
+        .tweak([NonHardenedIndex::new(1)], &child_public_key)
+        .is_ok());
+}
@@ -0,0 +1,29 @@
+use hex_literal::hex;
+use slip_10::{bip39::Mnemonic, nip06, HardenedIndex};
+
+/// Test vectors defined in https://github.com/nostr-protocol/nips/blob/master/06.md
+#[test]
+fn test_vectors() {
+    let vectors = [
+        (
+            "leader monkey parrot ring guide accident before fence cannon height naive bean",
+            hex!("7f7ff03d123792d6ac594bfa67bf6d0c0ab55b6b1fdb6249303fe861f1ccba9a"),
+            hex!("17162c921dc4d2518f9a101db33695df1afb56ab82f5ff3e5da6eec3ca5cd917"),
+        ),
+        (
+            "what bleak badge arrange retreat wolf trade produce cricket blur garlic valid proud rude strong choose busy staff weather area salt hollow arm fade",
+            hex!("c15d739894c81a2fcfd3a2df85a0d2c0dbc47a280d092799f144d73d7ae78add"),
+            hex!("d41b22899549e1f3d335a31002cfd382174006e166d3e658e3a5eecdb6463573"),
+        ),
+    ];
+
+    for (mnemonic, secret_key, public_key) in vectors {
+        let mnemonic: Mnemonic = mnemonic.parse().unwrap();
+        let keys = nip06::derive_keys_from_mnemonic(&mnemonic, "", HardenedIndex::new(0));
+        assert_eq!(
+            keys.secret_key.as_ref().to_be_bytes().as_bytes(),
+            secret_key
+        );
+        assert_eq!(keys.public_key, public_key);
+    }
+}
@@ -0,0 +1,379 @@
+use slip_10::{DerivationPath, StaticPath, H};
+
+#[test]
+fn parse_path() {
+    let cases: &[(&str, &[u32])] = &[
+        ("m", &[]),
+        ("m/0", &[0]),
+        ("m/44'/0'/0'/0/1", &[44 + H, H, H, 0, 1]),
+        ("m/44h/60H/0'", &[44 + H, 60 + H, H]),
+        ("44'/0'", &[44 + H, H]),
+        ("m/2147483647'", &[u32::MAX]),
+    ];
+
+    for (path, expected) in cases {
+        let parsed: DerivationPath = path.parse().unwrap();
+        let parsed = parsed.iter().map(u32::from).collect::<Vec<_>>();
+        assert_eq!(parsed, *expected, "path: {path}");
+    }
+}
+
+#[test]
+fn display_path() {
+    let cases: &[(&str, &str)] = &[
+        ("m", "m"),
+        ("m/0", "m/0"),
+        ("m/44'/0'/0'/0/1", "m/44'/0'/0'/0/1"),
+        ("m/44h/60H/0'", "m/44'/60'/0'"),
+        ("44'/0'", "m/44'/0'"),
+    ];
+
+    for (path, expected) in cases {
+        let parsed: DerivationPath = path.parse().unwrap();
+        assert_eq!(parsed.to_string(), *expected);
+    }
+}
+
+#[test]
+fn path_macro() {
+    macro_rules! check {
+        ($($path:literal),* $(,)?) => {$({
+            let expected: DerivationPath = $path.parse().unwrap();
+            let path = slip_10::path!($path);
+            assert!(
+                path.iter().map(|i| u32::from(*i)).eq(expected.iter().map(u32::from)),
+                "path: {}",
+                $path
+            );
+        })*};
+    }
+    check!(
+        "m",
+        "m/0",
+        "m/44'/0'/0'/0/1",
+        "m/44h/60H/0'",
+        "44'/0'",
+        "m/2147483647'",
+    );
+}
+
+#[test]
+fn parse_invalid_path() {
+    let cases: &[&str] = &[
+        "",
+        "m/",
+        "m//1",
+        "mm/1",
+        "m/1/",
+        "m/-1",
+        "m/1''",
+        "m/2147483648",
+        "m/2147483648'",
+        "m/+5",
+        "m/a",
+    ];
+
+    for path in cases {
+        assert!(path.parse::<DerivationPath>().is_err(), "path: {path}");
+    }
+}
+
+#[test]
+fn child_index_roundtrip() {
+    let cases: &[(&str, u32)] = &[("0", 0), ("5", 5), ("5'", 5 + H), ("2147483647'", u32::MAX)];
+    for (s, expected) in cases {
+        let index: slip_10::ChildIndex = s.parse().unwrap();
+        assert_eq!(u32::from(index), *expected);
+        assert_eq!(index.to_string(), *s);
+    }
+
+    for s in ["5h", "5H", "2147483653"] {
+        let index: slip_10::ChildIndex = s.parse().unwrap();
+        assert_eq!(u32::from(index), 5 + H);
+    }
+    for s in [
+        "",
+        "'",
+        "5''",
+        "2147483648'",
+        "4294967296",
+        "-1",
+        "+5",
+        "+5'",
+    ] {
+        assert!(s.parse::<slip_10::ChildIndex>().is_err(), "index: {s}");
+    }
+}
+
+#[test]
+fn parse_hardened_and_non_hardened_index() {
+    for s in ["5'", "5h", "5H", "2147483653"] {
+        let index: slip_10::HardenedIndex = s.parse().unwrap();
+        assert_eq!(*index, 5 + H);
+        assert!(
+            s.parse::<slip_10::NonHardenedIndex>().is_err(),
+            "index: {s}"
+        );
+    }
+
+    let index: slip_10::NonHardenedIndex = "5".parse().unwrap();
+    assert_eq!(*index, 5);
+    assert!("5".parse::<slip_10::HardenedIndex>().is_err());
+
+    for s in ["+5", "+5'", "2147483648'"] {
+        assert!(s.parse::<slip_10::HardenedIndex>().is_err(), "index: {s}");
+        assert!(
+            s.parse::<slip_10::NonHardenedIndex>().is_err(),
+            "index: {s}"
+        );
+    }
+}
+
+#[test]
+#[cfg(not(feature = "alloc"))]
+fn parse_too_long_path() {
+    let path = ["0"; DerivationPath::MAX_LEN].join("/");
+    let parsed: DerivationPath = path.parse().unwrap();
+    assert_eq!(parsed.len(), DerivationPath::MAX_LEN);
+
+    let path = format!("{path}/0");
+    assert!(matches!(
+        path.parse::<DerivationPath>(),
+        Err(slip_10::errors::ParsePathError::TooLong(_))
+    ));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn parse_long_path() {
+    let path = ["1'"; 100].join("/");
+    let parsed: DerivationPath = path.parse().unwrap();
+    assert_eq!(parsed.len(), 100);
+    assert!(parsed.iter().all(|i| u32::from(i) == 1 + H));
+}
+
+#[test]
+fn push_pop_extend() {
+    let mut path: DerivationPath = "m/44'/0'".parse().unwrap();
+    let suffix: DerivationPath = "0'/1".parse().unwrap();
+    path.extend(&suffix).unwrap();
+    path.push(2).unwrap();
+    assert_eq!(path.to_string(), "m/44'/0'/0'/1/2");
+
+    assert_eq!(path.pop().map(u32::from), Some(2));
+    assert_eq!(path.to_string(), "m/44'/0'/0'/1");
+
+    let mut path = DerivationPath::new();
+    assert!(path.pop().is_none());
+}
+
+#[test]
+fn static_path() {
+    let path: StaticPath<5> = "m/44'/0'/0'/0/1".parse().unwrap();
+    assert_eq!(path.len(), 5);
+    assert_eq!(path.to_string(), "m/44'/0'/0'/0/1");
+    assert_eq!(
+        path.iter().map(u32::from).collect::<Vec<_>>(),
+        [44 + H, H, H, 0, 1]
+    );
+
+    assert!(matches!(
+        "m/44'/0'/0'/0/1/2".parse::<StaticPath<5>>(),
+        Err(slip_10::errors::ParsePathError::TooLong(_))
+    ));
+    assert!("m".parse::<StaticPath<0>>().unwrap().is_empty());
+
+    let mut path = StaticPath::<2>::new();
+    path.extend([1, 2]).unwrap();
+    assert!(path.push(3).is_err());
+    assert_eq!(path.pop().map(u32::from), Some(2));
+    path.push(3).unwrap();
+    assert_eq!(path.to_string(), "m/1/3");
+}
+
+#[test]
+fn path_derivation_matches_indexes_derivation() {
+    let seed = slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap();
+    let master_key = slip_10::derive_master_key::<slip_10::supported_curves::Secp256k1>(&seed);
+    let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+
+    let path: DerivationPath = "m/44'/0'/0'/0/1".parse().unwrap();
+    let key1 = slip_10::derive_child_key_pair_with_path(&master_key_pair, &path);
+    let key2 = slip_10::derive_child_key_pair_with_path(&master_key_pair, [44 + H, H, H, 0, 1]);
+
+    let key3 = master_key_pair
+        .derive_child(44 + H)
+        .derive_child(H)
+        .derive_path([H, 0, 1]);
+
+    assert_eq!(key1.public_key().public_key, key2.public_key().public_key);
+    assert_eq!(key1.chain_code(), key2.chain_code());
+    assert_eq!(key1.public_key().public_key, key3.public_key().public_key);
+    assert_eq!(key1.chain_code(), key3.chain_code());
+}
+
+#[test]
+fn public_derivation_rejects_hardened_path() {
+    let seed = slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap();
+    let master_key = slip_10::derive_master_key::<slip_10::supported_curves::Secp256k1>(&seed);
+    let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+
+    let path: DerivationPath = "m/1/10/2".parse().unwrap();
+    let child_public_key = slip_10::try_derive_child_public_key_with_path(
+        master_key_pair.public_key(),
+        path.iter().map(slip_10::NonHardenedIndex::try_from),
+    )
+    .unwrap();
+    let child_key_pair = slip_10::derive_child_key_pair_with_path(&master_key_pair, &path);
+    assert_eq!(
+        child_public_key.public_key,
+        child_key_pair.public_key().public_key
+    );
+
+    let child_public_key2 = master_key_pair.public_key().derive_path(&path).unwrap();
+    assert_eq!(child_public_key.public_key, child_public_key2.public_key);
+
+    let path: DerivationPath = "m/1/10'/2".parse().unwrap();
+    let result = slip_10::try_derive_child_public_key_with_path(
+        master_key_pair.public_key(),
+        path.iter().map(slip_10::NonHardenedIndex::try_from),
+    );
+    assert!(result.is_err());
+    let err = master_key_pair.public_key().derive_path(&path).unwrap_err();
+    assert!(matches!(
+        err,
+        slip_10::errors::DeriveError::HardenedInPublicPath { level: 1, .. }
+    ));
+    assert_eq!(err.child_index(), Some(slip_10::ChildIndex::from(10 + H)));
+}
+
+#[test]
+fn bip44_path() {
+    use slip_10::{
+        bip44::{Bip44Path, Change, Purpose},
+        HardenedIndex, NonHardenedIndex,
+    };
+
+    let account = Bip44Path::new(Purpose::Bip84, HardenedIndex::new(1), HardenedIndex::new(2));
+    let path = account
+        .change(Change::Internal)
+        .index(NonHardenedIndex::new(7))
+        .to_path();
+    let expected: DerivationPath = "m/84'/1'/2'/1/7".parse().unwrap();
+    assert!(path
+        .iter()
+        .map(u32::from)
+        .eq(expected.iter().map(u32::from)));
+
+    let path = DerivationPath::from(account);
+    let expected: DerivationPath = "m/84'/1'/2'".parse().unwrap();
+    assert!(path
+        .iter()
+        .map(u32::from)
+        .eq(expected.iter().map(u32::from)));
+}
+
+#[test]
+fn slip44_coin_type() {
+    use slip_10::{
+        bip44::{Bip44Path, Purpose},
+        slip44::CoinType,
+    };
+
+    let path = Bip44Path::new(
+        Purpose::Bip44,
+        CoinType::Solana.into(),
+        H.try_into().unwrap(),
+    );
+    let expected: DerivationPath = "m/44'/501'/0'".parse().unwrap();
+    assert!(path
+        .to_path()
+        .iter()
+        .map(u32::from)
+        .eq(expected.iter().map(u32::from)));
+}
+
+#[test]
+fn const_index_constructors() {
+    const HARDENED: slip_10::HardenedIndex = slip_10::HardenedIndex::new(5);
+    const NON_HARDENED: slip_10::NonHardenedIndex = slip_10::NonHardenedIndex::new(5);
+    assert_eq!(*HARDENED, 5 + H);
+    assert_eq!(*NON_HARDENED, 5);
+
+    assert_eq!(*slip_10::HardenedIndex::new(H - 1), u32::MAX);
+    assert!(slip_10::HardenedIndex::checked_new(H).is_none());
+    assert!(slip_10::NonHardenedIndex::checked_new(H).is_none());
+    assert_eq!(
+        slip_10::NonHardenedIndex::checked_new(H - 1).map(|i| *i),
+        Some(H - 1)
+    );
+}
+
+#[test]
+fn indexes_eq_and_hash() {
+    use std::collections::HashSet;
+
+    use slip_10::{ChildIndex, HardenedIndex, NonHardenedIndex};
+
+    assert_eq!(
+        HardenedIndex::new(1),
+        HardenedIndex::try_from(1 + H).unwrap()
+    );
+    assert_ne!(NonHardenedIndex::new(1), NonHardenedIndex::new(2));
+    assert_eq!(ChildIndex::from(5 + H), HardenedIndex::new(5).into());
+    assert_ne!(
+        ChildIndex::from(HardenedIndex::new(5)),
+        ChildIndex::from(NonHardenedIndex::new(5))
+    );
+
+    let indexes: HashSet<ChildIndex> = [0, 1, 1, H, H].into_iter().map(ChildIndex::from).collect();
+    assert_eq!(indexes.len(), 3);
+}
+
+#[test]
+fn ordering() {
+    use std::collections::BTreeMap;
+
+    use slip_10::{ChildIndex, HardenedIndex, NonHardenedIndex};
+
+    assert!(NonHardenedIndex::new(1) < NonHardenedIndex::new(2));
+    assert!(HardenedIndex::new(1) < HardenedIndex::new(2));
+    assert!(ChildIndex::from(NonHardenedIndex::MAX) < ChildIndex::from(HardenedIndex::MIN));
+
+    let mut indexes: Vec<ChildIndex> = [H + 1, 5, H, 0].into_iter().map(Into::into).collect();
+    indexes.sort();
+    assert_eq!(
+        indexes.iter().copied().map(u32::from).collect::<Vec<_>>(),
+        [0, 5, H, H + 1]
+    );
+    assert!(indexes.binary_search(&ChildIndex::from(5)).is_ok());
+
+    let paths = [
+        "m/44'/1'",
+        "m/44'/0'/1",
+        "m",
+        "m/44'/0'",
+        "m/0/1",
+        "m/44'/0'/0",
+    ];
+    let paths: BTreeMap<DerivationPath, &str> = paths
+        .into_iter()
+        .map(|path| (path.parse().unwrap(), path))
+        .collect();
+    assert_eq!(
+        paths.values().copied().collect::<Vec<_>>(),
+        [
+            "m",
+            "m/0/1",
+            "m/44'/0'",
+            "m/44'/0'/0",
+            "m/44'/0'/1",
+            "m/44'/1'"
+        ]
+    );
+
+    let a: StaticPath<3> = "m/1/2".parse().unwrap();
+    let b: StaticPath<3> = "m/1/2/0".parse().unwrap();
+    assert!(a < b);
+    assert_eq!(a, "m/1/2".parse().unwrap());
+}
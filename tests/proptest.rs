@@ -0,0 +1,47 @@
+use proptest::prelude::*;
+use slip_10::{strategies, supported_curves::Secp256k1, ChildIndex, DerivationPath, Seed, H};
+
+proptest! {
+    #[test]
+    fn indexes_are_in_range(
+        hardened in strategies::any_hardened_index(),
+        non_hardened in strategies::any_non_hardened_index(),
+    ) {
+        prop_assert!(*hardened >= H);
+        prop_assert!(*non_hardened < H);
+        prop_assert!(matches!(ChildIndex::from(hardened), ChildIndex::Hardened(_)));
+    }
+
+    #[test]
+    fn path_respects_max_depth(
+        (max_depth, path) in (0..10usize)
+            .prop_flat_map(|max_depth| (Just(max_depth), strategies::any_path(max_depth))),
+    ) {
+        prop_assert!(path.len() <= max_depth);
+    }
+
+    #[test]
+    fn path_roundtrips_through_string(path in strategies::any_path(8)) {
+        let parsed: DerivationPath = path.to_string().parse().unwrap();
+        prop_assert_eq!(parsed, path);
+    }
+
+    #[test]
+    fn seed_is_within_bounds(seed in strategies::any_seed()) {
+        prop_assert!((Seed::MIN_LEN..=Seed::MAX_LEN).contains(&seed.as_bytes().len()));
+    }
+
+    #[test]
+    fn derivation_along_path_is_consistent(
+        seed in strategies::any_seed(),
+        path in strategies::any_path(4),
+    ) {
+        let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+        let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+        let child_key = slip_10::derive_child_key_pair_with_path(&master_key_pair, &path);
+        let expected = path.iter().fold(master_key_pair, |key, child_index| {
+            slip_10::derive_child_key_pair(&key, child_index)
+        });
+        prop_assert_eq!(child_key.public_key(), expected.public_key());
+    }
+}
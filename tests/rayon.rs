@@ -0,0 +1,27 @@
+use generic_ec::Curve;
+
+#[test]
+fn par_derive_children_matches_sequential_derivation() {
+    run::<slip_10::supported_curves::Secp256k1>();
+    run::<slip_10::supported_curves::Secp256r1>();
+}
+
+fn run<E: Curve + slip_10::SupportedCurve>() {
+    let seed = slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap();
+    let master_key = slip_10::derive_master_key::<E>(&seed);
+    let master_public_key = slip_10::ExtendedPublicKey::from(&master_key);
+
+    let children = slip_10::par_derive_children(&master_public_key, 10..300).unwrap();
+    assert_eq!(children.len(), 290);
+    for (i, child) in (10..300).zip(&children) {
+        let expected = slip_10::derive_child_public_key(&master_public_key, i.try_into().unwrap());
+        assert_eq!(child.public_key, expected.public_key);
+        assert_eq!(child.chain_code, expected.chain_code);
+    }
+
+    let last_non_hardened = slip_10::H - 1;
+    let children =
+        slip_10::par_derive_children(&master_public_key, last_non_hardened..slip_10::H).unwrap();
+    assert_eq!(children.len(), 1);
+    assert!(slip_10::par_derive_children(&master_public_key, 0..slip_10::H + 1).is_err());
+}
@@ -0,0 +1,66 @@
+use bitcoin::{bip32 as btc, NetworkKind};
+use slip_10::{
+    bip32::{Bip32ExtendedKey, Xprv, Xpub},
+    supported_curves::Secp256k1,
+    ChildIndex, H,
+};
+
+/// Key m/0'/1 of test vector 1 defined in
+/// https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#test-vector-1
+const XPUB: &str = "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ";
+const XPRV: &str = "xprv9wTYmMFdV23N2TdNG573QoEsfRrWKQgWeibmLntzniatZvR9BmLnvSxqu53Kw1UmYPxLgboyZQaXwTCg8MSY3H2EU4pWcQDnRnrVA1xe8fs";
+
+#[test]
+fn from_bitcoin() {
+    let xpub = Xpub::<Secp256k1>::from(XPUB.parse::<btc::Xpub>().unwrap());
+    assert_eq!(xpub.to_string(), XPUB);
+    assert_eq!(xpub.metadata.depth, 2);
+    assert_eq!(xpub.metadata.child_number, ChildIndex::from(1));
+
+    let xprv = Xprv::<Secp256k1>::from(XPRV.parse::<btc::Xpriv>().unwrap());
+    assert_eq!(xprv.to_string(), XPRV);
+}
+
+#[test]
+fn to_bitcoin() {
+    let xpub: Xpub<Secp256k1> = XPUB.parse().unwrap();
+    let btc_xpub = xpub.to_bitcoin(NetworkKind::Main).unwrap();
+    assert_eq!(btc_xpub.to_string(), XPUB);
+    assert_eq!(
+        btc_xpub.chain_code,
+        btc::ChainCode::from(xpub.key.chain_code)
+    );
+
+    let xprv: Xprv<Secp256k1> = XPRV.parse().unwrap();
+    let btc_xpriv = xprv.to_bitcoin(NetworkKind::Main).unwrap();
+    assert_eq!(btc_xpriv.to_string(), XPRV);
+    assert!(xprv
+        .to_bitcoin(NetworkKind::Test)
+        .unwrap()
+        .to_string()
+        .starts_with("tprv"));
+}
+
+#[test]
+fn derivation_matches() {
+    let seed = slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap();
+    let master_key = Bip32ExtendedKey::master(slip_10::derive_master_key::<Secp256k1>(&seed));
+    let child_key = master_key.derive_child_with_path([44 + H, H, 1]).unwrap();
+
+    let secp = bitcoin::secp256k1::Secp256k1::new();
+    let btc_master = master_key.to_xprv().to_bitcoin(NetworkKind::Main).unwrap();
+    let path: Vec<btc::ChildNumber> = [44 + H, H, 1]
+        .into_iter()
+        .map(|i| ChildIndex::from(i).into())
+        .collect();
+    let btc_child = btc_master.derive_priv(&secp, &path).unwrap();
+
+    assert_eq!(
+        Xprv::from(btc_child).to_string(),
+        child_key.to_xprv().to_string()
+    );
+    assert_eq!(
+        Xpub::from(btc::Xpub::from_priv(&secp, &btc_child)).to_string(),
+        child_key.to_xpub().to_string()
+    );
+}
@@ -0,0 +1,139 @@
+use slip_10::{
+    supported_curves::{Secp256k1, Secp256r1},
+    ExtendedKeyPair, ExtendedPublicKey, ExtendedSecretKey, H,
+};
+
+fn key_pair<E: generic_ec::Curve + slip_10::SupportedCurve>() -> ExtendedKeyPair<E> {
+    let seed = slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap();
+    ExtendedKeyPair::from(slip_10::derive_master_key::<E>(&seed)).derive_path([44 + H, 0])
+}
+
+#[test]
+fn k256() {
+    use k256::ecdsa::signature::{Signer, Verifier};
+
+    let key_pair = key_pair::<Secp256k1>();
+    let secret_key = key_pair.secret_key().to_k256_secret_key().unwrap();
+    let public_key = key_pair.public_key().to_k256_public_key().unwrap();
+    assert_eq!(secret_key.public_key(), public_key);
+    assert_eq!(
+        secret_key.to_bytes().as_slice(),
+        key_pair
+            .secret_key()
+            .secret_key
+            .as_ref()
+            .to_be_bytes()
+            .as_bytes()
+    );
+
+    let signing_key = key_pair.secret_key().to_k256_signing_key().unwrap();
+    let verifying_key = key_pair.public_key().to_k256_verifying_key().unwrap();
+    let signature: k256::ecdsa::Signature = signing_key.sign(b"message");
+    verifying_key.verify(b"message", &signature).unwrap();
+}
+
+#[test]
+fn p256() {
+    use p256::ecdsa::signature::{Signer, Verifier};
+
+    let key_pair = key_pair::<Secp256r1>();
+    let secret_key = key_pair.secret_key().to_p256_secret_key().unwrap();
+    let public_key = key_pair.public_key().to_p256_public_key().unwrap();
+    assert_eq!(secret_key.public_key(), public_key);
+
+    let signing_key = key_pair.secret_key().to_p256_signing_key().unwrap();
+    let verifying_key = key_pair.public_key().to_p256_verifying_key().unwrap();
+    let signature: p256::ecdsa::Signature = signing_key.sign(b"message");
+    verifying_key.verify(b"message", &signature).unwrap();
+}
+
+#[test]
+fn reject_invalid_keys() {
+    let chain_code = slip_10::ChainCode::new([0; 32]);
+    let secret_key = ExtendedSecretKey::<Secp256k1> {
+        secret_key: generic_ec::SecretScalar::new(&mut generic_ec::Scalar::zero()),
+        chain_code,
+    };
+    assert!(secret_key.to_k256_secret_key().is_err());
+
+    let public_key = ExtendedPublicKey::<Secp256r1> {
+        public_key: generic_ec::Point::zero(),
+        chain_code,
+    };
+    assert!(public_key.to_p256_public_key().is_err());
+}
+
+#[test]
+fn generic_conversion() {
+    use slip_10::rustcrypto::RustCryptoCurve;
+
+    fn check<E: RustCryptoCurve + slip_10::SupportedCurve>() {
+        let key_pair = key_pair::<E>();
+        let secret_key = key_pair.secret_key().to_rustcrypto_secret_key().unwrap();
+        let public_key = key_pair.public_key().to_rustcrypto_public_key().unwrap();
+        assert_eq!(secret_key.public_key(), public_key);
+    }
+
+    check::<Secp256k1>();
+    check::<Secp256r1>();
+}
+
+#[test]
+fn signer_and_verifier() {
+    use k256::ecdsa::signature::{Signer, Verifier};
+
+    fn sign<S: Signer<Sig>, Sig>(signer: &S) -> Sig {
+        signer.sign(b"message")
+    }
+    fn verify<V: Verifier<Sig>, Sig>(verifier: &V, signature: &Sig) -> bool {
+        verifier.verify(b"message", signature).is_ok()
+    }
+
+    let k256_key = key_pair::<Secp256k1>();
+    let signature: k256::ecdsa::Signature = sign(k256_key.secret_key());
+    assert!(verify(k256_key.public_key(), &signature));
+    assert!(verify(&k256_key, &signature));
+    assert!(!verify(&k256_key.derive_child(0), &signature));
+    // ECDSA signatures are deterministic
+    assert_eq!(sign::<_, k256::ecdsa::Signature>(&k256_key), signature);
+
+    let p256_key = key_pair::<Secp256r1>();
+    let signature: p256::ecdsa::Signature = sign(&p256_key);
+    assert!(verify(p256_key.public_key(), &signature));
+}
+
+#[test]
+fn sign_prehash() {
+    use k256::ecdsa::signature::hazmat::PrehashVerifier;
+    use sha2::Digest;
+
+    let prehash: [u8; 32] = sha2::Sha256::digest(b"message").into();
+
+    let k256_key = key_pair::<Secp256k1>();
+    let signature = k256_key.secret_key().sign_prehash(&prehash).unwrap();
+    k256_key
+        .public_key()
+        .to_k256_verifying_key()
+        .unwrap()
+        .verify_prehash(&prehash, &signature)
+        .unwrap();
+    // Same as signing the message with SHA256
+    let expected: k256::ecdsa::Signature =
+        k256::ecdsa::signature::Signer::sign(k256_key.secret_key(), b"message");
+    assert_eq!(signature, expected);
+
+    let p256_key = key_pair::<Secp256r1>();
+    let signature = p256_key.secret_key().sign_prehash(&prehash).unwrap();
+    p256_key
+        .public_key()
+        .to_p256_verifying_key()
+        .unwrap()
+        .verify_prehash(&prehash, &signature)
+        .unwrap();
+
+    let zero_key = ExtendedSecretKey::<Secp256k1> {
+        secret_key: generic_ec::SecretScalar::new(&mut generic_ec::Scalar::zero()),
+        chain_code: slip_10::ChainCode::new([0; 32]),
+    };
+    assert!(zero_key.sign_prehash(&prehash).is_err());
+}
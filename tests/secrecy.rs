@@ -0,0 +1,37 @@
+use slip_10::{
+    secrecy::{ExposeSecret, SecretBox},
+    supported_curves::Secp256k1,
+    ExtendedKeyPair, ExtendedSecretKey,
+};
+
+#[test]
+fn secret_box() {
+    let seed = slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap();
+    let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+    let expected_key_pair = ExtendedKeyPair::from(master_key.clone());
+
+    let boxed = SecretBox::new(Box::new(master_key));
+    let cloned = boxed.clone();
+    assert_eq!(boxed.expose_secret(), cloned.expose_secret());
+
+    let key_pair = SecretBox::new(Box::new(ExtendedKeyPair::from(
+        boxed.expose_secret().clone(),
+    )));
+    assert_eq!(key_pair.expose_secret(), &expected_key_pair);
+    assert_eq!(
+        key_pair.expose_secret().expose_secret(),
+        boxed.expose_secret()
+    );
+}
+
+#[test]
+fn secret_box_serde_roundtrip() {
+    let seed = slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap();
+    let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+
+    let boxed = SecretBox::new(Box::new(master_key));
+    let serialized = serde_json::to_string(&boxed).unwrap();
+    let restored: SecretBox<ExtendedSecretKey<Secp256k1>> =
+        serde_json::from_str(&serialized).unwrap();
+    assert_eq!(restored.expose_secret(), boxed.expose_secret());
+}
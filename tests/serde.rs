@@ -0,0 +1,151 @@
+use slip_10::supported_curves::Secp256k1;
+
+fn master_key_pair() -> slip_10::ExtendedKeyPair<Secp256k1> {
+    let seed = slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap();
+    slip_10::derive_master_key::<Secp256k1>(&seed).into()
+}
+
+#[test]
+fn keys_roundtrip() {
+    let key_pair = master_key_pair();
+
+    let public_key = serde_json::to_string(key_pair.public_key()).unwrap();
+    let public_key: slip_10::ExtendedPublicKey<Secp256k1> =
+        serde_json::from_str(&public_key).unwrap();
+    assert_eq!(public_key.public_key, key_pair.public_key().public_key);
+    assert_eq!(public_key.chain_code, *key_pair.chain_code());
+
+    let secret_key = serde_json::to_string(key_pair.secret_key()).unwrap();
+    let secret_key: slip_10::ExtendedSecretKey<Secp256k1> =
+        serde_json::from_str(&secret_key).unwrap();
+    assert_eq!(
+        secret_key.secret_key.as_ref(),
+        key_pair.secret_key().secret_key.as_ref()
+    );
+    assert_eq!(secret_key.chain_code, *key_pair.chain_code());
+
+    let restored_key_pair = serde_json::to_string(&key_pair).unwrap();
+    let restored_key_pair: slip_10::ExtendedKeyPair<Secp256k1> =
+        serde_json::from_str(&restored_key_pair).unwrap();
+    assert_eq!(
+        restored_key_pair.public_key().public_key,
+        key_pair.public_key().public_key
+    );
+}
+
+#[test]
+fn keys_are_serialized_compactly() {
+    let key_pair = master_key_pair();
+    let public_key = serde_json::to_value(key_pair.public_key()).unwrap();
+    assert_eq!(
+        public_key,
+        serde_json::json!({
+            "public_key": hex::encode(key_pair.public_key().public_key.to_bytes(true)),
+            "chain_code": hex::encode(key_pair.chain_code()),
+        })
+    );
+}
+
+#[test]
+fn legacy_format_is_accepted() {
+    let key_pair = master_key_pair();
+    let legacy = serde_json::json!({
+        "public_key": {
+            "curve": "secp256k1",
+            "point": hex::encode(key_pair.public_key().public_key.to_bytes(false)),
+        },
+        "chain_code": key_pair.chain_code().to_vec(),
+    });
+
+    let public_key: slip_10::ExtendedPublicKey<Secp256k1> = serde_json::from_value(legacy).unwrap();
+    assert_eq!(public_key.public_key, key_pair.public_key().public_key);
+    assert_eq!(public_key.chain_code, *key_pair.chain_code());
+}
+
+#[test]
+fn shift_roundtrip() {
+    let key_pair = master_key_pair();
+    let shift = slip_10::derive_path_shift(
+        key_pair.public_key(),
+        [1, 10].map(slip_10::NonHardenedIndex::new),
+    );
+
+    let serialized = serde_json::to_string(&shift).unwrap();
+    let restored: slip_10::DerivedShift<Secp256k1> = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(restored.shift, shift.shift);
+    assert_eq!(restored.child_public_key, shift.child_public_key);
+}
+
+#[test]
+fn indexes_roundtrip() {
+    let index: slip_10::ChildIndex = serde_json::from_str("2147483649").unwrap();
+    assert!(matches!(index, slip_10::ChildIndex::Hardened(_)));
+    assert_eq!(serde_json::to_string(&index).unwrap(), "2147483649");
+
+    let index: slip_10::NonHardenedIndex = serde_json::from_str("1").unwrap();
+    assert_eq!(*index, 1);
+    assert!(serde_json::from_str::<slip_10::NonHardenedIndex>("2147483649").is_err());
+    assert!(serde_json::from_str::<slip_10::HardenedIndex>("1").is_err());
+}
+
+#[test]
+fn path_roundtrip() {
+    use serde_test::{Configure, Token};
+
+    /// `DerivationPath` which can be compared, as required by `serde_test`
+    #[derive(Debug)]
+    struct Path(slip_10::DerivationPath);
+    impl PartialEq for Path {
+        fn eq(&self, other: &Self) -> bool {
+            self.0.to_string() == other.0.to_string()
+        }
+    }
+    impl serde::Serialize for Path {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.0.serialize(serializer)
+        }
+    }
+    impl<'de> serde::Deserialize<'de> for Path {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            slip_10::DerivationPath::deserialize(deserializer).map(Path)
+        }
+    }
+
+    let path = || Path("m/44'/0'/1".parse().unwrap());
+    serde_test::assert_tokens(&path().readable(), &[Token::Str("m/44'/0'/1")]);
+    serde_test::assert_tokens(
+        &path().compact(),
+        &[
+            Token::Seq { len: Some(3) },
+            Token::U32(44 + slip_10::H),
+            Token::U32(slip_10::H),
+            Token::U32(1),
+            Token::SeqEnd,
+        ],
+    );
+
+    let path: slip_10::DerivationPath = serde_json::from_str(r#""m/44h/0h""#).unwrap();
+    assert_eq!(serde_json::to_string(&path).unwrap(), r#""m/44'/0'""#);
+    assert!(serde_json::from_str::<slip_10::DerivationPath>(r#""m//0""#).is_err());
+}
+
+#[test]
+fn ed25519_keys_roundtrip() {
+    let seed = slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap();
+    let key_pair =
+        slip_10::ed25519::ExtendedKeyPair::from(slip_10::ed25519::derive_master_key(&seed));
+
+    let serialized = serde_json::to_value(&key_pair).unwrap();
+    assert_eq!(
+        serialized,
+        serde_json::json!({
+            "secret_key": hex::encode(key_pair.secret_key().secret_key),
+            "chain_code": hex::encode(key_pair.chain_code()),
+        })
+    );
+    let restored: slip_10::ed25519::ExtendedKeyPair = serde_json::from_value(serialized).unwrap();
+    assert_eq!(
+        restored.public_key().public_key,
+        key_pair.public_key().public_key
+    );
+}
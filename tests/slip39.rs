@@ -0,0 +1,233 @@
+use slip_10::{
+    errors::{CombineSlip39Error, ParseSlip39ShareError, Slip39Error},
+    slip39::{self, Group, Params, Share},
+    supported_curves::Secp256k1,
+};
+
+/// Test vectors defined in https://github.com/trezor/python-shamir-mnemonic/blob/master/vectors.json
+const VECTORS: &str = include_str!("data/slip39_vectors.json");
+
+/// Description, mnemonics, and master secret (empty if mnemonics are invalid)
+type Vector = (String, Vec<String>, String);
+
+fn vectors() -> Vec<Vector> {
+    serde_json::from_str(VECTORS).unwrap()
+}
+
+#[test]
+fn parse_shares() {
+    for (description, mnemonics, _) in vectors() {
+        let results = mnemonics
+            .iter()
+            .map(|mnemonic| mnemonic.parse::<Share>())
+            .collect::<Vec<_>>();
+        if description.contains("invalid checksum") {
+            assert!(matches!(
+                results[0],
+                Err(ParseSlip39ShareError::InvalidChecksum)
+            ));
+        } else if description.contains("invalid padding") {
+            assert!(matches!(
+                results[0],
+                Err(ParseSlip39ShareError::InvalidPadding)
+            ));
+        } else if description.contains("insufficient length") {
+            assert!(matches!(
+                results[0],
+                Err(ParseSlip39ShareError::InvalidLength)
+            ));
+        } else if description.contains("greater group threshold than group counts") {
+            assert!(results
+                .iter()
+                .any(|r| matches!(r, Err(ParseSlip39ShareError::InvalidThreshold))));
+        } else if description.contains("invalid master secret length") {
+            assert!(results[0].is_err());
+        } else {
+            for (mnemonic, share) in mnemonics.iter().zip(results) {
+                let share = share.unwrap_or_else(|err| panic!("{description}: {err}"));
+                assert_eq!(share.to_string(), *mnemonic, "{description}");
+            }
+        }
+    }
+}
+
+#[test]
+fn combine() {
+    for (description, mnemonics, master_secret) in vectors() {
+        let result = slip39::seed_from_slip39_shares::<Secp256k1>(&mnemonics, b"TREZOR");
+        if master_secret.is_empty() {
+            let err = result.expect_err(&description);
+            if description.contains("different identifiers")
+                || description.contains("different iteration exponents")
+                || description.contains("mismatching group")
+                || description.contains("mismatching member thresholds")
+            {
+                assert!(
+                    matches!(err, CombineSlip39Error::MismatchedShares),
+                    "{description}: {err:?}"
+                );
+            } else if description.contains("duplicate member indices") {
+                assert!(
+                    matches!(err, CombineSlip39Error::DuplicateShare),
+                    "{description}: {err:?}"
+                );
+            } else if description.contains("invalid digest") {
+                assert!(
+                    matches!(err, CombineSlip39Error::InvalidDigest),
+                    "{description}: {err:?}"
+                );
+            } else if description.contains("Insufficient")
+                || description.contains("insufficient number of members")
+                || description.contains("Basic sharing")
+            {
+                assert!(
+                    matches!(err, CombineSlip39Error::InsufficientShares),
+                    "{description}: {err:?}"
+                );
+            } else {
+                assert!(
+                    matches!(err, CombineSlip39Error::InvalidShare(_)),
+                    "{description}: {err:?}"
+                );
+            }
+            continue;
+        }
+
+        let master_secret = hex::decode(master_secret).unwrap();
+        let shares = mnemonics
+            .iter()
+            .map(|mnemonic| mnemonic.parse::<Share>().unwrap())
+            .collect::<Vec<_>>();
+        let recovered = slip39::combine_shares(&shares, b"TREZOR").unwrap();
+        assert_eq!(recovered, master_secret, "{description}");
+
+        let expected =
+            slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(&master_secret).unwrap());
+        let master_key = result.unwrap_or_else(|err| panic!("{description}: {err}"));
+        assert_eq!(master_key, expected, "{description}");
+    }
+}
+
+#[test]
+fn split() {
+    let mut rng = rand_core::OsRng;
+    let params = Params {
+        iteration_exponent: 0,
+        extendable: false,
+    };
+    let groups = [
+        Group {
+            member_threshold: 1,
+            member_count: 1,
+        },
+        Group {
+            member_threshold: 2,
+            member_count: 3,
+        },
+        Group {
+            member_threshold: 3,
+            member_count: 5,
+        },
+    ];
+
+    for secret_len in [16, 32] {
+        let shares = slip39::split_master_secret(
+            &[0xab; 32][..secret_len],
+            b"TREZOR",
+            2,
+            &groups,
+            params,
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(shares.len(), groups.len());
+        let identifier = shares[0][0].identifier();
+
+        for (group_index, (group_shares, group)) in shares.iter().zip(&groups).enumerate() {
+            assert_eq!(group_shares.len(), usize::from(group.member_count));
+            for (member_index, share) in group_shares.iter().enumerate() {
+                assert_eq!(share.identifier(), identifier);
+                assert!(!share.extendable());
+                assert_eq!(share.iteration_exponent(), 0);
+                assert_eq!(usize::from(share.group_index()), group_index);
+                assert_eq!(share.group_threshold(), 2);
+                assert_eq!(share.group_count(), 3);
+                assert_eq!(usize::from(share.member_index()), member_index);
+                assert_eq!(share.member_threshold(), group.member_threshold);
+
+                let mnemonic = share.to_string();
+                let expected_words = if secret_len == 16 { 20 } else { 33 };
+                assert_eq!(mnemonic.split(' ').count(), expected_words);
+                assert_eq!(mnemonic.parse::<Share>().unwrap(), *share);
+            }
+        }
+
+        // Shares of the groups 0 and 2 are sufficient to recover the secret
+        let shares = [&shares[0][..], &shares[2][1..4]].concat();
+        let recovered = slip39::combine_shares(&shares, b"TREZOR").unwrap();
+        assert_eq!(recovered, [0xab; 32][..secret_len]);
+        assert!(matches!(
+            slip39::combine_shares(&shares[..3], b"TREZOR"),
+            Err(CombineSlip39Error::InsufficientShares)
+        ));
+    }
+}
+
+#[test]
+fn invalid_split_params() {
+    let group = Group {
+        member_threshold: 2,
+        member_count: 3,
+    };
+    let split = |secret: &[u8], passphrase: &[u8], group_threshold, groups: &[Group]| {
+        slip39::split_master_secret(
+            secret,
+            passphrase,
+            group_threshold,
+            groups,
+            Params::default(),
+            &mut rand_core::OsRng,
+        )
+    };
+
+    assert!(matches!(
+        split(&[0; 15], b"", 1, &[group]),
+        Err(Slip39Error::InvalidSecretLength)
+    ));
+    assert!(matches!(
+        split(&[0; 17], b"", 1, &[group]),
+        Err(Slip39Error::InvalidSecretLength)
+    ));
+    assert!(matches!(
+        split(&[0; 16], "пароль".as_bytes(), 1, &[group]),
+        Err(Slip39Error::InvalidPassphrase)
+    ));
+    assert!(matches!(
+        split(&[0; 16], b"", 2, &[group]),
+        Err(Slip39Error::InvalidThreshold)
+    ));
+    assert!(matches!(
+        split(
+            &[0; 16],
+            b"",
+            1,
+            &[Group {
+                member_threshold: 1,
+                member_count: 2
+            }]
+        ),
+        Err(Slip39Error::InvalidThreshold)
+    ));
+    assert!(matches!(
+        split(
+            &[0; 16],
+            b"",
+            1,
+            &[Group {
+                member_threshold: 2,
+                member_count: 17
+            }]
+        ),
+        Err(Slip39Error::InvalidThreshold)
+    ));
+}
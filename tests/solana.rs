@@ -0,0 +1,31 @@
+use slip_10::{bip39::Mnemonic, ed25519, solana, HardenedIndex};
+
+#[test]
+fn keypair_matches_ed25519_derivation() {
+    let mnemonic: Mnemonic = "abandon abandon abandon abandon abandon abandon \
+        abandon abandon abandon abandon abandon about"
+        .parse()
+        .unwrap();
+    let keypair = solana::derive_keypair_from_mnemonic(&mnemonic, "", HardenedIndex::new(1));
+
+    let seed = mnemonic.to_seed("");
+    let master_key = ed25519::ExtendedKeyPair::from(ed25519::derive_master_key(
+        slip_10::Seed::new(&seed).unwrap(),
+    ));
+    let path: slip_10::DerivationPath = "m/44'/501'/1'/0'".parse().unwrap();
+    let expected = ed25519::try_derive_child_key_pair_with_path(
+        &master_key,
+        path.iter().map(HardenedIndex::try_from),
+    )
+    .unwrap();
+
+    assert_eq!(keypair.secret_key, expected.secret_key().secret_key);
+    assert_eq!(
+        keypair.public_key,
+        expected.public_key().public_key.to_bytes(true).as_bytes()
+    );
+
+    let bytes = keypair.to_bytes();
+    assert_eq!(bytes[..32], keypair.secret_key);
+    assert_eq!(bytes[32..], keypair.public_key);
+}
@@ -0,0 +1,33 @@
+use slip_10::{bip39::Mnemonic, stellar, HardenedIndex};
+
+/// Test vector defined in https://github.com/stellar/stellar-protocol/blob/master/ecosystem/sep-0005.md
+#[test]
+fn test_vector() {
+    let mnemonic: Mnemonic =
+        "illness spike retreat truth genius clock brain pass fit cave bargain toe"
+            .parse()
+            .unwrap();
+    let expected = [
+        (
+            "GDRXE2BQUC3AZNPVFSCEZ76NJ3WWL25FYFK6RGZGIEKWE4SOOHSUJUJ6",
+            "SBGWSG6BTNCKCOB3DIFBGCVMUPQFYPA2G4O34RMTB343OYPXU5DJDVMN",
+        ),
+        (
+            "GBAW5XGWORWVFE2XTJYDTLDHXTY2Q2MO73HYCGB3XMFMQ562Q2W2GJQX",
+            "SCEPFFWGAG5P2VX5DHIYK3XEMZYLTYWIPWYEKXFHSK25RVMIUNJ7CTIS",
+        ),
+    ];
+
+    for (account, (public_key, secret_key)) in (0..).zip(expected) {
+        let key_pair =
+            stellar::derive_key_pair_from_mnemonic(&mnemonic, "", HardenedIndex::new(account));
+        assert_eq!(
+            stellar::encode_public_key(key_pair.public_key()).to_string(),
+            public_key
+        );
+        assert_eq!(
+            stellar::encode_secret_key(key_pair.secret_key()).to_string(),
+            secret_key
+        );
+    }
+}
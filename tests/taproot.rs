@@ -0,0 +1,48 @@
+use generic_ec::Point;
+use hex_literal::hex;
+use slip_10::{bip39::Mnemonic, supported_curves::Secp256k1, taproot};
+
+/// Test vectors defined in https://github.com/bitcoin/bips/blob/master/bip-0086.mediawiki
+#[test]
+fn bip86_output_keys() {
+    let mnemonic: Mnemonic = "abandon abandon abandon abandon abandon abandon \
+        abandon abandon abandon abandon abandon about"
+        .parse()
+        .unwrap();
+    let master_key = slip_10::derive_master_key_from_mnemonic::<Secp256k1>(&mnemonic, "");
+    let master_key = slip_10::ExtendedKeyPair::from(master_key);
+
+    let vectors = [
+        (
+            slip_10::path!("m/86'/0'/0'/0/0"),
+            hex!("cc8a4bc64d897bddc5fbc2f670f7a8ba0b386779106cf1223c6fc5d7cd6fc115"),
+            hex!("a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c"),
+        ),
+        (
+            slip_10::path!("m/86'/0'/0'/0/1"),
+            hex!("83dfe85a3151d2517290da461fe2815591ef69f2b18a2ce63f01697a8b313145"),
+            hex!("a82f29944d65b86ae6b5e5cc75e294ead6c59391a1edc5e016e3498c67fc7bbb"),
+        ),
+        (
+            slip_10::path!("m/86'/0'/0'/1/0"),
+            hex!("399f1b2f4393f29a18c937859c5dd8a77350103157eb880f02e8c08214277cef"),
+            hex!("882d74e5d0572d5a816cef0041a96b6c1de832f6f9676d9605c44d5e9a97d3dc"),
+        ),
+    ];
+
+    for (path, internal_key, expected_output_key) in vectors {
+        let key = slip_10::derive_child_key_pair_with_path(&master_key, path);
+        assert_eq!(
+            key.public_key().public_key.to_bytes(true)[1..],
+            internal_key
+        );
+
+        let (output_key, parity) = taproot::taproot_output_key(key.public_key());
+        assert_eq!(output_key, expected_output_key);
+
+        let output_secret_key = taproot::taproot_tweak_secret_key(key.secret_key());
+        let output_public_key = (Point::generator() * &output_secret_key).to_bytes(true);
+        assert_eq!(output_public_key[1..], output_key);
+        assert_eq!(output_public_key[0] == 0x03, parity == taproot::Parity::Odd);
+    }
+}
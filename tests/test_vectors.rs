@@ -1,3 +1,5 @@
+#![allow(clippy::identity_op)]
+
 use generic_ec::Curve;
 use hex_literal::hex;
 
@@ -7,10 +9,16 @@ struct TestVector {
     derivations: &'static [Derivation],
 }
 
+/// Test vector for curves which secret key is a raw 32 bytes string (ed25519 and curve25519)
+struct RawKeyTestVector {
+    seed: &'static [u8],
+    derivations: &'static [Derivation],
+}
+
 struct Derivation {
     path: &'static [u32],
 
-    expected_chain_code: slip_10::ChainCode,
+    expected_chain_code: [u8; 32],
     expected_secret_key: [u8; 32],
     expected_public_key: [u8; 33],
 }
@@ -320,6 +328,194 @@ const TEST_VECTORS: &[TestVector] = &[
     },
 ];
 
+/// Test vectors for curve25519 defined in
+/// https://github.com/satoshilabs/slips/blob/817d54acc9989793288910a40f9eb59bebef3c6e/slip-0010.md#test-vectors
+const CURVE25519_TEST_VECTORS: &[RawKeyTestVector] = &[
+    // Test vector 1 for curve25519
+    RawKeyTestVector {
+        seed: &hex!("000102030405060708090a0b0c0d0e0f"),
+        derivations: &[
+            Derivation {
+                path: &[],
+                expected_chain_code: hex!(
+                    "77997ca3588a1a34f3589279ea2962247abfe5277d52770a44c706378c710768"
+                ),
+                expected_secret_key: hex!(
+                    "d70a59c2e68b836cc4bbe8bcae425169b9e2384f3905091e3d60b890e90cd92c"
+                ),
+                expected_public_key: hex!(
+                    "005c7289dc9f7f3ea1c8c2de7323b9fb0781f69c9ecd6de4f095ac89a02dc80577"
+                ),
+            },
+            Derivation {
+                path: &[0 + slip_10::H],
+                expected_chain_code: hex!(
+                    "349a3973aad771c628bf1f1b4d5e071f18eff2e492e4aa7972a7e43895d6597f"
+                ),
+                expected_secret_key: hex!(
+                    "cd7630d7513cbe80515f7317cdb9a47ad4a56b63c3f1dc29583ab8d4cc25a9b2"
+                ),
+                expected_public_key: hex!(
+                    "00cb8be6b256ce509008b43ae0dccd69960ad4f7ff2e2868c1fbc9e19ec3ad544b"
+                ),
+            },
+            Derivation {
+                path: &[0 + slip_10::H, 1 + slip_10::H],
+                expected_chain_code: hex!(
+                    "2ee5ba14faf2fe9d7ab532451c2be3a0a5375c5e8c44fb31d9ad7edc25cda000"
+                ),
+                expected_secret_key: hex!(
+                    "a95f97cfc1a61dd833b882c89d36a78a030ea6b2fbe3ae2a70e4f1fc9008d6b1"
+                ),
+                expected_public_key: hex!(
+                    "00e9506455dce2526df42e5e4eb5585eaef712e5f9c6a28bf9fb175d96595ea872"
+                ),
+            },
+            Derivation {
+                path: &[0 + slip_10::H, 1 + slip_10::H, 2 + slip_10::H],
+                expected_chain_code: hex!(
+                    "e1897d5a96459ce2a3d294cb2a6a59050ee61255818c50e03ac4263ef17af084"
+                ),
+                expected_secret_key: hex!(
+                    "3d6cce04a9175929da907a90b02176077b9ae050dcef9b959fed978bb2200cdc"
+                ),
+                expected_public_key: hex!(
+                    "0018f008fcbc6d1cd8b4fe7a9eba00f6570a9da02a9b0005028cb2731b12ee4118"
+                ),
+            },
+            Derivation {
+                path: &[
+                    0 + slip_10::H,
+                    1 + slip_10::H,
+                    2 + slip_10::H,
+                    2 + slip_10::H,
+                ],
+                expected_chain_code: hex!(
+                    "1cccc84e2737cfe81b51fbe4c97bbdb000f6a76eddffb9ed03108fbff3ff7e4f"
+                ),
+                expected_secret_key: hex!(
+                    "7ae7437efe0a3018999e6f00d72e810ebc50578dbf6728bfa1c7fe73501081a7"
+                ),
+                expected_public_key: hex!(
+                    "00512e288a8ef4d869620dc4b06bb06ad2524b350dee5a39fcfeb708dbac65c25c"
+                ),
+            },
+            Derivation {
+                path: &[
+                    0 + slip_10::H,
+                    1 + slip_10::H,
+                    2 + slip_10::H,
+                    2 + slip_10::H,
+                    1000000000 + slip_10::H,
+                ],
+                expected_chain_code: hex!(
+                    "8ccf15d55b1dda246b0c1bf3e979a471a82524c1bd0c1eaecccf00dde72168bb"
+                ),
+                expected_secret_key: hex!(
+                    "7a59954d387abde3bc703f531f67d659ec2b8a12597ae82824547d7e27991e26"
+                ),
+                expected_public_key: hex!(
+                    "00a077fcf5af53d210257d44a86eb2031233ac7237da220434ac01a0bebccc1919"
+                ),
+            },
+        ],
+    },
+];
+
+/// Test vectors for ed25519 defined in
+/// https://github.com/satoshilabs/slips/blob/817d54acc9989793288910a40f9eb59bebef3c6e/slip-0010.md#test-vectors
+const ED25519_TEST_VECTORS: &[RawKeyTestVector] = &[
+    // Test vector 1 for ed25519
+    RawKeyTestVector {
+        seed: &hex!("000102030405060708090a0b0c0d0e0f"),
+        derivations: &[
+            Derivation {
+                path: &[],
+                expected_chain_code: hex!(
+                    "90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb"
+                ),
+                expected_secret_key: hex!(
+                    "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"
+                ),
+                expected_public_key: hex!(
+                    "00a4b2856bfec510abab89753fac1ac0e1112364e7d250545963f135f2a33188ed"
+                ),
+            },
+            Derivation {
+                path: &[0 + slip_10::H],
+                expected_chain_code: hex!(
+                    "8b59aa11380b624e81507a27fedda59fea6d0b779a778918a2fd3590e16e9c69"
+                ),
+                expected_secret_key: hex!(
+                    "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3"
+                ),
+                expected_public_key: hex!(
+                    "008c8a13df77a28f3445213a0f432fde644acaa215fc72dcdf300d5efaa85d350c"
+                ),
+            },
+            Derivation {
+                path: &[0 + slip_10::H, 1 + slip_10::H],
+                expected_chain_code: hex!(
+                    "a320425f77d1b5c2505a6b1b27382b37368ee640e3557c315416801243552f14"
+                ),
+                expected_secret_key: hex!(
+                    "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2"
+                ),
+                expected_public_key: hex!(
+                    "001932a5270f335bed617d5b935c80aedb1a35bd9fc1e31acafd5372c30f5c1187"
+                ),
+            },
+            Derivation {
+                path: &[0 + slip_10::H, 1 + slip_10::H, 2 + slip_10::H],
+                expected_chain_code: hex!(
+                    "2e69929e00b5ab250f49c3fb1c12f252de4fed2c1db88387094a0f8c4c9ccd6c"
+                ),
+                expected_secret_key: hex!(
+                    "92a5b23c0b8a99e37d07df3fb9966917f5d06e02ddbd909c7e184371463e9fc9"
+                ),
+                expected_public_key: hex!(
+                    "00ae98736566d30ed0e9d2f4486a64bc95740d89c7db33f52121f8ea8f76ff0fc1"
+                ),
+            },
+            Derivation {
+                path: &[
+                    0 + slip_10::H,
+                    1 + slip_10::H,
+                    2 + slip_10::H,
+                    2 + slip_10::H,
+                ],
+                expected_chain_code: hex!(
+                    "8f6d87f93d750e0efccda017d662a1b31a266e4a6f5993b15f5c1f07f74dd5cc"
+                ),
+                expected_secret_key: hex!(
+                    "30d1dc7e5fc04c31219ab25a27ae00b50f6fd66622f6e9c913253d6511d1e662"
+                ),
+                expected_public_key: hex!(
+                    "008abae2d66361c879b900d204ad2cc4984fa2aa344dd7ddc46007329ac76c429c"
+                ),
+            },
+            Derivation {
+                path: &[
+                    0 + slip_10::H,
+                    1 + slip_10::H,
+                    2 + slip_10::H,
+                    2 + slip_10::H,
+                    1000000000 + slip_10::H,
+                ],
+                expected_chain_code: hex!(
+                    "68789923a0cac2cd5a29172a475fe9e0fb14cd6adb5ad98a3fa70333e7afa230"
+                ),
+                expected_secret_key: hex!(
+                    "8f94d394a8e8fd6b1bc2f3f49f5c47e385281d5c17e65324b0f62483e37e8793"
+                ),
+                expected_public_key: hex!(
+                    "003c24da049451555d51a7014a37337aa4e12d41e485abccfa46b47dfb2af54b7a"
+                ),
+            },
+        ],
+    },
+];
+
 #[test]
 fn test_vectors() {
     for vector in TEST_VECTORS {
@@ -330,12 +526,19 @@ fn test_vectors() {
             slip_10::CurveType::Secp256r1 => {
                 run_vector::<slip_10::supported_curves::Secp256r1>(vector)
             }
+            slip_10::CurveType::Custom(_) => unreachable!("test vectors use standard curves"),
         }
     }
 }
 
 fn run_vector<E: Curve + slip_10::SupportedCurve>(v: &TestVector) {
-    let master_key = slip_10::derive_master_key::<E>(&v.seed).unwrap();
+    let master_key = slip_10::derive_master_key::<E>(slip_10::Seed::new(v.seed).unwrap());
+    let master_key2 = slip_10::derive_master_key_with_curve_type::<E>(
+        v.curve_type,
+        slip_10::Seed::new(v.seed).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(master_key.chain_code, master_key2.chain_code);
     let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
 
     for derivation in v.derivations {
@@ -355,3 +558,1000 @@ fn run_vector<E: Curve + slip_10::SupportedCurve>(v: &TestVector) {
         );
     }
 }
+
+#[test]
+fn shift_deriver_matches_key_pair_derivation() {
+    for vector in TEST_VECTORS {
+        match vector.curve_type {
+            slip_10::CurveType::Secp256k1 => {
+                run_shift_deriver::<slip_10::supported_curves::Secp256k1>(vector)
+            }
+            slip_10::CurveType::Secp256r1 => {
+                run_shift_deriver::<slip_10::supported_curves::Secp256r1>(vector)
+            }
+            slip_10::CurveType::Custom(_) => unreachable!("test vectors use standard curves"),
+        }
+    }
+}
+
+fn run_shift_deriver<E: Curve + slip_10::SupportedCurve>(v: &TestVector) {
+    let master_key = slip_10::derive_master_key::<E>(slip_10::Seed::new(v.seed).unwrap());
+    let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+
+    let deriver = slip_10::ShiftDeriver::new(master_key_pair.public_key());
+    for i in 0..50 {
+        let child_index = slip_10::NonHardenedIndex::new(i);
+        let expected = slip_10::derive_child_key_pair(&master_key_pair, child_index);
+        let shift = deriver.derive(child_index);
+
+        assert_eq!(
+            shift.child_public_key.public_key,
+            expected.public_key().public_key
+        );
+        assert_eq!(shift.child_public_key.chain_code, *expected.chain_code());
+        assert_eq!(
+            master_key_pair.secret_key().secret_key.as_ref() + shift.shift,
+            *expected.secret_key().secret_key.as_ref()
+        );
+    }
+
+    let child_public_keys = slip_10::derive_child_public_keys(
+        master_key_pair.public_key(),
+        (0..50).map(slip_10::NonHardenedIndex::new),
+    );
+    for (i, child_public_key) in (0..50).zip(child_public_keys) {
+        let expected =
+            slip_10::derive_child_public_key(master_key_pair.public_key(), i.try_into().unwrap());
+        assert_eq!(child_public_key.public_key, expected.public_key);
+        assert_eq!(child_public_key.chain_code, expected.chain_code);
+    }
+
+    // Children are derived lazily, so an unbounded list of indexes is fine
+    let first_children = slip_10::derive_child_public_keys(
+        master_key_pair.public_key(),
+        (0..).map(slip_10::NonHardenedIndex::new),
+    )
+    .take(20)
+    .collect::<Vec<_>>();
+    assert_eq!(first_children.len(), 20);
+
+    let child_indexes = [7, 0, 3].map(slip_10::NonHardenedIndex::new);
+    let child_public_keys =
+        slip_10::batch_derive_child_public_keys(master_key_pair.public_key(), &child_indexes);
+    assert_eq!(child_public_keys.len(), child_indexes.len());
+    for (child_index, child_public_key) in child_indexes.into_iter().zip(child_public_keys) {
+        let expected = slip_10::derive_child_public_key(master_key_pair.public_key(), child_index);
+        assert_eq!(child_public_key.public_key, expected.public_key);
+        assert_eq!(child_public_key.chain_code, expected.chain_code);
+    }
+
+    let children = slip_10::derive_children_iter(
+        master_key_pair.public_key(),
+        slip_10::NonHardenedIndex::new(10),
+    );
+    for ((child_index, child_public_key), i) in children.take(20).zip(10..) {
+        assert_eq!(*child_index, i);
+        let expected = slip_10::derive_child_public_key(master_key_pair.public_key(), child_index);
+        assert_eq!(child_public_key.public_key, expected.public_key);
+        assert_eq!(child_public_key.chain_code, expected.chain_code);
+    }
+
+    let mut last_children =
+        slip_10::derive_children_iter(master_key_pair.public_key(), slip_10::NonHardenedIndex::MAX);
+    assert_eq!(last_children.size_hint(), (1, Some(1)));
+    assert!(last_children.next().is_some());
+    assert!(last_children.next().is_none());
+}
+
+#[test]
+fn gap_limit_scan() {
+    use slip_10::{supported_curves::Secp256k1, NonHardenedIndex};
+
+    let master_key =
+        slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(TEST_VECTORS[0].seed).unwrap());
+    let master_public_key = slip_10::ExtendedPublicKey::from(&master_key);
+    let used_indexes = [1, 2, 7, 26];
+    let used_keys = used_indexes.map(|i| {
+        slip_10::derive_child_public_key(&master_public_key, NonHardenedIndex::new(i)).public_key
+    });
+
+    let mut scanned = 0;
+    let result = slip_10::scan(&master_public_key, 20, |child_public_key| {
+        scanned += 1;
+        used_keys.contains(&child_public_key.public_key)
+    });
+    assert_eq!(
+        result.used.iter().map(|i| **i).collect::<Vec<_>>(),
+        used_indexes
+    );
+    assert_eq!(result.next_fresh.map(|i| *i), Some(27));
+    assert_eq!(scanned, 27 + 20);
+
+    let result = slip_10::scan(&master_public_key, 4, |child_public_key| {
+        used_keys.contains(&child_public_key.public_key)
+    });
+    assert_eq!(result.used.iter().map(|i| **i).collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(result.next_fresh.map(|i| *i), Some(3));
+
+    let result = slip_10::scan(&master_public_key, 5, |_| false);
+    assert!(result.used.is_empty());
+    assert_eq!(result.next_fresh.map(|i| *i), Some(0));
+}
+
+#[test]
+fn raw_secret_key_roundtrip() {
+    use slip_10::{supported_curves::Secp256k1, ExtendedSecretKey};
+
+    let master_key =
+        slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(TEST_VECTORS[0].seed).unwrap());
+    let bytes = master_key.to_bytes();
+    assert_eq!(
+        &bytes[..32],
+        TEST_VECTORS[0].derivations[0].expected_secret_key
+    );
+    assert_eq!(
+        bytes[32..],
+        TEST_VECTORS[0].derivations[0].expected_chain_code
+    );
+
+    let restored = ExtendedSecretKey::<Secp256k1>::from_bytes(&bytes).unwrap();
+    assert_eq!(restored.to_bytes(), bytes);
+
+    // Zero and out-of-range scalars are rejected
+    let mut invalid = bytes;
+    invalid[..32].fill(0);
+    assert!(ExtendedSecretKey::<Secp256k1>::from_bytes(&invalid).is_err());
+    invalid[..32].fill(0xff);
+    assert!(ExtendedSecretKey::<Secp256k1>::from_bytes(&invalid).is_err());
+}
+
+#[test]
+fn raw_public_key_roundtrip() {
+    use slip_10::{supported_curves::Secp256r1, ExtendedPublicKey};
+
+    let vector = &TEST_VECTORS[1];
+    let master_key =
+        slip_10::derive_master_key::<Secp256r1>(slip_10::Seed::new(vector.seed).unwrap());
+    let bytes = ExtendedPublicKey::from(&master_key).to_bytes();
+    assert_eq!(&bytes[..33], vector.derivations[0].expected_public_key);
+    assert_eq!(bytes[33..], vector.derivations[0].expected_chain_code);
+
+    let restored = ExtendedPublicKey::<Secp256r1>::try_from(&bytes[..]).unwrap();
+    assert_eq!(restored.to_bytes(), bytes);
+
+    assert!(ExtendedPublicKey::<Secp256r1>::try_from(&bytes[..64]).is_err());
+    // Identity point and off-curve points are rejected
+    let mut invalid = bytes;
+    invalid[..33].fill(0);
+    assert!(ExtendedPublicKey::<Secp256r1>::try_from(&invalid[..]).is_err());
+    invalid[0] = 0x02;
+    invalid[1..33].fill(0xff);
+    assert!(ExtendedPublicKey::<Secp256r1>::try_from(&invalid[..]).is_err());
+}
+
+#[test]
+fn hex_encoding() {
+    use slip_10::{
+        errors::ParseHexError, supported_curves::Secp256k1, ExtendedPublicKey, ExtendedSecretKey,
+        HexEncoding,
+    };
+
+    let derivation = &TEST_VECTORS[0].derivations[0];
+    let master_key =
+        slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(TEST_VECTORS[0].seed).unwrap());
+    let master_public_key = ExtendedPublicKey::from(&master_key);
+
+    let chain_code = master_key.chain_code.to_hex();
+    assert_eq!(chain_code, hex::encode(derivation.expected_chain_code));
+    assert_eq!(
+        slip_10::ChainCode::from_hex(&chain_code.to_uppercase()).unwrap(),
+        derivation.expected_chain_code
+    );
+
+    let public_key = master_public_key.public_key.to_hex();
+    assert_eq!(public_key, hex::encode(derivation.expected_public_key));
+    assert_eq!(
+        generic_ec::Point::<Secp256k1>::from_hex(&public_key).unwrap(),
+        master_public_key.public_key
+    );
+
+    let xpub = master_public_key.to_hex();
+    assert_eq!(
+        xpub,
+        hex::encode(derivation.expected_public_key) + &hex::encode(derivation.expected_chain_code)
+    );
+    let restored = ExtendedPublicKey::<Secp256k1>::from_hex(&xpub).unwrap();
+    assert_eq!(restored.to_bytes(), master_public_key.to_bytes());
+
+    let xprv = master_key.to_hex();
+    let restored = ExtendedSecretKey::<Secp256k1>::from_hex(&xprv).unwrap();
+    assert_eq!(restored.to_bytes(), master_key.to_bytes());
+
+    assert!(matches!(
+        ExtendedSecretKey::<Secp256k1>::from_hex(&xpub),
+        Err(ParseHexError::InvalidLength(_))
+    ));
+    assert!(matches!(
+        slip_10::ChainCode::from_hex(&"zz".repeat(32)),
+        Err(ParseHexError::InvalidHex)
+    ));
+    assert!(matches!(
+        ExtendedSecretKey::<Secp256k1>::from_hex(&"00".repeat(64)),
+        Err(ParseHexError::InvalidKey(_))
+    ));
+}
+
+#[test]
+fn curve_type_mismatch() {
+    let seed = TEST_VECTORS[0].seed;
+    let result = slip_10::derive_master_key_with_curve_type::<slip_10::supported_curves::Secp256r1>(
+        slip_10::CurveType::Secp256k1,
+        slip_10::Seed::new(seed).unwrap(),
+    );
+    assert!(matches!(
+        result,
+        Err(slip_10::errors::DeriveMasterKeyError::CurveMismatch)
+    ));
+    assert!(matches!(
+        slip_10::derive_master_key_pair::<slip_10::supported_curves::Secp256r1>(
+            slip_10::CurveType::Secp256k1,
+            slip_10::Seed::new(seed).unwrap(),
+        ),
+        Err(slip_10::errors::DeriveMasterKeyError::CurveMismatch)
+    ));
+}
+
+#[test]
+fn master_key_pair() {
+    use slip_10::supported_curves::Secp256k1;
+
+    let seed = slip_10::Seed::new(TEST_VECTORS[0].seed).unwrap();
+    let master_key_pair =
+        slip_10::derive_master_key_pair::<Secp256k1>(slip_10::CurveType::Secp256k1, &seed).unwrap();
+    let expected = slip_10::ExtendedKeyPair::from(slip_10::derive_master_key::<Secp256k1>(&seed));
+    assert_eq!(master_key_pair, expected);
+}
+
+#[test]
+fn ed25519_test_vectors() {
+    for vector in ED25519_TEST_VECTORS {
+        let master_key =
+            slip_10::ed25519::derive_master_key(slip_10::Seed::new(vector.seed).unwrap());
+        let master_key_pair = slip_10::ed25519::ExtendedKeyPair::from(master_key);
+
+        for derivation in vector.derivations {
+            let path = derivation
+                .path
+                .iter()
+                .map(|i| slip_10::HardenedIndex::try_from(*i).unwrap());
+            let key = slip_10::ed25519::derive_child_key_pair_with_path(&master_key_pair, path);
+
+            // Public key in SLIP10 test vectors is prefixed with 0x00
+            let mut public_key = [0u8; 33];
+            public_key[1..].copy_from_slice(&key.public_key().public_key.to_bytes(true));
+
+            assert_eq!(key.chain_code(), &derivation.expected_chain_code);
+            assert_eq!(public_key, derivation.expected_public_key);
+            assert_eq!(key.secret_key().secret_key, derivation.expected_secret_key);
+        }
+    }
+}
+
+#[test]
+fn curve25519_test_vectors() {
+    for vector in CURVE25519_TEST_VECTORS {
+        let master_key =
+            slip_10::curve25519::derive_master_key(slip_10::Seed::new(vector.seed).unwrap());
+        let master_key_pair = slip_10::curve25519::ExtendedKeyPair::from(master_key);
+
+        for derivation in vector.derivations {
+            let path = derivation
+                .path
+                .iter()
+                .map(|i| slip_10::HardenedIndex::try_from(*i).unwrap());
+            let key = slip_10::curve25519::derive_child_key_pair_with_path(&master_key_pair, path);
+
+            // Public key in SLIP10 test vectors is prefixed with 0x00
+            let mut public_key = [0u8; 33];
+            public_key[1..].copy_from_slice(&key.public_key().public_key);
+
+            assert_eq!(key.chain_code(), &derivation.expected_chain_code);
+            assert_eq!(public_key, derivation.expected_public_key);
+            assert_eq!(key.secret_key().secret_key, derivation.expected_secret_key);
+        }
+    }
+}
+
+#[test]
+fn chain_code() {
+    use subtle::ConstantTimeEq;
+
+    let derivation = &TEST_VECTORS[0].derivations[0];
+    let master_key = slip_10::derive_master_key::<slip_10::supported_curves::Secp256k1>(
+        slip_10::Seed::new(TEST_VECTORS[0].seed).unwrap(),
+    );
+    let chain_code = master_key.chain_code;
+
+    let expected = slip_10::ChainCode::from(derivation.expected_chain_code);
+    assert!(bool::from(chain_code.ct_eq(&expected)));
+    assert_eq!(chain_code, expected);
+    assert_eq!(<[u8; 32]>::from(chain_code), derivation.expected_chain_code);
+    assert_eq!(&chain_code[..], &derivation.expected_chain_code[..]);
+
+    let other = slip_10::ChainCode::new([0u8; 32]);
+    assert!(!bool::from(chain_code.ct_eq(&other)));
+    assert_ne!(chain_code, other);
+
+    let hex = hex::encode(derivation.expected_chain_code);
+    assert_eq!(chain_code.to_string(), hex);
+    assert_eq!(format!("{chain_code:x}"), hex);
+    if cfg!(feature = "insecure-debug") {
+        assert_eq!(format!("{chain_code:?}"), format!("ChainCode({hex})"));
+    } else {
+        let fingerprint =
+            hex::encode(&<sha2::Sha256 as sha2::Digest>::digest(chain_code.as_bytes())[..4]);
+        assert_eq!(
+            chain_code.fingerprint()[..],
+            hex::decode(&fingerprint).unwrap()
+        );
+        assert_eq!(
+            format!("{chain_code:?}"),
+            format!("ChainCode(fingerprint: {fingerprint})")
+        );
+    }
+}
+
+#[test]
+fn secret_keys_constant_time_eq() {
+    use slip_10::{supported_curves::Secp256k1, ExtendedKeyPair, ExtendedSecretKey};
+    use subtle::ConstantTimeEq;
+
+    let master_key =
+        slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(TEST_VECTORS[0].seed).unwrap());
+    let restored = ExtendedSecretKey::<Secp256k1>::from_bytes(&master_key.to_bytes()).unwrap();
+    assert!(bool::from(master_key.ct_eq(&restored)));
+    assert_eq!(master_key, restored);
+
+    // Keys that differ only in chain code are not equal
+    let mut other_chain_code = master_key.clone();
+    other_chain_code.chain_code = [0u8; 32].into();
+    assert!(!bool::from(master_key.ct_eq(&other_chain_code)));
+    assert_ne!(master_key, other_chain_code);
+
+    let master_key_pair = ExtendedKeyPair::from(master_key);
+    let child_key_pair = master_key_pair.derive_child(0);
+    assert_eq!(master_key_pair, ExtendedKeyPair::from(restored));
+    assert!(!bool::from(master_key_pair.ct_eq(&child_key_pair)));
+    assert_ne!(master_key_pair, child_key_pair);
+}
+
+#[test]
+fn public_keys_eq_and_hash() {
+    use std::collections::HashSet;
+
+    use slip_10::{supported_curves::Secp256k1, ExtendedPublicKey};
+
+    let master_key =
+        slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(TEST_VECTORS[0].seed).unwrap());
+    let master_public_key = ExtendedPublicKey::from(&master_key);
+    let child_public_key = master_public_key.derive_child(slip_10::NonHardenedIndex::MIN);
+
+    assert_eq!(master_public_key, ExtendedPublicKey::from(&master_key));
+    assert_ne!(master_public_key, child_public_key);
+
+    // Keys that differ only in chain code are not equal
+    let mut other_chain_code = master_public_key;
+    other_chain_code.chain_code = [0u8; 32].into();
+    assert_ne!(master_public_key, other_chain_code);
+
+    let keys: HashSet<_> = [master_public_key, child_public_key, master_public_key]
+        .into_iter()
+        .collect();
+    assert_eq!(keys.len(), 2);
+}
+
+#[test]
+fn debug_output_redacts_secrets() {
+    let master_key = slip_10::derive_master_key::<slip_10::supported_curves::Secp256k1>(
+        slip_10::Seed::new(TEST_VECTORS[0].seed).unwrap(),
+    );
+    let secret_key = hex::encode(master_key.secret_key.as_ref().to_be_bytes());
+    let chain_code = master_key.chain_code;
+
+    let ed25519_master_key =
+        slip_10::ed25519::derive_master_key(slip_10::Seed::new(TEST_VECTORS[0].seed).unwrap());
+    let ed25519_secret_key = hex::encode(ed25519_master_key.secret_key);
+    let ed25519_chain_code = ed25519_master_key.chain_code;
+
+    let outputs = [
+        (format!("{master_key:?}"), &secret_key, chain_code),
+        (
+            format!("{:?}", slip_10::ExtendedKeyPair::from(master_key)),
+            &secret_key,
+            chain_code,
+        ),
+        (
+            format!("{ed25519_master_key:?}"),
+            &ed25519_secret_key,
+            ed25519_chain_code,
+        ),
+    ];
+    for (output, secret_key, chain_code) in outputs {
+        if cfg!(feature = "insecure-debug") {
+            assert!(output.contains(secret_key.as_str()), "{output}");
+            assert!(output.contains(&chain_code.to_string()), "{output}");
+        } else {
+            assert!(!output.contains(secret_key.as_str()), "{output}");
+            assert!(output.contains("(…)"), "{output}");
+            assert!(!output.contains(&chain_code.to_string()), "{output}");
+            let fingerprint = hex::encode(chain_code.fingerprint());
+            assert!(output.contains(&fingerprint), "{output}");
+        }
+    }
+}
+
+#[test]
+fn secret_key_derivation_matches_key_pair_derivation() {
+    for vector in TEST_VECTORS {
+        match vector.curve_type {
+            slip_10::CurveType::Secp256k1 => {
+                run_secret_key_derivation::<slip_10::supported_curves::Secp256k1>(vector)
+            }
+            slip_10::CurveType::Secp256r1 => {
+                run_secret_key_derivation::<slip_10::supported_curves::Secp256r1>(vector)
+            }
+            slip_10::CurveType::Custom(_) => unreachable!("test vectors use standard curves"),
+        }
+    }
+}
+
+fn run_secret_key_derivation<E: Curve + slip_10::SupportedCurve>(v: &TestVector) {
+    let master_key = slip_10::derive_master_key::<E>(slip_10::Seed::new(v.seed).unwrap());
+
+    for derivation in v.derivations {
+        let Ok(path) = derivation
+            .path
+            .iter()
+            .map(|i| slip_10::HardenedIndex::try_from(*i))
+            .collect::<Result<Vec<_>, _>>()
+        else {
+            continue;
+        };
+        let key = slip_10::derive_child_secret_key_with_path(&master_key, path.iter().copied());
+        assert_eq!(key.chain_code, derivation.expected_chain_code);
+        assert_eq!(
+            &key.secret_key.as_ref().to_be_bytes()[..],
+            &derivation.expected_secret_key,
+        );
+
+        let key2 = path
+            .iter()
+            .fold(master_key.clone(), |key, i| key.derive_child(*i));
+        assert_eq!(key, key2);
+    }
+}
+
+#[test]
+fn bounded_retries() {
+    use slip_10::supported_curves::Secp256r1;
+
+    // Master key derivation from this seed needs one retry
+    let seed_retry = &TEST_VECTORS[4];
+    assert!(matches!(
+        slip_10::try_derive_master_key::<Secp256r1>(
+            slip_10::Seed::new(seed_retry.seed).unwrap(),
+            0
+        ),
+        Err(slip_10::errors::DeriveError::TooManyRetriesForMasterKey)
+    ));
+    let master_key = slip_10::try_derive_master_key::<Secp256r1>(
+        slip_10::Seed::new(seed_retry.seed).unwrap(),
+        1,
+    )
+    .unwrap();
+    assert_eq!(
+        master_key.chain_code,
+        seed_retry.derivations[0].expected_chain_code
+    );
+
+    // Non-hardened derivation m/28578'/33941 needs one retry
+    let derivation_retry = &TEST_VECTORS[3];
+    let master_key = slip_10::try_derive_master_key::<Secp256r1>(
+        slip_10::Seed::new(derivation_retry.seed).unwrap(),
+        0,
+    )
+    .unwrap();
+    let master_key_pair = slip_10::ExtendedKeyPair::from(master_key.clone());
+    let hardened_index = slip_10::HardenedIndex::new(28578);
+    let child_key_pair =
+        slip_10::try_derive_child_key_pair(&master_key_pair, hardened_index, 0).unwrap();
+    let child_key = slip_10::try_derive_child_secret_key(&master_key, hardened_index, 0).unwrap();
+    assert_eq!(&child_key, child_key_pair.secret_key());
+
+    let index = slip_10::NonHardenedIndex::new(33941);
+    let err = slip_10::try_derive_child_key_pair(&child_key_pair, index, 0).unwrap_err();
+    assert!(matches!(
+        err,
+        slip_10::errors::DeriveError::TooManyRetries { level: 0, .. }
+    ));
+    assert_eq!(err.child_index(), Some(index.into()));
+    assert!(slip_10::try_derive_child_public_key(child_key_pair.public_key(), index, 0).is_err());
+
+    let expected = &derivation_retry.derivations[2];
+    let grandchild_key_pair =
+        slip_10::try_derive_child_key_pair(&child_key_pair, index, 1).unwrap();
+    assert_eq!(
+        grandchild_key_pair.chain_code(),
+        &expected.expected_chain_code
+    );
+    let grandchild_public_key =
+        slip_10::try_derive_child_public_key(child_key_pair.public_key(), index, 1).unwrap();
+    assert_eq!(&grandchild_public_key, grandchild_key_pair.public_key());
+}
+
+#[test]
+fn derivation_stats() {
+    use slip_10::{supported_curves::Secp256r1, DerivationStats};
+
+    let seed_retry = &TEST_VECTORS[4];
+    let (master_key, stats) = slip_10::derive_master_key_with_stats::<Secp256r1>(
+        slip_10::Seed::new(seed_retry.seed).unwrap(),
+    );
+    assert_eq!(stats, DerivationStats { retries: 1 });
+    assert_eq!(
+        master_key.chain_code,
+        seed_retry.derivations[0].expected_chain_code
+    );
+
+    let derivation_retry = &TEST_VECTORS[3];
+    let (master_key, stats) = slip_10::derive_master_key_with_stats::<Secp256r1>(
+        slip_10::Seed::new(derivation_retry.seed).unwrap(),
+    );
+    assert_eq!(stats.retries, 0);
+    let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+
+    let expected = &derivation_retry.derivations[2];
+    let (child_key_pair, stats) = slip_10::derive_child_key_pair_with_path_and_stats(
+        &master_key_pair,
+        expected.path.iter().copied(),
+    );
+    assert_eq!(
+        stats,
+        [
+            DerivationStats { retries: 0 },
+            DerivationStats { retries: 1 }
+        ]
+    );
+    assert_eq!(child_key_pair.chain_code(), &expected.expected_chain_code);
+
+    let parent_key_pair = master_key_pair.derive_child(expected.path[0]);
+    let (child_public_key, stats) = slip_10::derive_child_public_key_with_stats(
+        parent_key_pair.public_key(),
+        expected.path[1].try_into().unwrap(),
+    );
+    assert_eq!(stats.retries, 1);
+    assert_eq!(&child_public_key, child_key_pair.public_key());
+}
+
+#[test]
+fn path_shift() {
+    use slip_10::{supported_curves::Secp256k1, NonHardenedIndex};
+
+    let master_key =
+        slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(TEST_VECTORS[0].seed).unwrap());
+    let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+    let path = [1, 10, 2].map(NonHardenedIndex::new);
+
+    let shift = slip_10::derive_path_shift(master_key_pair.public_key(), path);
+    let child_key_pair = master_key_pair.derive_path(path);
+    assert_eq!(&shift.child_public_key, child_key_pair.public_key());
+    assert_eq!(
+        master_key_pair.secret_key().secret_key.as_ref() + shift.shift,
+        *child_key_pair.secret_key().secret_key.as_ref()
+    );
+
+    let shift = slip_10::derive_path_shift(master_key_pair.public_key(), []);
+    assert_eq!(shift.shift, generic_ec::Scalar::zero());
+    assert_eq!(&shift.child_public_key, master_key_pair.public_key());
+}
+
+#[test]
+fn shift_bytes_roundtrip() {
+    use slip_10::{supported_curves::Secp256k1, DerivedShift, NonHardenedIndex};
+
+    let master_key =
+        slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(TEST_VECTORS[0].seed).unwrap());
+    let master_public_key = slip_10::ExtendedPublicKey::from(&master_key);
+    let shift = slip_10::derive_path_shift(&master_public_key, [1, 10].map(NonHardenedIndex::new));
+
+    let bytes = shift.to_bytes();
+    let restored = DerivedShift::<Secp256k1>::from_bytes(&bytes).unwrap();
+    assert_eq!(restored.shift, shift.shift);
+    assert_eq!(restored.child_public_key, shift.child_public_key);
+
+    let mut invalid = bytes;
+    invalid[..32].copy_from_slice(&[0xff; 32]);
+    assert!(DerivedShift::<Secp256k1>::from_bytes(&invalid).is_err());
+}
+
+#[test]
+fn shift_is_copy() {
+    fn assert_copy<T: Copy>() {}
+    assert_copy::<slip_10::DerivedShift<slip_10::supported_curves::Secp256k1>>();
+}
+
+#[test]
+fn shift_applied_to_polynomial_shares() {
+    use generic_ec::{Point, Scalar, SecretScalar};
+    use slip_10::{supported_curves::Secp256k1, NonHardenedIndex, SecretSharing};
+
+    let master_key =
+        slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(TEST_VECTORS[0].seed).unwrap());
+    let master_public_key = slip_10::ExtendedPublicKey::from(&master_key);
+    let shift = slip_10::derive_path_shift(&master_public_key, [1, 10].map(NonHardenedIndex::new));
+
+    // f(x) = sk + 42 x, shares are f(1) and f(2)
+    let f = |x: u64| master_key.secret_key.as_ref() + Scalar::from(42) * Scalar::from(x);
+    let child_shares = [1, 2].map(|x| {
+        let (share, chain_code) =
+            shift.apply_to_share(&SecretScalar::new(&mut f(x)), SecretSharing::Polynomial);
+        assert_eq!(chain_code, shift.child_public_key.chain_code);
+        share
+    });
+
+    // Lagrange coefficients at 0 for points 1 and 2 are 2 and -1
+    let child_sk = Scalar::from(2) * child_shares[0].as_ref() - child_shares[1].as_ref();
+    assert_eq!(
+        Point::generator() * child_sk,
+        shift.child_public_key.public_key
+    );
+}
+
+#[test]
+fn verify_child_public_key() {
+    use slip_10::{supported_curves::Secp256k1, NonHardenedIndex};
+
+    let master_key =
+        slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(TEST_VECTORS[0].seed).unwrap());
+    let parent = slip_10::ExtendedPublicKey::from(&master_key);
+    let child = slip_10::derive_child_public_key(&parent, NonHardenedIndex::new(5));
+
+    assert!(slip_10::verify_child_public_key(
+        &parent,
+        NonHardenedIndex::new(5),
+        &child
+    ));
+    assert!(!slip_10::verify_child_public_key(
+        &parent,
+        NonHardenedIndex::new(6),
+        &child
+    ));
+
+    let mut wrong_chain_code = child;
+    wrong_chain_code.chain_code = [0; 32].into();
+    assert!(!slip_10::verify_child_public_key(
+        &parent,
+        NonHardenedIndex::new(5),
+        &wrong_chain_code
+    ));
+}
+
+#[test]
+fn verify_many_child_public_keys() {
+    use slip_10::{supported_curves::Secp256k1, NonHardenedIndex};
+
+    let master_key =
+        slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(TEST_VECTORS[0].seed).unwrap());
+    let master_public_key = slip_10::ExtendedPublicKey::from(&master_key);
+    let parents = [0, 1]
+        .map(|i| slip_10::derive_child_public_key(&master_public_key, NonHardenedIndex::new(i)));
+
+    let mut derivations = std::vec::Vec::new();
+    for parent in &parents {
+        for i in 0..5 {
+            let index = NonHardenedIndex::new(i);
+            derivations.push((
+                parent,
+                index,
+                slip_10::derive_child_public_key(parent, index),
+            ));
+        }
+    }
+    // Claim wrong index, and wrong parent
+    derivations[3].1 = NonHardenedIndex::new(100);
+    derivations[7].0 = &parents[0];
+
+    let failed = slip_10::verify_child_public_keys(
+        derivations
+            .iter()
+            .map(|(parent, index, child)| (*parent, *index, child)),
+    );
+    assert_eq!(failed, [3, 7]);
+}
+
+#[test]
+fn derivation_scheme() {
+    use slip_10::{
+        errors::DeriveError,
+        scheme::{self, DerivationScheme, Slip10},
+        supported_curves::Secp256r1,
+    };
+
+    struct NoRetries;
+    impl DerivationScheme for NoRetries {
+        type Mac = <Slip10 as DerivationScheme>::Mac;
+        const MAX_RETRIES: Option<usize> = Some(0);
+    }
+
+    // Slip10 scheme produces the same keys as top-level functions
+    let vector = &TEST_VECTORS[3];
+    let master_key =
+        scheme::derive_master_key::<Slip10, Secp256r1>(slip_10::Seed::new(vector.seed).unwrap())
+            .unwrap();
+    let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+    for derivation in vector.derivations {
+        let key_pair = scheme::derive_child_key_pair_with_path::<Slip10, _>(
+            &master_key_pair,
+            derivation.path.iter().copied(),
+        )
+        .unwrap();
+        assert_eq!(key_pair.chain_code(), &derivation.expected_chain_code);
+        assert_eq!(
+            key_pair.public_key().public_key.to_bytes(true).as_bytes(),
+            derivation.expected_public_key
+        );
+    }
+
+    // Scheme which doesn't retry fails where SLIP10 retries
+    assert!(matches!(
+        scheme::derive_master_key::<NoRetries, Secp256r1>(
+            slip_10::Seed::new(TEST_VECTORS[4].seed).unwrap()
+        ),
+        Err(DeriveError::TooManyRetriesForMasterKey)
+    ));
+    let path = vector.derivations[2].path;
+    let err = scheme::derive_child_key_pair_with_path::<NoRetries, _>(
+        &master_key_pair,
+        path.iter().copied(),
+    )
+    .unwrap_err();
+    assert_eq!(err.level(), Some(1));
+    assert_eq!(err.child_index(), Some(path[1].into()));
+    let child_key_pair =
+        scheme::derive_child_key_pair::<NoRetries, _>(&master_key_pair, path[0]).unwrap();
+    let index = slip_10::NonHardenedIndex::try_from(path[1]).unwrap();
+    assert!(
+        scheme::derive_child_public_key::<NoRetries, _>(child_key_pair.public_key(), index)
+            .is_err()
+    );
+    assert!(
+        slip_10::ShiftDeriver::<_, NoRetries>::with_scheme(child_key_pair.public_key())
+            .try_derive(index, 1)
+            .is_ok()
+    );
+}
+
+#[test]
+fn commitment() {
+    use slip_10::{commitment, supported_curves::Secp256k1};
+
+    let master_key =
+        slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(TEST_VECTORS[0].seed).unwrap());
+    let master_key = slip_10::ExtendedKeyPair::from(master_key);
+    let child_key = slip_10::derive_child_key_pair(&master_key, slip_10::NonHardenedIndex::new(1));
+
+    for key in [&master_key, &child_key] {
+        let tweaked_public_key = commitment::tweak_with_commitment(key.public_key(), b"contract");
+        let tweaked_secret_key =
+            commitment::tweak_secret_key_with_commitment(key.secret_key(), b"contract");
+        assert_eq!(
+            slip_10::ExtendedPublicKey::from(&tweaked_secret_key),
+            tweaked_public_key
+        );
+        assert_eq!(tweaked_public_key.chain_code, key.public_key().chain_code);
+        assert_ne!(tweaked_public_key, *key.public_key());
+
+        assert!(commitment::verify_commitment(
+            key.public_key(),
+            b"contract",
+            &tweaked_public_key
+        ));
+        assert!(!commitment::verify_commitment(
+            key.public_key(),
+            b"another contract",
+            &tweaked_public_key
+        ));
+    }
+}
+
+#[test]
+fn threshold_shares() {
+    use generic_ec::{Point, Scalar, SecretScalar};
+    use slip_10::{supported_curves::Secp256k1, threshold, SecretSharing};
+
+    let master_key =
+        slip_10::derive_master_key::<Secp256k1>(slip_10::Seed::new(TEST_VECTORS[0].seed).unwrap());
+    let group_key = slip_10::ExtendedPublicKey::from(&master_key);
+    let path = [
+        slip_10::NonHardenedIndex::new(1),
+        slip_10::NonHardenedIndex::new(10),
+    ];
+    let child_key = slip_10::derive_child_public_key_with_path(&group_key, path);
+
+    // 2-out-of-3 Shamir sharing: f(x) = sk + 7x
+    let f = |x: u64| master_key.secret_key.as_ref() + Scalar::from(7) * Scalar::from(x);
+    let children = [1, 2, 3].map(|i| {
+        let child = threshold::derive_share(
+            &group_key,
+            path,
+            &SecretScalar::new(&mut f(i)),
+            SecretSharing::Polynomial,
+        );
+        assert_eq!(child.group_key, child_key);
+        assert_eq!(
+            Point::generator() * &child.secret_share,
+            threshold::derive_public_share(
+                &group_key,
+                path,
+                &(Point::generator() * f(i)),
+                SecretSharing::Polynomial
+            )
+        );
+        child.secret_share
+    });
+    // Interpolate child secret key from shares 1 and 3: sk = (3 f(1) - f(3)) / 2
+    let sk = (Scalar::from(3) * children[0].as_ref() - children[2].as_ref())
+        * Scalar::from(2).invert().unwrap();
+    assert_eq!(Point::generator() * sk, child_key.public_key);
+
+    // Additive sharing
+    let share1 = SecretScalar::<Secp256k1>::new(&mut Scalar::from(42));
+    let share2 = SecretScalar::new(&mut (master_key.secret_key.as_ref() - share1.as_ref()));
+    let child1 = threshold::derive_share(
+        &group_key,
+        path,
+        &share1,
+        SecretSharing::Additive { designated: false },
+    );
+    let child2 = threshold::derive_share(
+        &group_key,
+        path,
+        &share2,
+        SecretSharing::Additive { designated: true },
+    );
+    assert_eq!(
+        Point::generator() * (child1.secret_share.as_ref() + child2.secret_share.as_ref()),
+        child_key.public_key
+    );
+    assert_eq!(
+        threshold::derive_public_share(
+            &group_key,
+            path,
+            &(Point::generator() * &share1),
+            SecretSharing::Additive { designated: false }
+        ),
+        Point::generator() * &child1.secret_share
+    );
+}
+
+#[test]
+fn seed() {
+    use slip_10::{supported_curves::Secp256k1, Seed};
+
+    assert!(Seed::new(&[0; 15]).is_err());
+    assert!(Seed::new(&[0; 65]).is_err());
+    for len in [16, 33, 64] {
+        let seed = Seed::new(&[0xab; 64][..len]).unwrap();
+        assert_eq!(seed.as_bytes(), &[0xab; 64][..len]);
+    }
+
+    // Seeds of different length are not equal, even if one is a prefix of another
+    let seed = Seed::new(&[0; 32]).unwrap();
+    assert_eq!(seed, Seed::from([0; 32]));
+    assert_ne!(seed, Seed::from([0; 16]));
+    assert_ne!(seed, Seed::from([1; 32]));
+
+    #[cfg(not(feature = "insecure-debug"))]
+    assert_eq!(format!("{seed:?}"), "Seed(…)");
+
+    let mut rng = rand_core::OsRng;
+    assert!(Seed::generate(&mut rng, 12).is_err());
+    assert!(Seed::generate(&mut rng, 65).is_err());
+    let seed = Seed::generate(&mut rng, 32).unwrap();
+    assert_eq!(seed.as_bytes().len(), 32);
+    assert_ne!(seed, Seed::generate(&mut rng, 32).unwrap());
+
+    // Master key only depends on seed bytes
+    let vector = &TEST_VECTORS[0];
+    let seed = Seed::try_from(vector.seed).unwrap();
+    assert_eq!(
+        slip_10::derive_master_key::<Secp256k1>(&seed),
+        slip_10::derive_master_key::<Secp256k1>(Seed::new(vector.seed).unwrap())
+    );
+}
+
+#[test]
+fn key_pair_from_parts() {
+    use slip_10::{supported_curves::Secp256k1, ExtendedKeyPair, ExtendedPublicKey};
+
+    let seed = slip_10::Seed::new(TEST_VECTORS[0].seed).unwrap();
+    let secret_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+    let public_key = ExtendedPublicKey::from(&secret_key);
+    let key_pair = ExtendedKeyPair::try_from_parts(secret_key.clone(), public_key).unwrap();
+    assert_eq!(key_pair, ExtendedKeyPair::from(secret_key.clone()));
+
+    // Public key of another secret key
+    let other_key = key_pair.derive_child(1);
+    assert!(ExtendedKeyPair::try_from_parts(secret_key.clone(), *other_key.public_key()).is_err());
+
+    // Same public key, but different chain code
+    let mismatched_chain_code = ExtendedPublicKey {
+        chain_code: other_key.public_key().chain_code,
+        ..public_key
+    };
+    assert!(ExtendedKeyPair::try_from_parts(secret_key, mismatched_chain_code).is_err());
+}
+
+#[test]
+fn key_pair_into_parts() {
+    use slip_10::{supported_curves::Secp256k1, ExtendedKeyPair};
+
+    let seed = slip_10::Seed::new(TEST_VECTORS[0].seed).unwrap();
+    let key_pair = ExtendedKeyPair::from(slip_10::derive_master_key::<Secp256k1>(&seed));
+
+    assert_eq!(key_pair.clone().into_public_key(), *key_pair.public_key());
+    assert_eq!(key_pair.clone().into_secret_key(), *key_pair.secret_key());
+
+    let (secret_key, public_key) = key_pair.clone().into_parts();
+    assert_eq!(secret_key, *key_pair.secret_key());
+    assert_eq!(public_key, *key_pair.public_key());
+    assert_eq!(
+        ExtendedKeyPair::try_from_parts(secret_key, public_key).unwrap(),
+        key_pair
+    );
+}
+
+#[test]
+fn path_iter() {
+    use slip_10::{supported_curves::Secp256k1, ExtendedKeyPair, H};
+
+    let seed = slip_10::Seed::new(TEST_VECTORS[0].seed).unwrap();
+    let master_key = ExtendedKeyPair::from(slip_10::derive_master_key::<Secp256k1>(&seed));
+
+    let path = [44 + H, H, H, 0, 1];
+    let levels: Vec<_> = slip_10::derive_path_iter(&master_key, path).collect();
+    assert_eq!(levels.len(), path.len());
+    for (len, key_pair) in (1..).zip(&levels) {
+        assert_eq!(
+            *key_pair,
+            master_key.derive_path(path[..len].iter().copied())
+        );
+    }
+
+    assert_eq!(slip_10::derive_path_iter(&master_key, path).len(), 5);
+    assert_eq!(
+        slip_10::derive_path_iter(&master_key, [0u32; 0]).next(),
+        None
+    );
+}
+
+#[test]
+fn derive_key_pair_from_seed() {
+    use slip_10::{errors::DeriveError, supported_curves::Secp256k1, ExtendedKeyPair, H};
+
+    let vector = &TEST_VECTORS[0];
+    let key_pair =
+        slip_10::derive_key_pair_from_seed::<Secp256k1>(vector.seed, "m/0'/1/2'").unwrap();
+    let master_key = ExtendedKeyPair::from(slip_10::derive_master_key::<Secp256k1>(
+        slip_10::Seed::new(vector.seed).unwrap(),
+    ));
+    assert_eq!(key_pair, master_key.derive_path([H, 1, 2 + H]));
+
+    let err = slip_10::derive_key_pair_from_seed::<Secp256k1>(&[0u8; 8], "m/0").unwrap_err();
+    assert!(matches!(err, DeriveError::InvalidSeedLength(_)));
+    assert_eq!(err.level(), None);
+
+    let err = slip_10::derive_key_pair_from_seed::<Secp256k1>(vector.seed, "m/x").unwrap_err();
+    assert!(matches!(err, DeriveError::ParsePath(_)));
+    assert_eq!(err.child_index(), None);
+}
@@ -0,0 +1,53 @@
+use sha2::Sha256;
+use slip_10::{
+    supported_curves::Secp256k1, ChainCode, ChildIndex, DerivationPath, ExtendedPublicKey,
+    HardenedIndex, NonHardenedIndex, StaticPath, H,
+};
+
+fn hash(value: impl udigest::Digestable) -> [u8; 32] {
+    udigest::udigest(udigest::Tag::<Sha256>::new("slip10.test"), value).into()
+}
+
+#[test]
+fn child_index() {
+    assert_eq!(hash(ChildIndex::from(H + 1)), hash(HardenedIndex::new(1)));
+    assert_eq!(hash(ChildIndex::from(1)), hash(NonHardenedIndex::new(1)));
+    assert_ne!(hash(ChildIndex::from(1)), hash(ChildIndex::from(H + 1)));
+    // Child index isn't confused with plain `u32`
+    assert_ne!(hash(ChildIndex::from(1)), hash(1u32));
+}
+
+#[test]
+fn derivation_path() {
+    let path: DerivationPath = "m/44'/0'/0'/0/1".parse().unwrap();
+    let static_path: StaticPath<5> = "m/44'/0'/0'/0/1".parse().unwrap();
+    assert_eq!(hash(&path), hash(static_path));
+
+    let another_path: DerivationPath = "m/44'/0'/0'/0".parse().unwrap();
+    assert_ne!(hash(&path), hash(&another_path));
+    assert_ne!(hash(DerivationPath::default()), hash(ChildIndex::from(0)));
+
+    // Path isn't confused with a plain list of indexes
+    assert_ne!(hash(&path), hash(path.as_slice()));
+}
+
+#[test]
+fn extended_public_key() {
+    let seed = slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap();
+    let public_key = ExtendedPublicKey::from(&slip_10::derive_master_key::<Secp256k1>(&seed));
+    let child_public_key = public_key.derive_child(NonHardenedIndex::new(0));
+
+    assert_eq!(hash(public_key), hash(public_key));
+    assert_ne!(hash(public_key), hash(child_public_key));
+
+    // Chain code is bound into the digest
+    let other_chain_code = ExtendedPublicKey {
+        public_key: public_key.public_key,
+        chain_code: ChainCode::new([0; 32]),
+    };
+    assert_ne!(hash(public_key), hash(other_chain_code));
+    assert_ne!(
+        hash(ChainCode::new([0; 32])),
+        hash(udigest::Bytes([0u8; 32]))
+    );
+}
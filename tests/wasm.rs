@@ -0,0 +1,47 @@
+//! Only successful calls can be tested on native targets: errors are converted into JS values,
+//! which requires a JS host
+
+use slip_10::{bip32, supported_curves::Secp256k1, wasm::HdKey};
+
+const SEED: &[u8] = b"16-64 bytes of high entropy";
+
+#[test]
+fn matches_bip32() {
+    let seed = slip_10::Seed::new(SEED).unwrap();
+    let master_key = bip32::Bip32ExtendedKey::<Secp256k1>::master(slip_10::derive_master_key::<
+        Secp256k1,
+    >(&seed));
+    let path: slip_10::DerivationPath = "m/84'/0'/0'/0/1".parse().unwrap();
+    let expected = master_key.derive_child_with_path(&path).unwrap();
+
+    let key = HdKey::from_seed(SEED)
+        .unwrap()
+        .derive_path("m/84'/0'/0'")
+        .unwrap()
+        .derive_path("0/1")
+        .unwrap();
+
+    assert_eq!(key.xpub(), expected.to_xpub().to_string());
+    assert_eq!(key.xpub_bytes(), expected.to_xpub().to_bytes());
+    assert_eq!(
+        key.public_key(),
+        expected
+            .key()
+            .public_key()
+            .public_key
+            .to_bytes(true)
+            .as_bytes()
+    );
+    assert_eq!(key.chain_code(), expected.key().chain_code().to_bytes());
+    assert_eq!(key.fingerprint(), expected.key().public_key().fingerprint());
+    assert_eq!(key.depth(), 5);
+    assert_eq!(key.as_extended_key().key(), expected.key());
+}
+
+#[test]
+fn empty_path() {
+    let master_key = HdKey::from_seed(SEED).unwrap();
+    let same_key = master_key.derive_path("m").unwrap();
+    assert_eq!(master_key.xpub(), same_key.xpub());
+    assert_eq!(same_key.depth(), 0);
+}
@@ -0,0 +1,189 @@
+use generic_ec::{Scalar, SecretScalar};
+use hex_literal::hex;
+use slip_10::{errors::Web3KeystoreError, supported_curves::Secp256k1, web3_keystore};
+
+const SECRET_KEY: [u8; 32] =
+    hex!("7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d");
+
+/// Test vectors defined in https://ethereum.org/en/developers/docs/data-structures-and-encoding/web3-secret-storage/
+#[test]
+fn test_vectors() {
+    let pbkdf2 = r#"{
+        "crypto" : {
+            "cipher" : "aes-128-ctr",
+            "cipherparams" : {
+                "iv" : "6087dab2f9fdbbfaddc31a909735c1e6"
+            },
+            "ciphertext" : "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+            "kdf" : "pbkdf2",
+            "kdfparams" : {
+                "c" : 262144,
+                "dklen" : 32,
+                "prf" : "hmac-sha256",
+                "salt" : "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+            },
+            "mac" : "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+        },
+        "id" : "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+        "version" : 3
+    }"#;
+    // Scrypt vector from the spec uses r = 1, which is too small for n = 2^18 and is rejected by
+    // scrypt implementation, so this one is generated by another implementation
+    let scrypt = r#"{
+        "crypto" : {
+            "cipher" : "aes-128-ctr",
+            "cipherparams" : {
+                "iv" : "83dbcc02d8ccb40e466191a123791e0e"
+            },
+            "ciphertext" : "3b4309355ad643f2b15cfb6a83a7f6f328e7a6459a56ab8c6e25a89c8f43eb80",
+            "kdf" : "scrypt",
+            "kdfparams" : {
+                "dklen" : 32,
+                "n" : 4096,
+                "p" : 1,
+                "r" : 8,
+                "salt" : "ab0c7876052600dd703518d6fc3fe8984592145b591fc8fb5c6d43190334ba19"
+            },
+            "mac" : "994d83f6bfb7e6e3aa95980f72b6ad87db9d352789d0f2e433cf777425db3a42"
+        },
+        "id" : "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+        "version" : 3
+    }"#;
+
+    for json in [pbkdf2, scrypt] {
+        let keystore: web3_keystore::Keystore = serde_json::from_str(json).unwrap();
+        let secret_key = keystore.decrypt(b"testpassword").unwrap();
+        assert_eq!(*secret_key.as_ref().to_be_bytes(), SECRET_KEY);
+
+        assert!(matches!(
+            keystore.decrypt(b"wrong password"),
+            Err(Web3KeystoreError::WrongPassword)
+        ));
+    }
+}
+
+#[test]
+fn encrypt_decrypt() {
+    let mut rng = rand_core::OsRng;
+    let mut secret_key = Scalar::<Secp256k1>::from_be_bytes(SECRET_KEY).unwrap();
+    let secret_key = SecretScalar::new(&mut secret_key);
+
+    // Cheap parameters so test runs fast
+    let kdf = web3_keystore::Kdf::Scrypt(web3_keystore::ScryptParams {
+        n: 1024,
+        ..web3_keystore::ScryptParams::generate(&mut rng)
+    });
+    let keystore = web3_keystore::encrypt_with_kdf(&secret_key, b"password", kdf, &mut rng);
+    assert_eq!(keystore.id.len(), 36);
+    assert_eq!(
+        keystore.address,
+        Some(hex!("008aeeda4d805471df9b2a5b0f38a0c3bcba786b"))
+    );
+
+    let json = serde_json::to_value(&keystore).unwrap();
+    assert_eq!(json["version"], 3);
+    assert_eq!(json["crypto"]["kdf"], "scrypt");
+    assert_eq!(json["crypto"]["kdfparams"]["n"], 1024);
+    assert_eq!(json["crypto"]["cipher"], "aes-128-ctr");
+    assert_eq!(json["address"], "008aeeda4d805471df9b2a5b0f38a0c3bcba786b");
+
+    let keystore: web3_keystore::Keystore = serde_json::from_value(json).unwrap();
+    let decrypted = keystore.decrypt(b"password").unwrap();
+    assert_eq!(decrypted.as_ref(), secret_key.as_ref());
+}
+
+/// Keystores of eth-account/web3.py use 16 bytes salt
+#[test]
+fn short_salt() {
+    let keystore = |kdf: &str, kdfparams: &str, ciphertext: &str, mac: &str| {
+        format!(
+            r#"{{
+                "crypto" : {{
+                    "cipher" : "aes-128-ctr",
+                    "cipherparams" : {{ "iv" : "00112233445566778899aabbccddeeff" }},
+                    "ciphertext" : "{ciphertext}",
+                    "kdf" : "{kdf}",
+                    "kdfparams" : {kdfparams},
+                    "mac" : "{mac}"
+                }},
+                "id" : "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+                "version" : 3
+            }}"#
+        )
+    };
+    let pbkdf2 = keystore(
+        "pbkdf2",
+        r#"{ "c" : 262144, "dklen" : 32, "prf" : "hmac-sha256", "salt" : "0f1e2d3c4b5a69788796a5b4c3d2e1f0" }"#,
+        "84c17e0fadd9358b2d27fd6e99a895740435211003e0a6c8e35700bceae03b90",
+        "faa77f0ac47d98d8c81cf1c55cc0a0c7daa2845c72549c7ce7de7bdceca5f703",
+    );
+    let scrypt = keystore(
+        "scrypt",
+        r#"{ "dklen" : 32, "n" : 4096, "p" : 1, "r" : 8, "salt" : "0f1e2d3c4b5a69788796a5b4c3d2e1f0" }"#,
+        "43904f66d11e1d67d7fef7b198248e67b01ea6a2e81b6a891d4731d8f030f387",
+        "9c89e9bee0f1108eee176ddd7bba91927914655123d1edd3abda9a7b32e91a84",
+    );
+
+    for json in [pbkdf2, scrypt] {
+        let keystore: web3_keystore::Keystore = serde_json::from_str(&json).unwrap();
+        let secret_key = keystore.decrypt(b"testpassword").unwrap();
+        assert_eq!(*secret_key.as_ref().to_be_bytes(), SECRET_KEY);
+    }
+}
+
+#[test]
+fn excessive_kdf_params() {
+    let mut rng = rand_core::OsRng;
+    let mut secret_key = Scalar::<Secp256k1>::from_be_bytes(SECRET_KEY).unwrap();
+    let secret_key = SecretScalar::new(&mut secret_key);
+    let scrypt = web3_keystore::ScryptParams {
+        n: 1024,
+        ..web3_keystore::ScryptParams::generate(&mut rng)
+    };
+    let keystore = web3_keystore::encrypt_with_kdf(
+        &secret_key,
+        b"password",
+        web3_keystore::Kdf::Scrypt(scrypt.clone()),
+        &mut rng,
+    );
+
+    // Rejected before running the KDF
+    let tampered_params = [
+        web3_keystore::ScryptParams {
+            n: web3_keystore::ScryptParams::MAX_N * 2,
+            ..scrypt.clone()
+        },
+        web3_keystore::ScryptParams {
+            r: 1 << 20,
+            ..scrypt.clone()
+        },
+        web3_keystore::ScryptParams {
+            p: u32::MAX,
+            ..scrypt.clone()
+        },
+        web3_keystore::ScryptParams {
+            salt: vec![],
+            ..scrypt.clone()
+        },
+    ];
+    for params in tampered_params {
+        let mut tampered = keystore.clone();
+        tampered.crypto.kdf = web3_keystore::Kdf::Scrypt(params);
+        assert!(matches!(
+            tampered.decrypt(b"password"),
+            Err(Web3KeystoreError::InvalidKdfParams)
+        ));
+    }
+
+    let mut tampered = keystore.clone();
+    tampered.crypto.kdf = web3_keystore::Kdf::Pbkdf2(web3_keystore::Pbkdf2Params {
+        c: u32::MAX,
+        dklen: 32,
+        prf: web3_keystore::Prf::HmacSha256,
+        salt: scrypt.salt,
+    });
+    assert!(matches!(
+        tampered.decrypt(b"password"),
+        Err(Web3KeystoreError::InvalidKdfParams)
+    ));
+}
@@ -0,0 +1,55 @@
+use hex_literal::hex;
+use slip_10::{supported_curves::Secp256k1, wif};
+
+/// Test vector from https://en.bitcoin.it/wiki/Wallet_import_format
+const SECRET_KEY: [u8; 32] =
+    hex!("0c28fca386c7a227600b2fe50b7cae11ec86d3bf1fbe471be89827e19d72aa1d");
+
+#[test]
+fn wif_test_vector() {
+    let mut secret_key = generic_ec::Scalar::<Secp256k1>::from_be_bytes(SECRET_KEY).unwrap();
+    let secret_key = generic_ec::SecretScalar::new(&mut secret_key);
+
+    let encoded = wif::encode(&secret_key, wif::MAINNET).to_string();
+    assert_eq!(
+        encoded,
+        "KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617"
+    );
+
+    let decoded = wif::decode(&encoded).unwrap();
+    assert_eq!(
+        decoded.secret_key.as_ref().to_be_bytes().as_bytes(),
+        SECRET_KEY
+    );
+    assert_eq!(decoded.version, wif::MAINNET);
+    assert!(decoded.compressed);
+
+    let decoded = wif::decode("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ").unwrap();
+    assert_eq!(
+        decoded.secret_key.as_ref().to_be_bytes().as_bytes(),
+        SECRET_KEY
+    );
+    assert_eq!(decoded.version, wif::MAINNET);
+    assert!(!decoded.compressed);
+
+    let testnet = wif::encode(&secret_key, wif::TESTNET).to_string();
+    assert!(testnet.starts_with('c'));
+    assert_eq!(wif::decode(&testnet).unwrap().version, wif::TESTNET);
+}
+
+#[test]
+fn reject_invalid_wif() {
+    let invalid = [
+        // Bad checksum
+        "KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98618",
+        // Not base58
+        "KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP9861O",
+        // Too short
+        "KwdMAjGmerYanjeui5SHS7Jkmp",
+        // Base58Check of an xpub
+        "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8",
+    ];
+    for s in invalid {
+        assert!(wif::decode(s).is_err(), "{s}");
+    }
+}
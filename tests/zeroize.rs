@@ -0,0 +1,75 @@
+use slip_10::supported_curves::Secp256k1;
+use zeroize::Zeroize;
+
+#[test]
+fn zeroize_key_pair() {
+    let seed = slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap();
+    let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+    let mut key_pair = slip_10::ExtendedKeyPair::from(master_key);
+
+    key_pair.zeroize();
+    assert_eq!(key_pair.chain_code(), &[0u8; 32]);
+    assert_eq!(
+        key_pair.secret_key().secret_key.as_ref(),
+        &generic_ec::Scalar::zero()
+    );
+    assert!(key_pair.public_key().public_key.is_zero());
+}
+
+#[test]
+fn zeroize_derived_shift() {
+    let seed = slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap();
+    let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+    let master_key_pair = slip_10::ExtendedKeyPair::from(master_key);
+
+    let mut shift = slip_10::derive_public_shift(
+        master_key_pair.public_key(),
+        slip_10::NonHardenedIndex::try_from(1).unwrap(),
+    );
+    shift.zeroize();
+    assert_eq!(shift.shift, generic_ec::Scalar::zero());
+    assert_eq!(shift.child_public_key.chain_code, [0u8; 32]);
+}
+
+#[test]
+fn zeroize_ed25519_key_pair() {
+    let seed = slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap();
+    let master_key = slip_10::ed25519::derive_master_key(&seed);
+    let mut key_pair = slip_10::ed25519::ExtendedKeyPair::from(master_key);
+
+    key_pair.zeroize();
+    assert_eq!(key_pair.secret_key().secret_key, [0u8; 32]);
+    assert_eq!(key_pair.chain_code(), &[0u8; 32]);
+}
+
+#[test]
+fn zeroize_seed() {
+    let mut seed = slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap();
+    seed.zeroize();
+    assert_eq!(seed.as_bytes(), [0u8; 27]);
+}
+
+/// Enabling `zeroize` doesn't add `Drop` impls, so keys with public fields can be destructured
+/// and shifts stay `Copy`
+#[test]
+fn zeroize_is_additive() {
+    fn assert_copy<T: Copy>() {}
+    assert_copy::<slip_10::DerivedShift<Secp256k1>>();
+
+    let seed = slip_10::Seed::new(b"16-64 bytes of high entropy").unwrap();
+    let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+    let slip_10::ExtendedSecretKey {
+        secret_key,
+        chain_code,
+    } = master_key.clone();
+    assert_eq!(secret_key.as_ref(), master_key.secret_key.as_ref());
+    assert_eq!(chain_code, master_key.chain_code);
+
+    let master_key = slip_10::ed25519::derive_master_key(&seed);
+    let slip_10::ed25519::ExtendedSecretKey {
+        secret_key,
+        chain_code,
+    } = master_key.clone();
+    assert_eq!(secret_key, master_key.secret_key);
+    assert_eq!(chain_code, master_key.chain_code);
+}
@@ -0,0 +1,152 @@
+//! Kotlin and Swift bindings of [slip-10](slip_10) crate via [UniFFI]
+//!
+//! Exposes the core derivation API to mobile platforms, so Android and iOS wallets derive keys
+//! with exactly the same code as Rust services instead of re-implementing SLIP-10 per platform.
+//! [`HdKey`] wraps a secp256k1 BIP32 extended key: it's derived from the seed, derives child keys
+//! by path, and exports keys as xpub/xprv strings or raw bytes.
+//!
+//! Bindings are generated with `uniffi-bindgen` from the compiled library:
+//! ```text
+//! cargo build --release -p slip-10-uniffi
+//! uniffi-bindgen generate --library target/release/libslip_10_uniffi.so --language kotlin --out-dir out
+//! ```
+//!
+//! ### Example
+//! ```kotlin
+//! val master = HdKey.fromSeed(seed)
+//! val account = master.derivePath("m/84'/0'/0'")
+//! println(account.xpub())
+//! ```
+//!
+//! [UniFFI]: https://mozilla.github.io/uniffi-rs/
+
+use std::sync::Arc;
+
+use slip_10::{bip32, supported_curves::Secp256k1, DerivationPath, Seed};
+
+uniffi::setup_scaffolding!("slip10");
+
+/// Error returned by the bindings
+#[derive(Debug, uniffi::Error)]
+pub enum Slip10Error {
+    /// Seed is not 16-64 bytes long
+    InvalidSeed,
+    /// Derivation path is malformed
+    InvalidPath,
+    /// Extended key is malformed
+    InvalidKey,
+    /// Derived key exceeds maximum depth
+    DerivationFailed,
+}
+
+impl core::fmt::Display for Slip10Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidSeed => f.write_str("seed must be 16-64 bytes long"),
+            Self::InvalidPath => f.write_str("invalid derivation path"),
+            Self::InvalidKey => f.write_str("invalid extended key"),
+            Self::DerivationFailed => f.write_str("key derivation failed"),
+        }
+    }
+}
+
+impl core::error::Error for Slip10Error {}
+
+/// Secp256k1 extended key pair along with its BIP32 metadata
+///
+/// Secret key erases itself from memory when the object is destroyed.
+#[derive(Debug, uniffi::Object)]
+pub struct HdKey(bip32::Bip32ExtendedKey<Secp256k1>);
+
+#[uniffi::export]
+impl HdKey {
+    /// Derives a master key from the seed
+    ///
+    /// Returns an error if the seed is not 16-64 bytes long.
+    #[uniffi::constructor]
+    pub fn from_seed(seed: Vec<u8>) -> Result<Arc<Self>, Slip10Error> {
+        let seed = Seed::new(&seed).map_err(|_| Slip10Error::InvalidSeed)?;
+        let master_key = slip_10::derive_master_key::<Secp256k1>(&seed);
+        Ok(Arc::new(Self(bip32::Bip32ExtendedKey::master(master_key))))
+    }
+
+    /// Parses `xprv...` string
+    ///
+    /// Returns an error if the string is not a valid extended secret key.
+    #[uniffi::constructor]
+    pub fn from_xprv(xprv: String) -> Result<Arc<Self>, Slip10Error> {
+        let xprv: bip32::Xprv<Secp256k1> = xprv.parse().map_err(|_| Slip10Error::InvalidKey)?;
+        Ok(Arc::new(Self(xprv.into())))
+    }
+
+    /// Derives a child key with specified derivation path, e.g. `m/44'/0'/0'/0/0`
+    ///
+    /// Path is relative to this key. Returns an error if the path is malformed or the resulting
+    /// key exceeds maximum depth.
+    pub fn derive_path(&self, path: String) -> Result<Arc<Self>, Slip10Error> {
+        let path: DerivationPath = path.parse().map_err(|_| Slip10Error::InvalidPath)?;
+        let child_key = self
+            .0
+            .derive_child_with_path(&path)
+            .map_err(|_| Slip10Error::DerivationFailed)?;
+        Ok(Arc::new(Self(child_key)))
+    }
+
+    /// Returns `xpub...` string
+    pub fn xpub(&self) -> String {
+        self.0.to_xpub().to_string()
+    }
+
+    /// Returns `xprv...` string
+    ///
+    /// Output contains secret key, make sure it's handled accordingly.
+    pub fn xprv(&self) -> String {
+        self.0.to_xprv().to_string()
+    }
+
+    /// Returns 33 bytes compressed public key
+    pub fn public_key(&self) -> Vec<u8> {
+        self.0.key().public_key().public_key.to_bytes(true).to_vec()
+    }
+
+    /// Returns 32 bytes secret key
+    ///
+    /// Output contains secret key, make sure it's handled accordingly.
+    pub fn secret_key(&self) -> Vec<u8> {
+        self.0
+            .key()
+            .secret_key()
+            .secret_key
+            .as_ref()
+            .to_be_bytes()
+            .to_vec()
+    }
+
+    /// Returns 32 bytes chain code
+    pub fn chain_code(&self) -> Vec<u8> {
+        self.0.key().chain_code().to_bytes().to_vec()
+    }
+
+    /// Returns 4 bytes fingerprint of the key
+    pub fn fingerprint(&self) -> Vec<u8> {
+        self.0.key().public_key().fingerprint().to_vec()
+    }
+
+    /// Returns depth of the key, 0 for master key
+    pub fn depth(&self) -> u8 {
+        *self.0.metadata().depth
+    }
+}
+
+impl HdKey {
+    /// Returns the underlying extended key
+    pub fn as_extended_key(&self) -> &bip32::Bip32ExtendedKey<Secp256k1> {
+        &self.0
+    }
+}
+
+impl From<bip32::Bip32ExtendedKey<Secp256k1>> for HdKey {
+    fn from(key: bip32::Bip32ExtendedKey<Secp256k1>) -> Self {
+        Self(key)
+    }
+}
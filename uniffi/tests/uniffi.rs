@@ -0,0 +1,74 @@
+use slip_10::{bip32, supported_curves::Secp256k1};
+use slip_10_uniffi::{HdKey, Slip10Error};
+
+const SEED: &[u8] = b"16-64 bytes of high entropy";
+
+#[test]
+fn matches_bip32() {
+    let seed = slip_10::Seed::new(SEED).unwrap();
+    let master_key = bip32::Bip32ExtendedKey::<Secp256k1>::master(slip_10::derive_master_key::<
+        Secp256k1,
+    >(&seed));
+    let path: slip_10::DerivationPath = "m/84'/0'/0'/0/1".parse().unwrap();
+    let expected = master_key.derive_child_with_path(&path).unwrap();
+
+    let key = HdKey::from_seed(SEED.to_vec())
+        .unwrap()
+        .derive_path("m/84'/0'/0'".into())
+        .unwrap()
+        .derive_path("0/1".into())
+        .unwrap();
+
+    assert_eq!(key.xpub(), expected.to_xpub().to_string());
+    assert_eq!(key.xprv(), expected.to_xprv().to_string());
+    assert_eq!(
+        key.public_key(),
+        expected
+            .key()
+            .public_key()
+            .public_key
+            .to_bytes(true)
+            .as_bytes()
+    );
+    assert_eq!(
+        key.secret_key(),
+        expected
+            .key()
+            .secret_key()
+            .secret_key
+            .as_ref()
+            .to_be_bytes()
+            .as_bytes()
+    );
+    assert_eq!(key.chain_code(), expected.key().chain_code().to_bytes());
+    assert_eq!(key.fingerprint(), expected.key().public_key().fingerprint());
+    assert_eq!(key.depth(), 5);
+    assert_eq!(key.as_extended_key().key(), expected.key());
+
+    let restored = HdKey::from_xprv(key.xprv()).unwrap();
+    assert_eq!(restored.xpub(), key.xpub());
+}
+
+#[test]
+fn errors() {
+    assert!(matches!(
+        HdKey::from_seed(b"too short".to_vec()),
+        Err(Slip10Error::InvalidSeed)
+    ));
+
+    let key = HdKey::from_seed(SEED.to_vec()).unwrap();
+    assert!(matches!(
+        key.derive_path("m/x".into()),
+        Err(Slip10Error::InvalidPath)
+    ));
+    assert!(matches!(
+        HdKey::from_xprv(key.xpub()),
+        Err(Slip10Error::InvalidKey)
+    ));
+
+    let deep_path = vec!["0"; 256].join("/");
+    assert!(matches!(
+        key.derive_path(deep_path),
+        Err(Slip10Error::DerivationFailed)
+    ));
+}